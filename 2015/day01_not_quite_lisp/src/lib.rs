@@ -0,0 +1,87 @@
+use aoc_common::{
+    answers::Answer,
+    parse::{self, ParseError},
+    Solution,
+};
+use log::debug;
+use nom::{branch::alt, character::complete::char, combinator::value, multi::many0};
+use std::io::BufRead;
+
+// Each parenthesis is a step up or down, which is all the parts need to know
+fn parse_input(input: &mut impl BufRead) -> Result<Vec<i64>, ParseError> {
+    let text = aoc_common::read_input(input)?;
+
+    parse::parse(
+        text.trim_end(),
+        many0(alt((value(1, char('(')), value(-1, char(')'))))),
+    )
+}
+
+fn part1(steps: &[i64]) -> Answer {
+    steps
+        .iter()
+        .fold(0, |acc, step| {
+            debug!("{} {}", acc, step);
+            acc + step
+        })
+        .into()
+}
+
+fn part2(steps: &[i64]) -> Answer {
+    match steps.iter().zip(1..).try_fold(0, |acc, (step, pos)| {
+        debug!("{} {}", pos, step);
+        let next = acc + step;
+        if next == -1 {
+            Err(pos)
+        } else {
+            Ok(next)
+        }
+    }) {
+        Err(pos) => pos.into(),
+        Ok(_) => Answer::Integer(-1),
+    }
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 1;
+
+    type Input = Vec<i64>;
+
+    fn parse(input: &mut impl BufRead) -> Result<Vec<i64>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Vec<i64>) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Vec<i64>) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day01>();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day01>();
+    }
+}
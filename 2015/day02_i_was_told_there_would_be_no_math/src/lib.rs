@@ -0,0 +1,109 @@
+use aoc_common::{
+    answers::Answer,
+    parse::{self, ParseError},
+    scan, Solution,
+};
+use log::debug;
+use std::io::BufRead;
+
+pub struct Gift {
+    length: u64,
+    width: u64,
+    height: u64,
+}
+
+fn parse_input(input: &mut impl BufRead) -> Result<Vec<Gift>, ParseError> {
+    parse::parse_lines(input, |line| {
+        scan!(
+            line,
+            {length: u64} "x" {width: u64} "x" {height: u64}
+            => Gift { length, width, height }
+        )
+    })
+}
+
+fn part1(gifts: &[Gift]) -> Answer {
+    gifts
+        .iter()
+        .map(|gift| {
+            let areas = vec![
+                gift.length * gift.width,
+                gift.length * gift.height,
+                gift.width * gift.height,
+            ];
+
+            debug!(
+                "{}, {}, {}, {:?}",
+                gift.length, gift.width, gift.height, areas
+            );
+
+            areas.iter().sum::<u64>() * 2 + areas.iter().min().unwrap()
+        })
+        .sum::<u64>()
+        .into()
+}
+
+fn part2(gifts: &[Gift]) -> Answer {
+    gifts
+        .iter()
+        .map(|gift| {
+            let perimeters = vec![
+                2 * (gift.length + gift.width),
+                2 * (gift.length + gift.height),
+                2 * (gift.width + gift.height),
+            ];
+
+            debug!(
+                "{}, {}, {}, {:?}",
+                gift.length, gift.width, gift.height, perimeters
+            );
+
+            perimeters.iter().min().unwrap() + gift.length * gift.width * gift.height
+        })
+        .sum::<u64>()
+        .into()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 2;
+
+    type Input = Vec<Gift>;
+
+    fn parse(input: &mut impl BufRead) -> Result<Vec<Gift>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Vec<Gift>) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Vec<Gift>) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day02>();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day02>();
+    }
+}
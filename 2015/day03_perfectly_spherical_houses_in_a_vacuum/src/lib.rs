@@ -0,0 +1,99 @@
+use aoc_common::{
+    answers::Answer,
+    geometry::{self, Direction, Point2},
+    parse::{self, ParseError},
+    Solution,
+};
+use itertools::Itertools;
+use nom::multi::many0;
+use std::{collections::HashSet, io::BufRead};
+
+fn parse_input(input: &mut impl BufRead) -> Result<Vec<Direction>, ParseError> {
+    let text = aoc_common::read_input(input)?;
+
+    parse::parse(text.trim_end(), many0(geometry::direction))
+}
+
+fn part1(instructions: &[Direction]) -> Answer {
+    let mut visited_houses: HashSet<Point2> = HashSet::new();
+    let mut current_position: Point2 = Point2::ORIGIN;
+
+    visited_houses.insert(current_position);
+
+    instructions.iter().for_each(|instruction| {
+        current_position += instruction.offset();
+
+        visited_houses.insert(current_position);
+    });
+
+    visited_houses.len().into()
+}
+
+fn part2(instructions: &[Direction]) -> Answer {
+    const NUMBER_OF_SANTAS: usize = 2;
+
+    let mut visited_houses: HashSet<Point2> = HashSet::new();
+    let mut current_santa_positions: [Point2; NUMBER_OF_SANTAS] =
+        [Point2::ORIGIN; NUMBER_OF_SANTAS];
+
+    // All Santas start at the same position
+    visited_houses.insert(current_santa_positions[0]);
+
+    instructions
+        .iter()
+        .chunks(NUMBER_OF_SANTAS)
+        .into_iter()
+        .for_each(|instructions| {
+            instructions.enumerate().for_each(|(i, instruction)| {
+                current_santa_positions[i] += instruction.offset();
+
+                visited_houses.insert(current_santa_positions[i]);
+            })
+        });
+
+    visited_houses.len().into()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 3;
+
+    type Input = Vec<Direction>;
+
+    fn parse(input: &mut impl BufRead) -> Result<Vec<Direction>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Vec<Direction>) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Vec<Direction>) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day03>();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day03>();
+    }
+}
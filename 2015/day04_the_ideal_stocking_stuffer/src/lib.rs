@@ -0,0 +1,81 @@
+use aoc_common::{
+    answers::Answer,
+    parse::{self, ParseError},
+    Solution,
+};
+use nom::character::complete::alpha1;
+use std::io::BufRead;
+
+fn parse_input(input: &mut impl BufRead) -> Result<String, ParseError> {
+    let text = aoc_common::read_input(input)?;
+
+    parse::parse(text.trim_end(), alpha1).map(str::to_string)
+}
+
+fn find_digest_with_prefix(secret_key: &str, prefix: &str) -> Option<usize> {
+    let mut number: usize = 0;
+
+    while number != usize::MAX {
+        let data = secret_key.to_owned() + &number.to_string();
+
+        if format!("{:x}", md5::compute(data)).starts_with(prefix) {
+            return Some(number);
+        }
+
+        number += 1;
+    }
+
+    None
+}
+
+fn part1(secret_key: &str) -> Option<Answer> {
+    find_digest_with_prefix(secret_key, "00000").map(Answer::from)
+}
+
+fn part2(secret_key: &str) -> Option<Answer> {
+    find_digest_with_prefix(secret_key, "000000").map(Answer::from)
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 4;
+
+    type Input = String;
+
+    fn parse(input: &mut impl BufRead) -> Result<String, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &String) -> Result<Answer, ParseError> {
+        Ok(part1(input).unwrap())
+    }
+
+    fn part2(input: &String) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input).unwrap()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day04>();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day04>();
+    }
+}
@@ -0,0 +1,151 @@
+use aoc_common::{
+    answers::Answer,
+    parse::{self, ParseError},
+    Solution,
+};
+use itertools::Itertools;
+use log::debug;
+use nom::character::complete::alpha1;
+use std::{collections::HashMap, io::BufRead};
+
+fn parse_input(input: &mut impl BufRead) -> Result<Vec<String>, ParseError> {
+    parse::parse_lines(input, |line| parse::parse(line, alpha1).map(str::to_string))
+}
+
+fn part1(strings: &[String]) -> Answer {
+    strings
+        .iter()
+        .filter(|line| {
+            let mut vowel_count = 0;
+            let mut double_letter = false;
+            let mut contains_ab_cd_pq_xy = false;
+
+            let mut tuple = line.chars().tuple_windows::<(char, char)>().peekable();
+
+            while let Some((c1, c2)) = tuple.next() {
+                if "aeiou".contains(c1) {
+                    vowel_count += 1;
+                }
+                if tuple.peek().is_none() && "aeiou".contains(c2) {
+                    vowel_count += 1;
+                }
+
+                if c1 == c2 {
+                    double_letter = true;
+                }
+
+                let pair = c1.to_string() + &c2.to_string();
+                if ["ab", "cd", "pq", "xy"].contains(&pair.as_str()) {
+                    contains_ab_cd_pq_xy = true;
+                }
+            }
+
+            debug!("{} {} {}", vowel_count, double_letter, contains_ab_cd_pq_xy);
+
+            vowel_count >= 3 && double_letter && !contains_ab_cd_pq_xy
+        })
+        .count()
+        .into()
+}
+
+fn part2(strings: &[String]) -> Answer {
+    strings
+        .iter()
+        .filter(|line| {
+            let mut repeating_letter = false;
+            let mut pair_appears_twice = false;
+            // Keep track of each pair and the position it is encountered in the string
+            let mut char_pairs: HashMap<String, usize> = HashMap::new();
+
+            debug!("{:?}", line);
+
+            // We slide a three character window through the string
+            let tuple = line
+                .chars()
+                .tuple_windows::<(char, char, char)>()
+                .enumerate();
+
+            tuple.for_each(|(i, (c1, c2, c3))| {
+                if c1 == c3 {
+                    repeating_letter = true;
+                }
+
+                // We only add the c1+c2 pair in the first window, since otherwise
+                // c1+c2 is the same as c2+c3 in the previous window and we don't
+                // want to add the same pair twice.
+                let pair = c1.to_string() + &c2.to_string();
+                if i == 0 {
+                    char_pairs.insert(pair, i);
+                }
+
+                // If insert() returns a value then the pair has been seen before
+                let pair = c2.to_string() + &c3.to_string();
+                if let Some(pos) = char_pairs.insert(pair.clone(), i + 1) {
+                    // Current position of c2+c3 is i+1.
+                    // If the previous position is i (aka i + 1 - 1) then the pairs
+                    // are overlapping so it doesn't count as appearing twice.
+                    if pos != i {
+                        pair_appears_twice = true;
+                        debug!("{} appears twice, in positions: {} {}", pair, i, pos);
+                    } else {
+                        // If the pairs overlap store the earlier position so that
+                        // we can correctly handle strings such as "aaaa".
+                        char_pairs.insert(pair, i);
+                    }
+                }
+            });
+
+            debug!(
+                "{:?} {} {}",
+                char_pairs, repeating_letter, pair_appears_twice
+            );
+
+            repeating_letter && pair_appears_twice
+        })
+        .count()
+        .into()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 5;
+
+    type Input = Vec<String>;
+
+    fn parse(input: &mut impl BufRead) -> Result<Vec<String>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Vec<String>) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Vec<String>) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day05>();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day05>();
+    }
+}
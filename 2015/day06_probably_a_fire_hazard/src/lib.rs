@@ -0,0 +1,221 @@
+use aoc_common::{
+    answers::Answer,
+    parse::{self, ParseError},
+    Solution,
+};
+use log::debug;
+use nom::Parser;
+use std::{io::BufRead, ops::Not};
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum LightState {
+    On,
+    Off,
+}
+
+impl Not for LightState {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        match self {
+            LightState::Off => LightState::On,
+            LightState::On => LightState::Off,
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+enum LightAction {
+    TurnOn,
+    TurnOff,
+    Toggle,
+}
+
+impl From<&str> for LightAction {
+    fn from(input: &str) -> Self {
+        match input {
+            "turn on" => LightAction::TurnOn,
+            "turn off" => LightAction::TurnOff,
+            "toggle" => LightAction::Toggle,
+            _ => unimplemented!(""),
+        }
+    }
+}
+
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub struct Instruction {
+    light_action: LightAction,
+    coord_pair1: (usize, usize),
+    coord_pair2: (usize, usize),
+}
+
+impl TryFrom<&str> for Instruction {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        use nom::{
+            branch::alt,
+            bytes::complete::tag,
+            character::complete,
+            sequence::{preceded, separated_pair, terminated},
+            IResult,
+        };
+
+        fn light_action(input: &str) -> IResult<&str, &str> {
+            alt((tag("turn on"), tag("toggle"), tag("turn off"))).parse(input)
+        }
+
+        fn range(input: &str) -> IResult<&str, (u32, u32)> {
+            separated_pair(
+                preceded(complete::multispace0, complete::u32),
+                tag(","),
+                terminated(complete::u32, complete::multispace0),
+            )
+            .parse(input)
+        }
+
+        let (action, (x0, x1), _, (y0, y1)) =
+            parse::parse(input, (light_action, range, tag("through"), range))?;
+
+        debug!("{:?}", action);
+        debug!("{} {}", x0, x1);
+        debug!("{} {}", y0, y1);
+
+        Ok(Instruction {
+            light_action: action.into(),
+            coord_pair1: (x0 as usize, x1 as usize),
+            coord_pair2: (y0 as usize, y1 as usize),
+        })
+    }
+}
+
+fn parse_input(input: &mut impl BufRead) -> Result<Vec<Instruction>, ParseError> {
+    parse::parse_lines(input, |line| Instruction::try_from(line))
+}
+
+fn part1(instructions: &[Instruction]) -> Answer {
+    const GRID_SIZE: usize = 1000;
+    let mut grid: [[LightState; GRID_SIZE]; GRID_SIZE] = [[LightState::Off; GRID_SIZE]; GRID_SIZE];
+
+    instructions.iter().for_each(|instruction| {
+        #[allow(clippy::needless_range_loop)]
+        for i in (instruction.coord_pair1.0)..=(instruction.coord_pair2.0) {
+            for j in (instruction.coord_pair1.1)..=(instruction.coord_pair2.1) {
+                match instruction.light_action {
+                    LightAction::TurnOn => grid[i][j] = LightState::On,
+                    LightAction::TurnOff => grid[i][j] = LightState::Off,
+                    LightAction::Toggle => grid[i][j] = !grid[i][j],
+                }
+            }
+        }
+    });
+
+    grid.iter()
+        .flat_map(|r| r.iter())
+        .filter(|&&l| l == LightState::On)
+        .count()
+        .into()
+}
+
+fn part2(instructions: &[Instruction]) -> Answer {
+    const GRID_SIZE: usize = 1000;
+    // We use Vec here since an array of usize on the stack would overflow it
+    let mut grid: Vec<Vec<usize>> = vec![vec![0; GRID_SIZE]; GRID_SIZE];
+
+    instructions.iter().for_each(|instruction| {
+        #[allow(clippy::needless_range_loop)]
+        for i in (instruction.coord_pair1.0)..=(instruction.coord_pair2.0) {
+            for j in (instruction.coord_pair1.1)..=(instruction.coord_pair2.1) {
+                match instruction.light_action {
+                    LightAction::TurnOn => grid[i][j] += 1,
+                    LightAction::TurnOff => {
+                        if grid[i][j] > 0 {
+                            grid[i][j] -= 1
+                        }
+                    }
+                    LightAction::Toggle => grid[i][j] += 2,
+                }
+            }
+        }
+    });
+
+    grid.iter()
+        .fold(0, |sum, r| sum + r.iter().sum::<usize>())
+        .into()
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 6;
+
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &mut impl BufRead) -> Result<Vec<Instruction>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Vec<Instruction>) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Vec<Instruction>) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn parser_tests() {
+        init();
+
+        assert_eq!(
+            Instruction::try_from("turn on 0,0 through 999,999"),
+            Ok(Instruction {
+                light_action: LightAction::TurnOn,
+                coord_pair1: (0, 0),
+                coord_pair2: (999, 999),
+            })
+        );
+
+        assert_eq!(
+            Instruction::try_from("toggle 0,0 through 999,0"),
+            Ok(Instruction {
+                light_action: LightAction::Toggle,
+                coord_pair1: (0, 0),
+                coord_pair2: (999, 0),
+            })
+        );
+
+        assert_eq!(
+            Instruction::try_from("turn off 499,499 through 500,500"),
+            Ok(Instruction {
+                light_action: LightAction::TurnOff,
+                coord_pair1: (499, 499),
+                coord_pair2: (500, 500),
+            })
+        );
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day06>();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day06>();
+    }
+}
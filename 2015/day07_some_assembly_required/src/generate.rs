@@ -0,0 +1,84 @@
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+// Wires are named like columns in a spreadsheet, a to z and then aa, ab...
+fn wire_name(mut index: usize) -> String {
+    let mut name = Vec::new();
+    loop {
+        name.push(b'a' + (index % 26) as u8);
+        if index < 26 {
+            break;
+        }
+        index = index / 26 - 1;
+    }
+    name.reverse();
+
+    String::from_utf8(name).unwrap()
+}
+
+// A circuit with `size` wires. Every gate only takes signals from wires that
+// come before it, so that there are no loops, and b is one of the wires that
+// get a signal straight from a number so that part 2 has something to
+// override. a is the last wire, like in the real inputs, which also list the
+// gates in no particular order.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let mut gates = vec![format!("{} -> b", rng.random::<u16>())];
+    // b is wire 1 and a is wire 0, the others are numbered from 2 on
+    let mut wires = vec![wire_name(1)];
+    (2..size.max(2)).for_each(|index| {
+        let wire = |rng: &mut StdRng| wires[rng.random_range(0..wires.len())].clone();
+        let out = wire_name(index);
+
+        let gate = match rng.random_range(0..10) {
+            0 => format!("{} -> {}", rng.random::<u16>(), out),
+            1 => format!("{} -> {}", wire(&mut rng), out),
+            2 | 3 => format!("NOT {} -> {}", wire(&mut rng), out),
+            4 if rng.random_bool(0.5) => format!("1 AND {} -> {}", wire(&mut rng), out),
+            4 | 5 => format!("{} AND {} -> {}", wire(&mut rng), wire(&mut rng), out),
+            6 | 7 => format!("{} OR {} -> {}", wire(&mut rng), wire(&mut rng), out),
+            8 => format!(
+                "{} LSHIFT {} -> {}",
+                wire(&mut rng),
+                rng.random_range(1..16),
+                out
+            ),
+            _ => format!(
+                "{} RSHIFT {} -> {}",
+                wire(&mut rng),
+                rng.random_range(1..16),
+                out
+            ),
+        };
+
+        gates.push(gate);
+        wires.push(out);
+    });
+    gates.push(format!("{} -> a", wires.last().unwrap()));
+
+    gates.shuffle(&mut rng);
+    gates.into_iter().map(|gate| gate + "\n").collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{generate::*, Day07};
+    use aoc_common::Solution;
+
+    #[test]
+    fn generate_tests() {
+        assert_eq!(
+            [0, 1, 25, 26, 27, 701, 702].map(wire_name),
+            ["a", "b", "z", "aa", "ab", "zz", "aaa"]
+        );
+
+        let input = generate(500, 7);
+        assert_eq!(input.lines().count(), 500);
+        assert_eq!(input, generate(500, 7));
+        assert_ne!(input, generate(500, 8));
+
+        let parsed = Day07::parse(&mut input.as_bytes()).unwrap();
+        assert!(Day07::part1(&parsed).is_ok());
+        assert!(Day07::part2(&parsed).unwrap().is_ok());
+    }
+}
@@ -0,0 +1,650 @@
+mod generate;
+
+use aoc_common::{
+    answers::Answer,
+    examples,
+    parse::{self, ParseError},
+    Solution,
+};
+use log::debug;
+use nom::Parser;
+#[cfg(not(test))]
+use rand::{distr::Alphanumeric, Rng};
+use std::{collections::HashMap, io::BufRead};
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Wire {
+    name: String,
+    value: Option<u16>,
+}
+
+// In the input file, the input wires to a gate can either be a
+// number or a string. Handle both of these as a "Wire" and give
+// random names to number inputs.
+impl From<&str> for Wire {
+    fn from(input: &str) -> Self {
+        fn generate_random_string() -> String {
+            #[cfg(not(test))]
+            {
+                rand::rng()
+                    .sample_iter(&Alphanumeric)
+                    .take(10)
+                    .map(char::from)
+                    .collect::<String>()
+            }
+            #[cfg(test)]
+            {
+                "TEST".to_string()
+            }
+        }
+
+        match input.parse::<u16>() {
+            Ok(value) => Wire {
+                name: generate_random_string(),
+                value: Some(value),
+            },
+            Err(_) => Wire {
+                name: input.to_string(),
+                value: None,
+            },
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum Gate {
+    PassThrough {
+        wire_in: Wire,
+        wire_out: Wire,
+    },
+    And {
+        wire_in_1: Wire,
+        wire_in_2: Wire,
+        wire_out: Wire,
+    },
+    LeftShift {
+        wire_in_1: Wire,
+        wire_in_2: Wire,
+        wire_out: Wire,
+    },
+    Not {
+        wire_in: Wire,
+        wire_out: Wire,
+    },
+    Or {
+        wire_in_1: Wire,
+        wire_in_2: Wire,
+        wire_out: Wire,
+    },
+    RightShift {
+        wire_in_1: Wire,
+        wire_in_2: Wire,
+        wire_out: Wire,
+    },
+}
+
+impl Gate {
+    fn reset(&mut self) {
+        match self {
+            Gate::PassThrough { wire_out, .. }
+            | Gate::And { wire_out, .. }
+            | Gate::LeftShift { wire_out, .. }
+            | Gate::Not { wire_out, .. }
+            | Gate::Or { wire_out, .. }
+            | Gate::RightShift { wire_out, .. } => {
+                wire_out.value = None;
+            }
+        };
+    }
+}
+
+impl TryFrom<&str> for Gate {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        use nom::{
+            branch::alt,
+            bytes::complete::tag,
+            character::complete,
+            sequence::{preceded, separated_pair},
+            IResult,
+        };
+
+        // Assume that whitespaces in the input "behave" so that we don't
+        // have to sprinkle complete::whitespace1 parsers everywhere
+        fn pass_through(input: &str) -> IResult<&str, Gate> {
+            separated_pair(
+                alt((complete::digit1, complete::alpha1)),
+                tag(" -> "),
+                complete::alpha1,
+            )
+            .parse(input)
+            .map(|(s, (wire_in, wire_out))| {
+                (
+                    s,
+                    Gate::PassThrough {
+                        wire_in: wire_in.into(),
+                        wire_out: wire_out.into(),
+                    },
+                )
+            })
+        }
+
+        fn and_or_shift(input: &str) -> IResult<&str, Gate> {
+            separated_pair(
+                (
+                    alt((complete::digit1, complete::alpha1)),
+                    alt((tag(" AND "), tag(" OR "), tag(" LSHIFT "), tag(" RSHIFT "))),
+                    alt((complete::digit1, complete::alpha1)),
+                ),
+                tag(" -> "),
+                complete::alpha1,
+            )
+            .parse(input)
+            .map(|(s, ((wire_in_1, op, wire_in_2), wire_out))| {
+                (
+                    s,
+                    match op {
+                        " AND " => Gate::And {
+                            wire_in_1: wire_in_1.into(),
+                            wire_in_2: wire_in_2.into(),
+                            wire_out: wire_out.into(),
+                        },
+                        " OR " => Gate::Or {
+                            wire_in_1: wire_in_1.into(),
+                            wire_in_2: wire_in_2.into(),
+                            wire_out: wire_out.into(),
+                        },
+                        " LSHIFT " => Gate::LeftShift {
+                            wire_in_1: wire_in_1.into(),
+                            wire_in_2: wire_in_2.into(),
+                            wire_out: wire_out.into(),
+                        },
+                        " RSHIFT " => Gate::RightShift {
+                            wire_in_1: wire_in_1.into(),
+                            wire_in_2: wire_in_2.into(),
+                            wire_out: wire_out.into(),
+                        },
+                        _ => unreachable!(),
+                    },
+                )
+            })
+        }
+
+        fn not(input: &str) -> IResult<&str, Gate> {
+            separated_pair(
+                preceded(tag("NOT "), complete::alpha1),
+                tag(" -> "),
+                complete::alpha1,
+            )
+            .parse(input)
+            .map(|(s, (wire_in, wire_out))| {
+                (
+                    s,
+                    Gate::Not {
+                        wire_in: wire_in.into(),
+                        wire_out: wire_out.into(),
+                    },
+                )
+            })
+        }
+
+        parse::parse(input, alt((pass_through, and_or_shift, not)))
+    }
+}
+
+// Recursively figure out the signal value for the gate corresponding
+// to the input name.
+fn compute_signal_value(
+    name: &str,
+    wire_names_to_gates: &mut HashMap<String, Gate>,
+) -> Option<u16> {
+    let ret_val: Option<u16> = if let Some(gate) = wire_names_to_gates.get(name) {
+        // Each gate follows the same logic
+        // - if the output wire has a value, return that
+        // - otherwise obtain the values of all input wires by recursively
+        // calling the function (if the input values are not already known)
+        // and then setting the output value on the output wire accordingly
+        match gate {
+            Gate::PassThrough { wire_in, wire_out } => {
+                if wire_out.value.is_some() {
+                    wire_out.value
+                } else if wire_in.value.is_some() {
+                    wire_in.value
+                } else {
+                    compute_signal_value(&wire_in.name.clone(), wire_names_to_gates)
+                }
+            }
+            Gate::And {
+                wire_in_1,
+                wire_in_2,
+                wire_out,
+            } => {
+                if wire_out.value.is_some() {
+                    wire_out.value
+                } else {
+                    // Clone the input wires so that we can drop the reference to
+                    // wire_names_to_gates. This is fine (albeit ugly) since we're
+                    // not interested in storing the input wires' values
+                    let wire_in_1 = wire_in_1.clone();
+                    let wire_in_2 = wire_in_2.clone();
+
+                    Some(
+                        if let Some(value) = wire_in_1.value {
+                            value
+                        } else {
+                            compute_signal_value(&wire_in_1.name, wire_names_to_gates)?
+                        } & if let Some(value) = wire_in_2.value {
+                            value
+                        } else {
+                            compute_signal_value(&wire_in_2.name, wire_names_to_gates)?
+                        },
+                    )
+                }
+            }
+            Gate::LeftShift {
+                wire_in_1,
+                wire_in_2,
+                wire_out,
+            } => {
+                if wire_out.value.is_some() {
+                    wire_out.value
+                } else {
+                    let wire_in_1 = wire_in_1.clone();
+                    let wire_in_2 = wire_in_2.clone();
+
+                    Some(
+                        if let Some(value) = wire_in_1.value {
+                            value
+                        } else {
+                            compute_signal_value(&wire_in_1.name, wire_names_to_gates)?
+                        } << if let Some(value) = wire_in_2.value {
+                            value
+                        } else {
+                            compute_signal_value(&wire_in_2.name, wire_names_to_gates)?
+                        },
+                    )
+                }
+            }
+            Gate::Not { wire_in, wire_out } => {
+                if wire_out.value.is_some() {
+                    wire_out.value
+                } else if let Some(value) = wire_in.value {
+                    Some(!value)
+                } else {
+                    Some(!compute_signal_value(&wire_in.name.clone(), wire_names_to_gates).unwrap())
+                }
+            }
+            Gate::Or {
+                wire_in_1,
+                wire_in_2,
+                wire_out,
+            } => {
+                if wire_out.value.is_some() {
+                    wire_out.value
+                } else {
+                    let wire_in_1 = wire_in_1.clone();
+                    let wire_in_2 = wire_in_2.clone();
+
+                    Some(
+                        if let Some(value) = wire_in_1.value {
+                            value
+                        } else {
+                            compute_signal_value(&wire_in_1.name, wire_names_to_gates)?
+                        } | if let Some(value) = wire_in_2.value {
+                            value
+                        } else {
+                            compute_signal_value(&wire_in_2.name, wire_names_to_gates)?
+                        },
+                    )
+                }
+            }
+            Gate::RightShift {
+                wire_in_1,
+                wire_in_2,
+                wire_out,
+            } => {
+                if wire_out.value.is_some() {
+                    wire_out.value
+                } else {
+                    let wire_in_1 = wire_in_1.clone();
+                    let wire_in_2 = wire_in_2.clone();
+
+                    Some(
+                        if let Some(value) = wire_in_1.value {
+                            value
+                        } else {
+                            compute_signal_value(&wire_in_1.name, wire_names_to_gates)?
+                        } >> if let Some(value) = wire_in_2.value {
+                            value
+                        } else {
+                            compute_signal_value(&wire_in_2.name, wire_names_to_gates)?
+                        },
+                    )
+                }
+            }
+        }
+    } else {
+        None
+    };
+
+    if let Some(gate) = wire_names_to_gates.get_mut(name) {
+        match gate {
+            Gate::PassThrough { wire_out, .. }
+            | Gate::And { wire_out, .. }
+            | Gate::LeftShift { wire_out, .. }
+            | Gate::Not { wire_out, .. }
+            | Gate::Or { wire_out, .. }
+            | Gate::RightShift { wire_out, .. } => {
+                wire_out.value = ret_val;
+            }
+        };
+    };
+
+    ret_val
+}
+
+fn parse_input(input: &mut impl BufRead) -> Result<HashMap<String, Gate>, ParseError> {
+    let mut wire_names_to_gates: HashMap<String, Gate> = HashMap::new();
+
+    let gates: Vec<Gate> = parse::parse_lines(input, |line| Gate::try_from(line))?;
+
+    gates.into_iter().for_each(|gate| {
+        // Create a mapping between each wire name and the gate that produces its signal
+        // We know that "each wire can only get a signal from one source"
+        match &gate {
+            Gate::PassThrough { wire_out, .. }
+            | Gate::And { wire_out, .. }
+            | Gate::LeftShift { wire_out, .. }
+            | Gate::Not { wire_out, .. }
+            | Gate::Or { wire_out, .. }
+            | Gate::RightShift { wire_out, .. } => {
+                wire_names_to_gates.insert(wire_out.name.to_string(), gate);
+            }
+        };
+    });
+
+    Ok(wire_names_to_gates)
+}
+
+// The wires' values are filled in as they're worked out, on a copy of the
+// circuit so that it can be solved again from scratch
+fn part1(wire_names_to_gates: &HashMap<String, Gate>, wire: &str) -> Result<Answer, ParseError> {
+    let mut wire_names_to_gates = wire_names_to_gates.clone();
+
+    wire_names_to_gates.iter().for_each(|mapping| {
+        debug!("{:?}", mapping);
+    });
+
+    signal(wire, &mut wire_names_to_gates).map(Answer::from)
+}
+
+// A wire that no gate drives has no signal, and neither has anything it goes
+// into
+fn signal(name: &str, wire_names_to_gates: &mut HashMap<String, Gate>) -> Result<u16, ParseError> {
+    compute_signal_value(name, wire_names_to_gates)
+        .ok_or_else(|| ParseError::no_answer(format!("there is no signal on wire {}", name)))
+}
+
+fn part2(wire_names_to_gates: &HashMap<String, Gate>) -> Result<Answer, ParseError> {
+    let mut wire_names_to_gates = wire_names_to_gates.clone();
+
+    let a_signal_value = signal("a", &mut wire_names_to_gates)?.to_string();
+
+    wire_names_to_gates.values_mut().for_each(|gate| {
+        gate.reset();
+    });
+
+    // Replace b's gate with a PassThrough gate with a's value as input
+    wire_names_to_gates.insert(
+        "b".to_string(),
+        Gate::try_from((a_signal_value + " -> b").as_str())?,
+    );
+
+    wire_names_to_gates.iter().for_each(|mapping| {
+        debug!("{:?}", mapping);
+    });
+
+    signal("a", &mut wire_names_to_gates).map(Answer::from)
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 7;
+
+    // The circuit, and the wire whose signal part 1 is after
+    type Input = (HashMap<String, Gate>, String);
+
+    fn parse(input: &mut impl BufRead) -> Result<(HashMap<String, Gate>, String), ParseError> {
+        Ok((parse_input(input)?, "a".to_string()))
+    }
+
+    fn set_parameter(
+        (_, wire): &mut (HashMap<String, Gate>, String),
+        name: &str,
+        value: &str,
+    ) -> Result<(), String> {
+        match name {
+            "wire" => *wire = examples::parameter(name, value)?,
+            _ => return Err(format!("unknown parameter {}", name)),
+        }
+        Ok(())
+    }
+
+    fn part1(
+        (wire_names_to_gates, wire): &(HashMap<String, Gate>, String),
+    ) -> Result<Answer, ParseError> {
+        part1(wire_names_to_gates, wire)
+    }
+
+    fn part2(
+        (wire_names_to_gates, _): &(HashMap<String, Gate>, String),
+    ) -> Option<Result<Answer, ParseError>> {
+        Some(part2(wire_names_to_gates))
+    }
+
+    fn generate(size: usize, seed: u64) -> Option<String> {
+        Some(generate::generate(size, seed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::examples::Example;
+    use std::io::Cursor;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn parser_tests() {
+        assert_eq!(
+            Gate::try_from("123 -> x"),
+            Ok(Gate::PassThrough {
+                wire_in: Wire {
+                    name: "TEST".to_string(),
+                    value: Some(123)
+                },
+                wire_out: Wire {
+                    name: "x".to_string(),
+                    value: None
+                }
+            })
+        );
+
+        assert_eq!(
+            Gate::try_from("abc -> x"),
+            Ok(Gate::PassThrough {
+                wire_in: Wire {
+                    name: "abc".to_string(),
+                    value: None
+                },
+                wire_out: Wire {
+                    name: "x".to_string(),
+                    value: None
+                }
+            })
+        );
+
+        assert_eq!(
+            Gate::try_from("x AND y -> d"),
+            Ok(Gate::And {
+                wire_in_1: Wire {
+                    name: "x".to_string(),
+                    value: None
+                },
+                wire_in_2: Wire {
+                    name: "y".to_string(),
+                    value: None
+                },
+                wire_out: Wire {
+                    name: "d".to_string(),
+                    value: None
+                }
+            })
+        );
+
+        assert_eq!(
+            Gate::try_from("x OR y -> e"),
+            Ok(Gate::Or {
+                wire_in_1: Wire {
+                    name: "x".to_string(),
+                    value: None
+                },
+                wire_in_2: Wire {
+                    name: "y".to_string(),
+                    value: None
+                },
+                wire_out: Wire {
+                    name: "e".to_string(),
+                    value: None
+                }
+            })
+        );
+
+        assert_eq!(
+            Gate::try_from("x LSHIFT 2 -> f"),
+            Ok(Gate::LeftShift {
+                wire_in_1: Wire {
+                    name: "x".to_string(),
+                    value: None
+                },
+                wire_in_2: Wire {
+                    name: "TEST".to_string(),
+                    value: Some(2)
+                },
+                wire_out: Wire {
+                    name: "f".to_string(),
+                    value: None
+                }
+            })
+        );
+
+        assert_eq!(
+            Gate::try_from("y RSHIFT 2 -> g"),
+            Ok(Gate::RightShift {
+                wire_in_1: Wire {
+                    name: "y".to_string(),
+                    value: None
+                },
+                wire_in_2: Wire {
+                    name: "TEST".to_string(),
+                    value: Some(2)
+                },
+                wire_out: Wire {
+                    name: "g".to_string(),
+                    value: None
+                }
+            })
+        );
+
+        assert_eq!(
+            Gate::try_from("NOT x -> h"),
+            Ok(Gate::Not {
+                wire_in: Wire {
+                    name: "x".to_string(),
+                    value: None
+                },
+                wire_out: Wire {
+                    name: "h".to_string(),
+                    value: None
+                }
+            })
+        );
+    }
+
+    #[test]
+    fn malformed_input_tests() {
+        init();
+
+        let input = Example::load("examples/example.txt")
+            .unwrap()
+            .input
+            .replace("x OR y -> e", "x XOR y -> e");
+        let error = parse_input(&mut Cursor::new(input)).unwrap_err();
+
+        assert_eq!(error.line, 4);
+        assert_eq!(error.text, "x XOR y -> e");
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day07>();
+    }
+
+    // The signals on the example's other wires, which the example can only
+    // ask part 1 for one at a time
+    #[test]
+    fn signal_tests() {
+        init();
+
+        let input = Example::load("examples/example.txt").unwrap().input;
+        let mut wire_names_to_gates = parse_input(&mut Cursor::new(input)).unwrap();
+
+        assert_eq!(
+            compute_signal_value("d", &mut wire_names_to_gates),
+            Some(72)
+        );
+        assert_eq!(
+            compute_signal_value("e", &mut wire_names_to_gates),
+            Some(507)
+        );
+        assert_eq!(
+            compute_signal_value("f", &mut wire_names_to_gates),
+            Some(492)
+        );
+        assert_eq!(
+            compute_signal_value("g", &mut wire_names_to_gates),
+            Some(114)
+        );
+        assert_eq!(
+            compute_signal_value("h", &mut wire_names_to_gates),
+            Some(65412)
+        );
+        assert_eq!(
+            compute_signal_value("i", &mut wire_names_to_gates),
+            Some(65079)
+        );
+        assert_eq!(
+            compute_signal_value("x", &mut wire_names_to_gates),
+            Some(123)
+        );
+        assert_eq!(
+            compute_signal_value("y", &mut wire_names_to_gates),
+            Some(456)
+        );
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day07>();
+    }
+}
@@ -0,0 +1,149 @@
+use aoc_common::{
+    answers::Answer,
+    parse::{self, ParseError},
+    Solution,
+};
+use log::debug;
+use nom::{
+    branch::alt,
+    bytes::complete::{tag, take},
+    character::complete::alpha1,
+    combinator::eof,
+    multi::many0,
+    sequence::{delimited, preceded, terminated},
+    IResult, Parser,
+};
+use std::io::BufRead;
+
+#[derive(Debug)]
+struct StringFragment {
+    characters_of_code: usize,
+    characters_in_memory: usize,
+    characters_in_encoded: usize,
+}
+
+fn ascii_code(input: &str) -> IResult<&str, &str> {
+    preceded(tag("\\x"), take(2usize)).parse(input)
+}
+
+fn parse_string(input: &str) -> IResult<&str, Vec<StringFragment>> {
+    delimited(
+        tag("\""),
+        many0(alt((
+            ascii_code.map(|_| StringFragment {
+                characters_of_code: 4,
+                characters_in_memory: 1,
+                characters_in_encoded: 5,
+            }),
+            tag("\\\"").map(|_| StringFragment {
+                characters_of_code: 2,
+                characters_in_memory: 1,
+                characters_in_encoded: 4,
+            }),
+            tag("\\\\").map(|_| StringFragment {
+                characters_of_code: 2,
+                characters_in_memory: 1,
+                characters_in_encoded: 4,
+            }),
+            alpha1.map(|s: &str| StringFragment {
+                characters_of_code: s.len(),
+                characters_in_memory: s.len(),
+                characters_in_encoded: s.len(),
+            }),
+        ))),
+        terminated(tag("\""), eof),
+    )
+    .parse(input)
+    .map(|(s, mut v)| {
+        // Manually add the beginning and end double quotes
+        v.push(StringFragment {
+            characters_of_code: 2,
+            characters_in_memory: 0,
+            characters_in_encoded: 6,
+        });
+        (s, v)
+    })
+}
+
+fn get_character_stats(input_string: &str) -> Result<(usize, usize, usize), ParseError> {
+    let string_fragments = parse::parse(input_string, parse_string)?;
+
+    debug!("{}, {:?}", input_string, string_fragments);
+
+    Ok(string_fragments.into_iter().fold((0, 0, 0), |acc, x| {
+        (
+            acc.0 + x.characters_of_code,
+            acc.1 + x.characters_in_memory,
+            acc.2 + x.characters_in_encoded,
+        )
+    }))
+}
+
+// The parts only need the character counts of each string
+fn parse_input(input: &mut impl BufRead) -> Result<Vec<(usize, usize, usize)>, ParseError> {
+    parse::parse_lines(input, get_character_stats)
+}
+
+fn part1(stats: &[(usize, usize, usize)]) -> Answer {
+    stats
+        .iter()
+        .map(|(characters_of_code, characters_in_memory, _)| {
+            characters_of_code - characters_in_memory
+        })
+        .sum::<usize>()
+        .into()
+}
+
+fn part2(stats: &[(usize, usize, usize)]) -> Answer {
+    stats
+        .iter()
+        .map(|(characters_of_code, _, characters_in_encoded)| {
+            characters_in_encoded - characters_of_code
+        })
+        .sum::<usize>()
+        .into()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 8;
+
+    type Input = Vec<(usize, usize, usize)>;
+
+    fn parse(input: &mut impl BufRead) -> Result<Vec<(usize, usize, usize)>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Vec<(usize, usize, usize)>) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Vec<(usize, usize, usize)>) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day08>();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day08>();
+    }
+}
@@ -0,0 +1,61 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use std::collections::HashSet;
+
+// Made up of a few syllables, like Tambi or Norrath
+fn location_name(rng: &mut StdRng) -> String {
+    const CONSONANTS: &[u8] = b"bdfglmnrstvz";
+    const VOWELS: &[u8] = b"aeiou";
+
+    let name: String = (0..rng.random_range(2..5))
+        .flat_map(|_| {
+            [
+                CONSONANTS[rng.random_range(0..CONSONANTS.len())],
+                VOWELS[rng.random_range(0..VOWELS.len())],
+            ]
+        })
+        .map(char::from)
+        .collect();
+
+    name[..1].to_uppercase() + &name[1..]
+}
+
+// The distances between every two of `size` locations. Every route goes
+// through all of them, so the number of routes grows with the factorial of
+// the size.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let mut seen = HashSet::new();
+    let mut locations = Vec::new();
+    while locations.len() < size {
+        let name = location_name(&mut rng);
+        if seen.insert(name.clone()) {
+            locations.push(name);
+        }
+    }
+
+    locations
+        .iter()
+        .enumerate()
+        .flat_map(|(i, from)| locations[i + 1..].iter().map(move |to| (from, to)))
+        .map(|(from, to)| format!("{} to {} = {}\n", from, to, rng.random_range(1..=150)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{generate::*, Day09};
+    use aoc_common::Solution;
+
+    #[test]
+    fn generate_tests() {
+        let input = generate(7, 7);
+        assert_eq!(input.lines().count(), 7 * 6 / 2);
+        assert_eq!(input, generate(7, 7));
+        assert_ne!(input, generate(7, 8));
+
+        let parsed = Day09::parse(&mut input.as_bytes()).unwrap();
+        assert!(Day09::part1(&parsed).is_ok());
+        assert!(Day09::part2(&parsed).unwrap().is_ok());
+    }
+}
@@ -0,0 +1,144 @@
+mod generate;
+
+use aoc_common::{
+    answers::Answer,
+    parse::{self, ParseError},
+    Solution,
+};
+use itertools::Itertools;
+use log::debug;
+use nom::{bytes::complete::tag, character::complete, sequence::separated_pair};
+use std::{
+    collections::{HashMap, HashSet},
+    io::BufRead,
+};
+
+type Distances = HashMap<(String, String), u32>;
+
+fn parse_input(input: &mut impl BufRead) -> Result<(HashSet<String>, Distances), ParseError> {
+    let mut locations: HashSet<String> = HashSet::new();
+    let mut distances: Distances = HashMap::new();
+
+    // fn parse_distance(input: &str) -> IResult<&str, ((&str, &str), u32)> {
+    //     separated_pair(
+    //         separated_pair(complete::alpha1, tag(" to "), complete::alpha1),
+    //         tag(" = "),
+    //         complete::u32,
+    //     )(input)
+    // }
+
+    let lines: Vec<_> = parse::parse_lines(input, |line| {
+        parse::parse(
+            line,
+            separated_pair(
+                separated_pair(
+                    // These type annotations are not needed in parse_distance
+                    complete::alpha1::<&str, nom::error::Error<&str>>,
+                    tag(" to "),
+                    complete::alpha1::<&str, nom::error::Error<&str>>,
+                ),
+                tag(" = "),
+                complete::u32,
+            ),
+        )
+        .map(|((location1, location2), distance)| {
+            (location1.to_string(), location2.to_string(), distance)
+        })
+    })?;
+
+    lines
+        .into_iter()
+        .for_each(|(location1, location2, distance)| {
+            distances.insert((location1.clone(), location2.clone()), distance);
+            distances.insert((location2.clone(), location1.clone()), distance);
+            locations.insert(location1);
+            locations.insert(location2);
+        });
+
+    debug!("{:?}", locations);
+    debug!("{:?}", distances);
+
+    Ok((locations, distances))
+}
+
+fn part1((locations, distances): &(HashSet<String>, Distances)) -> Answer {
+    locations
+        .iter()
+        .permutations(locations.len())
+        .map(|permutation| {
+            permutation
+                .into_iter()
+                .tuple_windows::<(&String, &String)>()
+                .fold(0, |dist, (l1, l2)| {
+                    dist + distances.get(&(l1.to_string(), l2.to_string())).unwrap()
+                })
+        })
+        .min()
+        .unwrap()
+        .into()
+}
+
+fn part2((locations, distances): &(HashSet<String>, Distances)) -> Answer {
+    locations
+        .iter()
+        .permutations(locations.len())
+        .map(|permutation| {
+            permutation
+                .into_iter()
+                .tuple_windows::<(&String, &String)>()
+                .fold(0, |dist, (l1, l2)| {
+                    dist + distances.get(&(l1.to_string(), l2.to_string())).unwrap()
+                })
+        })
+        .max()
+        .unwrap()
+        .into()
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 9;
+
+    type Input = (HashSet<String>, Distances);
+
+    fn parse(input: &mut impl BufRead) -> Result<(HashSet<String>, Distances), ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &(HashSet<String>, Distances)) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &(HashSet<String>, Distances)) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+
+    fn generate(size: usize, seed: u64) -> Option<String> {
+        Some(generate::generate(size, seed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day09>();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day09>();
+    }
+}
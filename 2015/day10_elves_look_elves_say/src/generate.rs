@@ -0,0 +1,30 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+// A starting sequence of `size` digits. The real ones only have 1s, 2s and
+// 3s, which is all that the sequence ever has in it once it gets going.
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let digits: String = (0..size)
+        .map(|_| char::from(rng.random_range(b'1'..=b'3')))
+        .collect();
+
+    digits + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generate::*;
+
+    #[test]
+    fn generate_tests() {
+        let input = generate(10, 7);
+        assert_eq!(input.trim().len(), 10);
+        assert!(input
+            .trim()
+            .chars()
+            .all(|digit| ('1'..='3').contains(&digit)));
+        assert_eq!(input, generate(10, 7));
+        assert_ne!(input, generate(10, 8));
+    }
+}
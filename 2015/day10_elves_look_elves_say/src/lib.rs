@@ -0,0 +1,137 @@
+mod generate;
+
+use aoc_common::{
+    answers::Answer,
+    parse::{self, ParseError},
+    Solution,
+};
+use itertools::Itertools;
+use log::debug;
+use nom::character::complete::digit1;
+use std::io::BufRead;
+
+fn parse_input(input: &mut impl BufRead) -> Result<String, ParseError> {
+    let text = aoc_common::read_input(input)?;
+
+    parse::parse(text.trim_end(), digit1).map(str::to_string)
+}
+
+fn look_and_say_slow(input: &str) -> String {
+    input
+        .chars()
+        .chunk_by(|&x| x)
+        .into_iter()
+        .map(|(_, r)| r.collect())
+        .collect::<Vec<String>>()
+        .into_iter()
+        .fold("".to_string(), |acc, s| {
+            let c = s.chars().next().unwrap();
+            let len = s.len().to_string();
+            format!("{acc}{len}{c}")
+        })
+}
+
+fn look_and_say_fast(input: &str) -> String {
+    let mut result = String::new();
+    let mut chars = input.chars();
+    let mut prev_char = chars.next().unwrap();
+    let mut count = 1;
+
+    for c in chars {
+        if c == prev_char {
+            count += 1;
+        } else {
+            result.push_str(&count.to_string());
+            result.push(prev_char);
+            count = 1;
+            prev_char = c;
+        }
+    }
+
+    result.push_str(&count.to_string());
+    result.push(prev_char);
+
+    result
+}
+
+fn part1(sequence: &str) -> Answer {
+    let mut s = sequence.to_string();
+
+    for _ in 0..40 {
+        s = look_and_say_slow(&s);
+    }
+
+    s.len().into()
+}
+
+fn part2(sequence: &str) -> Answer {
+    let mut s = sequence.to_string();
+
+    for i in 0..50 {
+        s = look_and_say_fast(&s);
+        debug!("{} {}", i, s.len());
+    }
+
+    s.len().into()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 10;
+
+    type Input = String;
+
+    fn parse(input: &mut impl BufRead) -> Result<String, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &String) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &String) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+
+    fn generate(size: usize, seed: u64) -> Option<String> {
+        Some(generate::generate(size, seed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::differential::{self, Pair};
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day10>();
+    }
+
+    // A few rounds, so that the counts get over 1 and the sequences get long
+    fn rounds(look_and_say: fn(&str) -> String, input: &str) -> String {
+        (0..10).fold(input.trim().to_string(), |s, _| look_and_say(&s))
+    }
+
+    #[test]
+    fn differential_tests() {
+        init();
+
+        differential::check_pair::<Day10, _>(
+            &Pair {
+                name: "look_and_say",
+                reference: |input| rounds(look_and_say_slow, input),
+                candidate: |input| rounds(look_and_say_fast, input),
+            },
+            1..=20,
+        );
+    }
+}
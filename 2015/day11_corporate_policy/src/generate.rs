@@ -0,0 +1,88 @@
+use crate::Password;
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+
+fn letter(rng: &mut StdRng) -> u8 {
+    loop {
+        let letter = rng.random_range(b'a'..=b'z');
+        if !b"iol".contains(&letter) {
+            return letter;
+        }
+    }
+}
+
+// A straight of three letters, two pairs and one more letter, in any order.
+// The pairs can still end up next to a letter that's the same, hence the
+// check.
+fn valid_password(rng: &mut StdRng) -> [u8; 8] {
+    loop {
+        let straight = loop {
+            let first = rng.random_range(b'a'..=b'x');
+            let straight = [first, first + 1, first + 2];
+            if !straight.iter().any(|letter| b"iol".contains(letter)) {
+                break straight;
+            }
+        };
+        let (first, second) = (letter(rng), letter(rng));
+
+        let mut blocks = [
+            straight.to_vec(),
+            vec![first; 2],
+            vec![second; 2],
+            vec![letter(rng)],
+        ];
+        blocks.shuffle(rng);
+
+        let password: [u8; 8] = blocks.concat().try_into().unwrap();
+        if first != second && Password(password.map(char::from)).is_valid() {
+            return password;
+        }
+    }
+}
+
+// The password that comes before, the opposite of Password::inc()
+fn dec(password: &mut [u8; 8]) {
+    for letter in password.iter_mut().rev() {
+        if *letter == b'a' {
+            *letter = b'z';
+        } else {
+            *letter -= 1;
+            return;
+        }
+    }
+}
+
+// A password `size` passwords before a valid one, so that there's never
+// more than `size` passwords to go through to get to the next valid one
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    let mut password = valid_password(&mut rng);
+    (0..size).for_each(|_| dec(&mut password));
+
+    String::from_utf8(password.to_vec()).unwrap() + "\n"
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::generate::*;
+
+    #[test]
+    fn generate_tests() {
+        let mut rng = StdRng::seed_from_u64(7);
+        (0..100)
+            .for_each(|_| assert!(Password(valid_password(&mut rng).map(char::from)).is_valid()));
+
+        let mut password = *b"abcdaaaa";
+        dec(&mut password);
+        assert_eq!(&password, b"abcczzzz");
+
+        let input = generate(1000, 7);
+        assert_eq!(input, generate(1000, 7));
+        assert_ne!(input, generate(1000, 8));
+
+        // The valid password that it was made from
+        let mut password = Password::try_from(input.trim()).unwrap();
+        (0..1000).for_each(|_| password.inc());
+        assert!(password.is_valid());
+    }
+}
@@ -0,0 +1,102 @@
+use aoc_common::{answers::Answer, parse::ParseError, Solution};
+use nom::error::ErrorKind;
+use serde_json::Value;
+use std::io::BufRead;
+
+// Sum all numbers but skip the objects whose values fulfill the is_bad_value
+// predicate. This way we can reuse the function for both parts.
+fn sum_all_numbers(node: &Value, is_bad_value: fn(&Value) -> bool) -> i64 {
+    match node {
+        Value::Null => 0,
+        Value::Bool(_) => 0,
+        Value::Number(number) => number.as_i64().unwrap(),
+        Value::String(_) => 0,
+        Value::Array(array) => array
+            .iter()
+            .fold(0, |acc, elem| acc + sum_all_numbers(elem, is_bad_value)),
+        Value::Object(object) => {
+            if object.values().any(is_bad_value) {
+                0
+            } else {
+                object
+                    .values()
+                    .fold(0, |acc, elem| acc + sum_all_numbers(elem, is_bad_value))
+            }
+        }
+    }
+}
+
+// serde_json knows where the JSON went wrong, which is all a ParseError needs.
+// At the very end of the input the column is 0, which is put on the last
+// character instead.
+fn parse_input(input: &mut impl BufRead) -> Result<Value, ParseError> {
+    let text = aoc_common::read_input(input)?;
+
+    serde_json::from_str(&text).map_err(|e| ParseError {
+        line: e.line(),
+        column: e.column().max(1),
+        kind: ErrorKind::Fail,
+        text: text
+            .lines()
+            .nth(e.line().saturating_sub(1))
+            .unwrap_or_default()
+            .to_string(),
+        expected: None,
+    })
+}
+
+fn part1(json_tree: &Value) -> Answer {
+    sum_all_numbers(json_tree, |_| false).into()
+}
+
+fn part2(json_tree: &Value) -> Answer {
+    sum_all_numbers(
+        json_tree,
+        |value| matches!(value, Value::String(s) if s.eq("red")),
+    )
+    .into()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 12;
+
+    type Input = Value;
+
+    fn parse(input: &mut impl BufRead) -> Result<Value, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Value) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Value) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day12>();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day12>();
+    }
+}
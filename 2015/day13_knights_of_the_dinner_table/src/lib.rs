@@ -0,0 +1,139 @@
+use aoc_common::{
+    answers::Answer,
+    parse::{self, ParseError},
+    scan, Solution,
+};
+use itertools::Itertools;
+use log::debug;
+use nom::{branch::alt, bytes::complete::tag, combinator::value};
+use std::{
+    collections::{HashMap, HashSet},
+    io::BufRead,
+};
+
+type HappinessChanges = HashMap<(String, String), i32>;
+
+fn parse_input(
+    input: &mut impl BufRead,
+) -> Result<(HashSet<String>, HappinessChanges), ParseError> {
+    let mut people: HashSet<String> = HashSet::new();
+    let mut happiness_changes: HappinessChanges = HashMap::new();
+
+    let lines: Vec<_> = parse::parse_lines(input, |line| {
+        scan!(
+            line,
+            {person1: alpha1} " would "
+            {sign: alt((value(1, tag("gain")), value(-1, tag("lose"))))}
+            " " {happiness_amount: i32} " happiness units by sitting next to "
+            {person2: alpha1} "."
+            => (person1.to_string(), person2.to_string(), sign * happiness_amount)
+        )
+    })?;
+    let end = lines.len() + 1;
+
+    lines
+        .into_iter()
+        .for_each(|(person1, person2, happiness_change)| {
+            happiness_changes.insert((person1.clone(), person2.clone()), happiness_change);
+            people.insert(person1);
+            people.insert(person2);
+        });
+
+    debug!("{:?}", people);
+    debug!("{:?}", happiness_changes);
+
+    // Everyone has to say how they feel about everyone else
+    if people.is_empty() || happiness_changes.len() != people.len() * (people.len() - 1) {
+        return Err(ParseError::missing(end, "a line for each pair of guests"));
+    }
+
+    Ok((people, happiness_changes))
+}
+
+fn calculate_happiness(
+    people: &HashSet<String>,
+    happiness_changes: &HashMap<(String, String), i32>,
+) -> Answer {
+    people
+        .iter()
+        .permutations(people.len())
+        .map(|permutation| {
+            permutation
+                .iter()
+                .circular_tuple_windows()
+                .fold(0, |happiness, (p1, p2)| {
+                    happiness
+                        + happiness_changes
+                            .get(&(p1.to_string(), p2.to_string()))
+                            .unwrap()
+                        + happiness_changes
+                            .get(&(p2.to_string(), p1.to_string()))
+                            .unwrap()
+                })
+        })
+        .max()
+        .unwrap()
+        .into()
+}
+
+fn part1((people, happiness_changes): &(HashSet<String>, HappinessChanges)) -> Answer {
+    calculate_happiness(people, happiness_changes)
+}
+
+fn part2((people, happiness_changes): &(HashSet<String>, HappinessChanges)) -> Answer {
+    let mut people = people.clone();
+    let mut happiness_changes = happiness_changes.clone();
+
+    people.iter().for_each(|person| {
+        happiness_changes.insert((person.to_string(), "Me".to_string()), 0);
+        happiness_changes.insert(("Me".to_string(), person.to_string()), 0);
+    });
+
+    people.insert("Me".to_string());
+
+    calculate_happiness(&people, &happiness_changes)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 13;
+
+    type Input = (HashSet<String>, HappinessChanges);
+
+    fn parse(input: &mut impl BufRead) -> Result<(HashSet<String>, HappinessChanges), ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &(HashSet<String>, HappinessChanges)) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &(HashSet<String>, HappinessChanges)) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day13>();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day13>();
+    }
+}
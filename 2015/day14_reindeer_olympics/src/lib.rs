@@ -0,0 +1,186 @@
+use aoc_common::{
+    answers::Answer,
+    examples,
+    parse::{self, ParseError},
+    scan, Solution,
+};
+use std::io::BufRead;
+
+#[derive(Clone, Debug)]
+pub struct Reindeer {
+    speed: u32,
+    flight_duration: u32,
+    rest_duration: u32,
+    points: u32,
+    current_position: u32,
+}
+
+impl TryFrom<&str> for Reindeer {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        scan!(
+            input,
+            {_: alpha1} " can fly " {speed: u32} " km/s for " {flight_duration: u32}
+            " seconds, but then must rest for " {rest_duration: u32} " seconds."
+            => Reindeer {
+                speed,
+                flight_duration,
+                rest_duration,
+                points: 0,
+                current_position: 0,
+            }
+        )
+    }
+}
+
+fn parse_input(input: &mut impl BufRead) -> Result<Vec<Reindeer>, ParseError> {
+    parse::parse_lines1(input, "a reindeer", |line| Reindeer::try_from(line))
+}
+
+fn simulate_second(reindeer: &mut [Reindeer], second: u32) {
+    let mut max_distance = 0;
+
+    reindeer.iter_mut().for_each(|reindeer| {
+        //               full sprint                       full sprint
+        // ...<--------------------------------><-------------------------------->...
+        // ...<---------------><---------------><---------------><--------------->...
+        //     flight_duration   rest_duration   flight_duration   rest_duration
+        let full_sprints = second / (reindeer.flight_duration + reindeer.rest_duration);
+
+        //                                            /-- seconds_in_last_sprint
+        //                                            |
+        //               full sprint                  v     last sprint
+        // ...<--------------------------------><----------->
+        // ...<---------------><---------------><---------------><--------------->
+        //     flight_duration   rest_duration   flight_duration   rest_duration
+        //
+        //                                            /-- seconds_in_last_sprint
+        //                                            |
+        //               full sprint                  v     last sprint
+        // ...<--------------------------------><-------------------->
+        // ...<---------------><---------------><---------------><--------------->
+        //     flight_duration   rest_duration   flight_duration   rest_duration
+        let seconds_in_last_sprint = second % (reindeer.flight_duration + reindeer.rest_duration);
+
+        // Keep in mind that distance is covered only during flight_duration
+        reindeer.current_position = if seconds_in_last_sprint < reindeer.flight_duration {
+            (full_sprints * reindeer.flight_duration + seconds_in_last_sprint) * reindeer.speed
+        } else {
+            (full_sprints * reindeer.flight_duration + reindeer.flight_duration) * reindeer.speed
+        };
+
+        if reindeer.current_position > max_distance {
+            max_distance = reindeer.current_position;
+        }
+    });
+
+    // Multiple reindeer can be tied for the lead
+    reindeer
+        .iter_mut()
+        .filter(|reindeer| reindeer.current_position == max_distance)
+        .for_each(|reindeer| reindeer.points += 1);
+}
+
+fn part1(reindeer: &[Reindeer], duration: u32) -> Answer {
+    let mut reindeer = reindeer.to_vec();
+
+    simulate_second(&mut reindeer, duration);
+
+    reindeer
+        .iter()
+        .max_by_key(|reindeer| reindeer.current_position)
+        .unwrap()
+        .current_position
+        .into()
+}
+
+fn part2(reindeer: &[Reindeer], duration: u32) -> Answer {
+    let mut reindeer = reindeer.to_vec();
+
+    (1..=duration).for_each(|second| {
+        simulate_second(&mut reindeer, second);
+    });
+
+    reindeer
+        .iter()
+        .max_by_key(|reindeer| reindeer.points)
+        .unwrap()
+        .points
+        .into()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 14;
+
+    // The reindeer, and how many seconds they race for
+    type Input = (Vec<Reindeer>, u32);
+
+    fn parse(input: &mut impl BufRead) -> Result<(Vec<Reindeer>, u32), ParseError> {
+        Ok((parse_input(input)?, 2503))
+    }
+
+    fn set_parameter(
+        (_, duration): &mut (Vec<Reindeer>, u32),
+        name: &str,
+        value: &str,
+    ) -> Result<(), String> {
+        match name {
+            "seconds" => *duration = examples::parameter(name, value)?,
+            _ => return Err(format!("unknown parameter {}", name)),
+        }
+        Ok(())
+    }
+
+    fn part1((reindeer, duration): &(Vec<Reindeer>, u32)) -> Result<Answer, ParseError> {
+        Ok(part1(reindeer, *duration))
+    }
+
+    fn part2((reindeer, duration): &(Vec<Reindeer>, u32)) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(reindeer, *duration)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::io::Cursor;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day14>();
+    }
+
+    #[test]
+    fn malformed_input_tests() {
+        init();
+
+        let error = parse_input(&mut Cursor::new(
+            "Comet can fly 14 km/s for 10 seconds, but then must rest for 127 seconds.\n\
+             Dancer can fly 16 km/s for ten seconds, but then must rest for 162 seconds.\n",
+        ))
+        .unwrap_err();
+
+        assert_eq!((error.line, error.column), (2, 28));
+        assert_eq!(
+            error.text,
+            "Dancer can fly 16 km/s for ten seconds, but then must rest for 162 seconds."
+        );
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day14>();
+    }
+}
@@ -0,0 +1,223 @@
+use aoc_common::{
+    answers::Answer,
+    parse::{self, ParseError},
+    scan, Solution,
+};
+use generator::{done, Gn};
+use log::debug;
+use std::io::BufRead;
+
+// Macro that defines a generator that generates all permutations
+// of two positive integers whose sum is $sum
+macro_rules! permutations2 {
+    ($sum: ident) => {{
+        Gn::new_scoped(move |mut s| {
+            for i in 0..=$sum {
+                let j = $sum - i;
+                s.yield_([i, j]);
+            }
+            done!();
+        })
+    }};
+}
+
+// Macro that defines a generator that generates all permutations
+// of four positive integers whose sum is $sum
+macro_rules! permutations4 {
+    ($sum: ident) => {{
+        Gn::new_scoped(move |mut s| {
+            for i in 0..=$sum {
+                for j in 0..=$sum - i {
+                    for k in 0..=$sum - i - j {
+                        let l = $sum - i - j - k;
+                        s.yield_([i, j, k, l]);
+                    }
+                }
+            }
+            done!();
+        })
+    }};
+}
+
+#[derive(Debug)]
+pub struct Ingredient {
+    capacity: i32,
+    durability: i32,
+    flavor: i32,
+    texture: i32,
+    calories: i32,
+}
+
+fn parse_input(input: &mut impl BufRead) -> Result<Vec<Ingredient>, ParseError> {
+    parse::parse_lines1(input, "an ingredient", |line| {
+        scan!(
+            line,
+            {_: alpha1} ": capacity " {capacity: i32} ", durability " {durability: i32}
+            ", flavor " {flavor: i32} ", texture " {texture: i32} ", calories " {calories: i32}
+            => Ingredient {
+                capacity,
+                durability,
+                flavor,
+                texture,
+                calories,
+            }
+        )
+    })
+}
+
+fn calculate_score_and_calories(ingredients: &[Ingredient], amounts: &[i32]) -> Option<(i32, i32)> {
+    // The function works for any number of ingredients but the ingredients
+    // and amounts slices have to be of the same size
+    if ingredients.len() != amounts.len() {
+        return None;
+    }
+
+    // Taking the example input, this would be:
+    //
+    // ingredients:
+    // [
+    //  Ingredient {capacity -1, durability -2, flavor  6, texture  3, calories 8}
+    //  Ingredient {capacity  2, durability  3, flavor -2, texture -1, calories 3}
+    // ]
+    //
+    // amounts:
+    // [
+    //  44,
+    //  56
+    // ]
+
+    let (mut capacity, mut durability, mut flavor, mut texture, calories) = ingredients
+        .iter()
+        .zip(amounts.iter())
+        // ingredients.zip(amounts):
+        // [
+        //  (Ingredient {capacity -1, durability -2, flavor  6, texture  3, calories 8}, 44)
+        //  (Ingredient {capacity  2, durability  3, flavor -2, texture -1, calories 3}, 56)
+        // ]
+        //
+        .map(|(ingredient, amount)| {
+            (
+                ingredient.capacity * amount,
+                ingredient.durability * amount,
+                ingredient.flavor * amount,
+                ingredient.texture * amount,
+                ingredient.calories * amount,
+            )
+        })
+        // ingredients.zip(amounts).map:
+        // [
+        //  (-44, -88,  264, 132, 352),
+        //  (112, 168, -112, -56, 168)
+        // ]
+        //
+        .fold((0, 0, 0, 0, 0), |acc, score| {
+            (
+                acc.0 + score.0,
+                acc.1 + score.1,
+                acc.2 + score.2,
+                acc.3 + score.3,
+                acc.4 + score.4,
+            )
+        });
+    // ingredients.zip(amounts).map.fold:
+    // [
+    //  (68, 80, 152, 76, 520)
+    // ]
+
+    capacity = capacity.clamp(0, i32::MAX);
+    durability = durability.clamp(0, i32::MAX);
+    flavor = flavor.clamp(0, i32::MAX);
+    texture = texture.clamp(0, i32::MAX);
+
+    Some((capacity * durability * flavor * texture, calories))
+}
+
+fn part1(ingredients: &[Ingredient], teaspoons: i32) -> Answer {
+    debug!("{:?}", ingredients);
+
+    if ingredients.len() == 2 {
+        permutations2!(teaspoons)
+            .map(|amounts| {
+                let (score, _) = calculate_score_and_calories(ingredients, &amounts).unwrap();
+                score
+            })
+            .max()
+            .unwrap()
+            .into()
+    } else if ingredients.len() == 4 {
+        permutations4!(teaspoons)
+            .map(|amounts| {
+                let (score, _) = calculate_score_and_calories(ingredients, &amounts).unwrap();
+                score
+            })
+            .max()
+            .unwrap()
+            .into()
+    } else {
+        unreachable!()
+    }
+}
+
+fn part2(ingredients: &[Ingredient], teaspoons: i32) -> Answer {
+    if ingredients.len() == 2 {
+        permutations2!(teaspoons)
+            .map(|amounts| calculate_score_and_calories(ingredients, &amounts).unwrap())
+            .filter_map(|(score, calories)| if calories == 500 { Some(score) } else { None })
+            .max()
+            .unwrap()
+            .into()
+    } else if ingredients.len() == 4 {
+        permutations4!(teaspoons)
+            .map(|amounts| calculate_score_and_calories(ingredients, &amounts).unwrap())
+            .filter_map(|(score, calories)| if calories == 500 { Some(score) } else { None })
+            .max()
+            .unwrap()
+            .into()
+    } else {
+        unreachable!()
+    }
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 15;
+
+    type Input = Vec<Ingredient>;
+
+    fn parse(input: &mut impl BufRead) -> Result<Vec<Ingredient>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Vec<Ingredient>) -> Result<Answer, ParseError> {
+        Ok(part1(input, 100))
+    }
+
+    fn part2(input: &Vec<Ingredient>) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input, 100)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day15>();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day15>();
+    }
+}
@@ -0,0 +1,188 @@
+use aoc_common::{
+    answers::Answer,
+    parse::{self, ParseError},
+    Solution,
+};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete,
+    sequence::{pair, terminated},
+    IResult, Parser,
+};
+use std::{collections::HashMap, io::BufRead};
+
+#[derive(Debug)]
+pub struct Aunt {
+    id: u32,
+    properties: HashMap<String, u32>,
+}
+
+impl TryFrom<&str> for Aunt {
+    type Error = ParseError;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let mut properties = HashMap::new();
+
+        fn property_parser(input: &str) -> IResult<&str, (&str, u32)> {
+            pair(
+                alt((
+                    terminated(tag("children"), tag(": ")),
+                    terminated(tag("cats"), tag(": ")),
+                    terminated(tag("samoyeds"), tag(": ")),
+                    terminated(tag("pomeranians"), tag(": ")),
+                    terminated(tag("akitas"), tag(": ")),
+                    terminated(tag("vizslas"), tag(": ")),
+                    terminated(tag("goldfish"), tag(": ")),
+                    terminated(tag("trees"), tag(": ")),
+                    terminated(tag("cars"), tag(": ")),
+                    terminated(tag("perfumes"), tag(": ")),
+                )),
+                complete::u32,
+            )
+            .parse(input)
+        }
+
+        let (_, id, _, property1, _, property2, _, property3) = parse::parse(
+            value,
+            (
+                tag("Sue "),
+                complete::u32,
+                tag(": "),
+                property_parser,
+                tag(", "),
+                property_parser,
+                tag(", "),
+                property_parser,
+            ),
+        )?;
+
+        [property1, property2, property3]
+            .iter()
+            .for_each(|(property, amount)| {
+                properties.insert(property.to_string(), *amount);
+            });
+
+        Ok(Self { id, properties })
+    }
+}
+
+// Make sure that the comparison works no matter which aunt has more properties
+impl PartialEq for Aunt {
+    fn eq(&self, other: &Self) -> bool {
+        if self.properties.len() <= other.properties.len() {
+            self.properties.iter().all(|(property, amount)| {
+                other
+                    .properties
+                    .get(property)
+                    .is_some_and(|&other_amount| other_amount == *amount)
+            })
+        } else {
+            other
+                .properties
+                .iter()
+                .all(|(other_property, other_amount)| {
+                    self.properties
+                        .get(other_property)
+                        .is_some_and(|&amount| amount == *other_amount)
+                })
+        }
+    }
+}
+
+fn parse_input(input: &mut impl BufRead) -> Result<Vec<Aunt>, ParseError> {
+    parse::parse_lines1(input, "an aunt", |line| Aunt::try_from(line))
+}
+
+// Find which aunt in aunts is aunt Sue, use aunt_comparsion_method for comparison
+fn find_aunt_sue(
+    aunts: &[Aunt],
+    aunt_comparison_method: fn(&Aunt, &Aunt) -> bool,
+) -> Result<Answer, ParseError> {
+    let aunt_sue = Aunt {
+        id: u32::MAX,
+        properties: HashMap::from([
+            ("children".to_string(), 3),
+            ("cats".to_string(), 7),
+            ("samoyeds".to_string(), 2),
+            ("pomeranians".to_string(), 3),
+            ("akitas".to_string(), 0),
+            ("vizslas".to_string(), 0),
+            ("goldfish".to_string(), 5),
+            ("trees".to_string(), 3),
+            ("cars".to_string(), 2),
+            ("perfumes".to_string(), 1),
+        ]),
+    };
+
+    aunts
+        .iter()
+        .find(|aunt| aunt_comparison_method(aunt, &aunt_sue))
+        .map(|aunt| aunt.id.into())
+        .ok_or_else(|| ParseError::no_answer("none of the aunts match the MFCSAM"))
+}
+
+fn part1(aunts: &[Aunt]) -> Result<Answer, ParseError> {
+    find_aunt_sue(aunts, |aunt1, aunt2| aunt1 == aunt2)
+}
+
+fn part2(aunts: &[Aunt]) -> Result<Answer, ParseError> {
+    find_aunt_sue(aunts, |aunt1, aunt2| {
+        aunt1
+            .properties
+            .iter()
+            .all(
+                |(aunt1_property, aunt1_amount)| match aunt1_property.as_str() {
+                    "cats" | "trees" => aunt2
+                        .properties
+                        .get(aunt1_property)
+                        .is_some_and(|&aunt2_amount| aunt2_amount < *aunt1_amount),
+                    "pomeranians" | "goldfish" => aunt2
+                        .properties
+                        .get(aunt1_property)
+                        .is_some_and(|&aunt2_amount| aunt2_amount > *aunt1_amount),
+                    _ => aunt2
+                        .properties
+                        .get(aunt1_property)
+                        .is_some_and(|&aunt2_amount| aunt2_amount == *aunt1_amount),
+                },
+            )
+    })
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 16;
+
+    type Input = Vec<Aunt>;
+
+    fn parse(input: &mut impl BufRead) -> Result<Vec<Aunt>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Vec<Aunt>) -> Result<Answer, ParseError> {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<Aunt>) -> Option<Result<Answer, ParseError>> {
+        Some(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day16>();
+    }
+}
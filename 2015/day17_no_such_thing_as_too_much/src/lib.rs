@@ -0,0 +1,162 @@
+use aoc_common::{
+    answers::Answer,
+    examples,
+    parse::{self, ParseError},
+    Solution,
+};
+use itertools::Itertools;
+use nom::character::complete;
+use std::io::BufRead;
+
+fn parse_input(input: &mut impl BufRead) -> Result<Vec<u32>, ParseError> {
+    parse::parse_lines(input, |line| parse::parse(line, complete::u32))
+}
+
+// Given an amount of liters and a bunch of containers, return all of
+// the different combinations of containers which fit exactly the
+// amount of liters
+fn fill_containers(liters: u32, containers: &[u32]) -> Vec<Vec<u32>> {
+    // State space generation/search, in each node we have the following branches:
+    // - fill the current container
+    //   - if no liters left, solution is found
+    //   - if liters left, proceed to fill the remaining containers
+    // - ignore the current container and proceed to fill the rest
+    //
+    // current_choices is used to remember the containers we filled so far
+    fn fill_containers_rec(
+        liters: u32,
+        sorted_containers: &[u32],
+        index: usize,
+        current_choices: &mut Vec<u32>,
+        solutions: &mut Vec<Vec<u32>>,
+    ) {
+        if sorted_containers.len() != index {
+            // Fill the current container
+            match liters.cmp(&sorted_containers[index]) {
+                std::cmp::Ordering::Equal => {
+                    // No liters left after filling the container, we found a solution
+                    let mut solution = current_choices.clone();
+                    solution.push(sorted_containers[index]);
+                    solutions.push(solution);
+                }
+                std::cmp::Ordering::Greater => {
+                    // Proceed to fill the other containers using the remaining liters
+                    let mut new_choices = current_choices.clone();
+                    new_choices.push(sorted_containers[index]);
+                    fill_containers_rec(
+                        liters - sorted_containers[index],
+                        sorted_containers,
+                        index + 1,
+                        &mut new_choices,
+                        solutions,
+                    );
+                }
+                std::cmp::Ordering::Less => {
+                    // Not enough liters to fill the container, don't do anything
+                }
+            }
+
+            // Try and see if there are other solutions obtained by skipping the current container
+            //
+            // Rely on the fact that we sort the containers array so avoid generating/searching
+            // the state space if the current liters amount doesn't fill the smallest remaining
+            // container
+            if liters >= *sorted_containers.iter().last().unwrap() {
+                fill_containers_rec(
+                    liters,
+                    sorted_containers,
+                    index + 1,
+                    current_choices,
+                    solutions,
+                );
+            }
+        }
+    }
+
+    // Create a new slice with the containers sorted in descending order
+    let mut sorted_containers = vec![0; containers.len()];
+    sorted_containers.clone_from_slice(containers);
+    sorted_containers.sort();
+    sorted_containers.reverse();
+
+    let mut solutions: Vec<Vec<u32>> = Vec::new();
+
+    fill_containers_rec(
+        liters,
+        &sorted_containers,
+        0,
+        &mut Vec::new(),
+        &mut solutions,
+    );
+
+    solutions
+}
+
+fn part1(containers: &[u32], liters: u32) -> Answer {
+    fill_containers(liters, containers).len().into()
+}
+
+fn part2(containers: &[u32], liters: u32) -> Answer {
+    fill_containers(liters, containers)
+        .iter()
+        .min_set_by_key(|solution| solution.len())
+        .len()
+        .into()
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 17;
+
+    // The containers, and how many liters of eggnog have to fit in them
+    type Input = (Vec<u32>, u32);
+
+    fn parse(input: &mut impl BufRead) -> Result<(Vec<u32>, u32), ParseError> {
+        Ok((parse_input(input)?, 150))
+    }
+
+    fn set_parameter(
+        (_, liters): &mut (Vec<u32>, u32),
+        name: &str,
+        value: &str,
+    ) -> Result<(), String> {
+        match name {
+            "liters" => *liters = examples::parameter(name, value)?,
+            _ => return Err(format!("unknown parameter {}", name)),
+        }
+        Ok(())
+    }
+
+    fn part1((containers, liters): &(Vec<u32>, u32)) -> Result<Answer, ParseError> {
+        Ok(part1(containers, *liters))
+    }
+
+    fn part2((containers, liters): &(Vec<u32>, u32)) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(containers, *liters)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day17>();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day17>();
+    }
+}
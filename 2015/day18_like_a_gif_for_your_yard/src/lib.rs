@@ -0,0 +1,150 @@
+use aoc_common::{answers::Answer, examples, grid::Grid, parse::ParseError, Solution};
+use std::io::BufRead;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum LightState {
+    On,
+    Off,
+}
+
+impl LightState {
+    fn parse(value: char) -> Option<Self> {
+        match value {
+            '#' => Some(LightState::On),
+            '.' => Some(LightState::Off),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Lights {
+    lights: Grid<LightState>,
+    old_lights: Grid<LightState>,
+}
+
+impl Lights {
+    fn new(lights: &Grid<LightState>) -> Self {
+        Lights {
+            lights: lights.clone(),
+            old_lights: lights.clone(),
+        }
+    }
+
+    fn step(&mut self) {
+        std::mem::swap(&mut self.lights, &mut self.old_lights);
+
+        for position in self.old_lights.positions() {
+            let neighbours_on = self
+                .old_lights
+                .neighbours8(position)
+                .filter(|&neighbour| self.old_lights[neighbour] == LightState::On)
+                .count();
+
+            self.lights[position] = match (&self.old_lights[position], neighbours_on) {
+                (LightState::On, 2 | 3) | (LightState::Off, 3) => LightState::On,
+                _ => LightState::Off,
+            }
+        }
+    }
+
+    fn light_corners(&mut self) {
+        let (last_row, last_column) = (self.lights.nrows() - 1, self.lights.ncols() - 1);
+
+        for corner in [
+            (0, 0),
+            (0, last_column),
+            (last_row, 0),
+            (last_row, last_column),
+        ] {
+            self.lights[corner] = LightState::On;
+        }
+    }
+
+    fn count_lights_on(&self) -> usize {
+        self.lights.find_all(&LightState::On).count()
+    }
+}
+
+fn parse_input(input: &mut impl BufRead) -> Result<Grid<LightState>, ParseError> {
+    Grid::parse(input, LightState::parse)
+}
+
+fn part1(lights: &Grid<LightState>, steps: usize) -> Answer {
+    let mut lights = Lights::new(lights);
+
+    for _ in 1..=steps {
+        lights.step();
+    }
+
+    lights.count_lights_on().into()
+}
+
+fn part2(lights: &Grid<LightState>, steps: usize) -> Answer {
+    let mut lights = Lights::new(lights);
+
+    lights.light_corners();
+    for _ in 1..=steps {
+        lights.step();
+        lights.light_corners();
+    }
+
+    lights.count_lights_on().into()
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 18;
+
+    // The lights, and how many steps they're animated for
+    type Input = (Grid<LightState>, usize);
+
+    fn parse(input: &mut impl BufRead) -> Result<(Grid<LightState>, usize), ParseError> {
+        Ok((parse_input(input)?, 100))
+    }
+
+    fn set_parameter(
+        (_, steps): &mut (Grid<LightState>, usize),
+        name: &str,
+        value: &str,
+    ) -> Result<(), String> {
+        match name {
+            "steps" => *steps = examples::parameter(name, value)?,
+            _ => return Err(format!("unknown parameter {}", name)),
+        }
+        Ok(())
+    }
+
+    fn part1((lights, steps): &(Grid<LightState>, usize)) -> Result<Answer, ParseError> {
+        Ok(part1(lights, *steps))
+    }
+
+    fn part2((lights, steps): &(Grid<LightState>, usize)) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(lights, *steps)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day18>();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day18>();
+    }
+}
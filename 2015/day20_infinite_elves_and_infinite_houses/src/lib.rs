@@ -0,0 +1,75 @@
+use aoc_common::{
+    answers::Answer,
+    math,
+    parse::{self, ParseError},
+    Solution,
+};
+use nom::character::complete;
+use std::io::BufRead;
+
+fn parse_input(input: &mut impl BufRead) -> Result<u64, ParseError> {
+    let text = aoc_common::read_input(input)?;
+
+    parse::parse(text.trim_end(), complete::u64)
+}
+
+// Every elf delivers to house n when it delivers to every house, so house
+// presents / presents_per_elf (rounded up) gets at least what's needed and
+// the houses past that one don't have to be looked at
+fn first_house(presents: u64, presents_per_elf: u64, sums: impl Fn(usize) -> Vec<u64>) -> Answer {
+    let sums = sums(presents.div_ceil(presents_per_elf).max(1) as usize);
+
+    sums.iter()
+        .position(|sum| sum * presents_per_elf >= presents)
+        .unwrap()
+        .into()
+}
+
+fn part1(presents: u64) -> Answer {
+    first_house(presents, 10, math::divisor_sums)
+}
+
+// Each elf stops after 50 houses, so elf d only gets to house n when n is
+// at most 50 times d
+fn part2(presents: u64) -> Answer {
+    first_house(presents, 11, |limit| {
+        math::divisor_sums_by(limit, |d, n| if n / d <= 50 { d } else { 0 })
+    })
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 20;
+
+    type Input = u64;
+
+    fn parse(input: &mut impl BufRead) -> Result<u64, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &u64) -> Result<Answer, ParseError> {
+        Ok(part1(*input))
+    }
+
+    fn part2(input: &u64) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(*input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day20>();
+    }
+}
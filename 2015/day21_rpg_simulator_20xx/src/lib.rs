@@ -0,0 +1,283 @@
+use aoc_common::{answers::Answer, parse::ParseError, scan, Solution};
+use itertools::{iproduct, Itertools};
+use std::io::BufRead;
+
+#[derive(Debug)]
+pub struct Unit {
+    hp: i32,
+    damage: i32,
+    armor: i32,
+}
+
+#[derive(Debug, Clone)]
+struct Item {
+    cost: usize,
+    damage: i32,
+    armor: i32,
+}
+
+#[derive(Debug, PartialEq)]
+enum FightResult {
+    PlayerWins,
+    BossWins,
+}
+
+struct Shop {
+    weapons: [Item; 5],
+    armor: [Item; 6],
+    rings: [Item; 8],
+}
+
+// The trick is to add (0,0,0) Items in order to emulate the lack
+// of that particular item in a potential combination. That's why
+// we have one such entry for armor (for combinations with no
+// armor) and two entries for rings (for combinations with no rings
+// or one ring)
+const SHOP: Shop = Shop {
+    weapons: [
+        Item {
+            cost: 8,
+            damage: 4,
+            armor: 0,
+        },
+        Item {
+            cost: 10,
+            damage: 5,
+            armor: 0,
+        },
+        Item {
+            cost: 25,
+            damage: 6,
+            armor: 0,
+        },
+        Item {
+            cost: 40,
+            damage: 7,
+            armor: 0,
+        },
+        Item {
+            cost: 74,
+            damage: 8,
+            armor: 0,
+        },
+    ],
+    armor: [
+        Item {
+            cost: 0,
+            damage: 0,
+            armor: 0,
+        },
+        Item {
+            cost: 13,
+            damage: 0,
+            armor: 1,
+        },
+        Item {
+            cost: 31,
+            damage: 0,
+            armor: 2,
+        },
+        Item {
+            cost: 53,
+            damage: 0,
+            armor: 3,
+        },
+        Item {
+            cost: 75,
+            damage: 0,
+            armor: 4,
+        },
+        Item {
+            cost: 102,
+            damage: 0,
+            armor: 5,
+        },
+    ],
+    rings: [
+        Item {
+            cost: 0,
+            damage: 0,
+            armor: 0,
+        },
+        Item {
+            cost: 0,
+            damage: 0,
+            armor: 0,
+        },
+        Item {
+            cost: 25,
+            damage: 1,
+            armor: 0,
+        },
+        Item {
+            cost: 50,
+            damage: 2,
+            armor: 0,
+        },
+        Item {
+            cost: 100,
+            damage: 3,
+            armor: 0,
+        },
+        Item {
+            cost: 20,
+            damage: 0,
+            armor: 1,
+        },
+        Item {
+            cost: 40,
+            damage: 0,
+            armor: 2,
+        },
+        Item {
+            cost: 80,
+            damage: 0,
+            armor: 3,
+        },
+    ],
+};
+
+impl Unit {
+    fn equip(&mut self, (weapon, armor, ring1, ring2): &(&Item, &Item, &Item, &Item)) {
+        self.damage += weapon.damage + ring1.damage + ring2.damage;
+        self.armor += armor.armor + ring1.armor + ring2.armor;
+    }
+
+    fn heal_and_remove_equipment(&mut self) {
+        self.hp = 100;
+        self.damage = 0;
+        self.armor = 0;
+    }
+}
+
+fn parse_input(input: &mut impl BufRead) -> Result<Unit, ParseError> {
+    let boss_stats = aoc_common::read_input(input)?;
+
+    scan!(
+        boss_stats.trim_end(),
+        "Hit Points: " {hp: i32} "\nDamage: " {damage: i32} "\nArmor: " {armor: i32}
+        => Unit { hp, damage, armor }
+    )
+}
+
+// The entire loop could be removed and replaced with some simple division
+// but I like the roleplaying aspect of this :)
+fn fight(player: &Unit, boss: &Unit) -> FightResult {
+    let mut player_hp = player.hp;
+    let mut boss_hp = boss.hp;
+
+    loop {
+        boss_hp -= (player.damage - boss.armor).clamp(1, i32::MAX);
+        if boss_hp <= 0 {
+            return FightResult::PlayerWins;
+        }
+        player_hp -= (boss.damage - player.armor).clamp(1, i32::MAX);
+        if player_hp <= 0 {
+            return FightResult::BossWins;
+        }
+    }
+}
+
+// Given the boss defined in the input and the fight result
+// get the cost of all combination of items which lead to
+// that fight result with the input boss
+fn cost_of_item_combinations_with_fight_result(
+    boss: &Unit,
+    fight_result: FightResult,
+) -> Vec<usize> {
+    let mut player = Unit {
+        hp: 100,
+        damage: 0,
+        armor: 0,
+    };
+
+    let item_combinations = iproduct!(&SHOP.weapons, &SHOP.armor, &SHOP.rings, &SHOP.rings);
+
+    item_combinations
+        .filter(|items| {
+            // This could be done in a better way but I like the image of a
+            // player fighting a respawning boss over and over and healing
+            // and changing equipment between rounds :)
+            player.equip(items);
+            let result = fight(&player, boss);
+            player.heal_and_remove_equipment();
+
+            result == fight_result
+        })
+        .map(|(weapon, armor, ring1, ring2)| weapon.cost + armor.cost + ring1.cost + ring2.cost)
+        .collect_vec()
+}
+
+fn part1(boss: &Unit) -> Answer {
+    cost_of_item_combinations_with_fight_result(boss, FightResult::PlayerWins)
+        .iter()
+        .min()
+        .unwrap()
+        .into()
+}
+
+fn part2(boss: &Unit) -> Answer {
+    cost_of_item_combinations_with_fight_result(boss, FightResult::BossWins)
+        .iter()
+        .max()
+        .unwrap()
+        .into()
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 21;
+
+    type Input = Unit;
+
+    fn parse(input: &mut impl BufRead) -> Result<Unit, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Unit) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Unit) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn test_fight() {
+        init();
+
+        assert_eq!(
+            fight(
+                &Unit {
+                    hp: 8,
+                    damage: 5,
+                    armor: 5
+                },
+                &Unit {
+                    hp: 12,
+                    damage: 7,
+                    armor: 2
+                }
+            ),
+            FightResult::PlayerWins
+        );
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day21>();
+    }
+}
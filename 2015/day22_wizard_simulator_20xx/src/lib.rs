@@ -0,0 +1,324 @@
+use aoc_common::{
+    answers::Answer,
+    parse::{self, ParseError},
+    search::{self, SearchProblem},
+    Solution,
+};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{self, line_ending, multispace0},
+    error::Error,
+    sequence::delimited,
+    IResult, Parser,
+};
+use std::{collections::BTreeMap, io::BufRead};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct Player {
+    hp: i32,
+    mana: i32,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Boss {
+    hp: i32,
+    damage: i32,
+}
+
+impl TryFrom<&str> for Boss {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        fn parse_stats(input: &str) -> IResult<&str, (i32, i32), Error<&str>> {
+            (
+                delimited(tag("Hit Points: "), complete::i32, line_ending),
+                delimited(tag("Damage: "), complete::i32, multispace0),
+            )
+                .parse(input)
+        }
+
+        let (hp, damage) = parse::parse(input, parse_stats)?;
+
+        Ok(Boss { hp, damage })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum Turn {
+    PlayerTurn,
+    BossTurn,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+struct State {
+    player: Player,
+    boss: Boss,
+    // This map makes the solution slower but I like it since it is more
+    // extensible. Not that aoc problems have that as a goal... just personal
+    // preference, I don't like hardcoding things if the effort of doing
+    // otherwise is not too big. It's a BTreeMap so that the states can be
+    // hashed by the search.
+    effects: BTreeMap<Effect, Duration>,
+    turn: Turn,
+}
+
+impl State {
+    fn handle_effects(&mut self) {
+        self.effects
+            .entry(Effect::Shield)
+            .and_modify(|duration| *duration -= 1);
+        self.effects.entry(Effect::Poison).and_modify(|duration| {
+            *duration -= 1;
+            self.boss.hp -= 3;
+        });
+        self.effects.entry(Effect::Recharge).and_modify(|duration| {
+            *duration -= 1;
+            self.player.mana += 101;
+        });
+
+        self.effects.retain(|_, duration| *duration > 0);
+    }
+}
+
+#[derive(Debug, Clone, Eq, PartialEq, Hash, PartialOrd, Ord)]
+enum Effect {
+    Shield,
+    Poison,
+    Recharge,
+}
+
+type Duration = u32;
+
+enum SpellType {
+    MagicMissile,
+    Drain,
+    Shield,
+    Poison,
+    Recharge,
+}
+
+struct Spell {
+    spell_type: SpellType,
+    cost: i32,
+}
+
+impl Spell {
+    fn can_be_cast(&self, state: &State) -> bool {
+        match self.spell_type {
+            SpellType::MagicMissile | SpellType::Drain => state.player.mana >= self.cost,
+            SpellType::Shield => {
+                state.player.mana >= self.cost && !state.effects.contains_key(&Effect::Shield)
+            }
+            SpellType::Poison => {
+                state.player.mana >= self.cost && !state.effects.contains_key(&Effect::Poison)
+            }
+            SpellType::Recharge => {
+                state.player.mana >= self.cost && !state.effects.contains_key(&Effect::Recharge)
+            }
+        }
+    }
+    fn cast(&self, state: &mut State) {
+        state.player.mana -= self.cost;
+        match self.spell_type {
+            SpellType::MagicMissile => {
+                state.boss.hp -= 4;
+            }
+            SpellType::Drain => {
+                state.player.hp += 2;
+                state.boss.hp -= 2;
+            }
+            SpellType::Shield => {
+                state.effects.insert(Effect::Shield, 6);
+            }
+            SpellType::Poison => {
+                state.effects.insert(Effect::Poison, 6);
+            }
+            SpellType::Recharge => {
+                state.effects.insert(Effect::Recharge, 5);
+            }
+        }
+    }
+}
+
+const SPELLBOOK: [Spell; 5] = [
+    Spell {
+        spell_type: SpellType::MagicMissile,
+        cost: 53,
+    },
+    Spell {
+        spell_type: SpellType::Drain,
+        cost: 73,
+    },
+    Spell {
+        spell_type: SpellType::Shield,
+        cost: 113,
+    },
+    Spell {
+        spell_type: SpellType::Poison,
+        cost: 173,
+    },
+    Spell {
+        spell_type: SpellType::Recharge,
+        cost: 229,
+    },
+];
+
+// The fight goes one turn at a time, the cost of the player's turns is the
+// mana that the spell they cast costs and the boss' turns are free
+struct Fight {
+    player: Player,
+    boss: Boss,
+    player_turn_life_loss: bool,
+}
+
+impl SearchProblem for Fight {
+    type State = State;
+
+    fn starts(&self) -> impl IntoIterator<Item = State> {
+        [State {
+            player: self.player.clone(),
+            boss: self.boss.clone(),
+            effects: BTreeMap::new(),
+            turn: Turn::PlayerTurn,
+        }]
+    }
+
+    fn successors(&self, state: &State) -> impl IntoIterator<Item = (State, u64)> {
+        let mut state = state.clone();
+        let mut next_states = Vec::new();
+
+        match state.turn {
+            Turn::PlayerTurn => {
+                if self.player_turn_life_loss {
+                    state.player.hp -= 1;
+                    if state.player.hp <= 0 {
+                        return next_states;
+                    }
+                }
+
+                state.handle_effects();
+                if state.boss.hp <= 0 {
+                    next_states.push((state, 0));
+                    return next_states;
+                }
+
+                // Go through all spells that can be cast (aka there is
+                // enough mana and they would not start an effect that is
+                // already active)
+                for spell in SPELLBOOK.iter().filter(|s| s.can_be_cast(&state)) {
+                    let mut new_state = state.clone();
+                    spell.cast(&mut new_state);
+                    new_state.turn = Turn::BossTurn;
+                    next_states.push((new_state, spell.cost as u64));
+                }
+            }
+            Turn::BossTurn => {
+                state.handle_effects();
+                if state.boss.hp > 0 {
+                    // Boss attacks
+                    if state.effects.contains_key(&Effect::Shield) {
+                        state.player.hp -= state.boss.damage - 7;
+                    } else {
+                        state.player.hp -= state.boss.damage;
+                    }
+                    state.turn = Turn::PlayerTurn;
+                }
+
+                if state.player.hp > 0 {
+                    next_states.push((state, 0));
+                }
+            }
+        }
+
+        next_states
+    }
+
+    fn is_goal(&self, state: &State) -> bool {
+        state.boss.hp <= 0
+    }
+}
+
+// Search for the cheapest sequence of spells that kills the boss
+fn find_least_mana_to_win(player: Player, boss: Boss, player_turn_life_loss: bool) -> i32 {
+    let fight = Fight {
+        player,
+        boss,
+        player_turn_life_loss,
+    };
+
+    search::dijkstra(&fight).unwrap().cost as i32
+}
+
+fn parse_input(input: &mut impl BufRead) -> Result<Boss, ParseError> {
+    Boss::try_from(aoc_common::read_input(input)?.as_str())
+}
+
+fn part1(boss: &Boss) -> Answer {
+    find_least_mana_to_win(Player { hp: 50, mana: 500 }, boss.clone(), false).into()
+}
+
+fn part2(boss: &Boss) -> Answer {
+    find_least_mana_to_win(Player { hp: 50, mana: 500 }, boss.clone(), true).into()
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 22;
+
+    type Input = Boss;
+
+    fn parse(input: &mut impl BufRead) -> Result<Boss, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Boss) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Boss) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn find_least_mana_to_win_tests() {
+        init();
+
+        // Although not explicitly stated, the examples in the problem text
+        // show the optimal choice of spells
+        assert_eq!(
+            find_least_mana_to_win(
+                Player { hp: 10, mana: 250 },
+                Boss { hp: 13, damage: 8 },
+                false
+            ),
+            226
+        );
+
+        assert_eq!(
+            find_least_mana_to_win(
+                Player { hp: 10, mana: 250 },
+                Boss { hp: 14, damage: 8 },
+                false
+            ),
+            641
+        );
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day22>();
+    }
+}
@@ -0,0 +1,285 @@
+use aoc_common::{
+    answers::Answer,
+    parse::{self, ParseError},
+    Solution,
+};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete,
+    sequence::{pair, terminated},
+    IResult, Parser,
+};
+use std::{
+    io::BufRead,
+    ops::{Index, IndexMut},
+};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Register {
+    A,
+    B,
+}
+
+impl From<char> for Register {
+    fn from(value: char) -> Self {
+        match value {
+            'a' => Register::A,
+            'b' => Register::B,
+            _ => unreachable!("Register is not supported"),
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Cpu {
+    a: u64,
+    b: u64,
+    pc: i64,
+}
+
+impl Index<&Register> for Cpu {
+    type Output = u64;
+
+    fn index(&self, index: &Register) -> &Self::Output {
+        match index {
+            Register::A => &self.a,
+            Register::B => &self.b,
+        }
+    }
+}
+
+impl IndexMut<&Register> for Cpu {
+    fn index_mut(&mut self, index: &Register) -> &mut Self::Output {
+        match index {
+            Register::A => &mut self.a,
+            Register::B => &mut self.b,
+        }
+    }
+}
+
+impl Cpu {
+    fn execute_instruction(&mut self, instr: &Instruction) {
+        match instr {
+            Instruction::Hlf(reg) => {
+                self[reg] /= 2;
+                self.pc += 1;
+            }
+            Instruction::Tpl(reg) => {
+                self[reg] *= 3;
+                self.pc += 1;
+            }
+            Instruction::Inc(reg) => {
+                self[reg] += 1;
+                self.pc += 1;
+            }
+            Instruction::Jmp(offset) => self.pc += offset,
+            Instruction::Jie(reg, offset) => {
+                if self[reg].is_multiple_of(2) {
+                    self.pc += offset;
+                } else {
+                    self.pc += 1;
+                }
+            }
+            Instruction::Jio(reg, offset) => {
+                if self[reg] == 1 {
+                    self.pc += offset;
+                } else {
+                    self.pc += 1;
+                }
+            }
+        }
+    }
+}
+
+#[derive(PartialEq, Eq, Debug)]
+pub enum Instruction {
+    Hlf(Register),
+    Tpl(Register),
+    Inc(Register),
+    Jmp(i64),
+    Jie(Register, i64),
+    Jio(Register, i64),
+}
+
+impl TryFrom<&str> for Instruction {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        fn hlf_tpl_inc_instruction(input: &str) -> IResult<&str, Instruction> {
+            pair(
+                alt((tag("hlf "), tag("tpl "), tag("inc "))),
+                complete::one_of("ab"),
+            )
+            .parse(input)
+            .map(|(s, (instr, reg))| {
+                (
+                    s,
+                    match instr {
+                        "hlf " => Instruction::Hlf(reg.into()),
+                        "tpl " => Instruction::Tpl(reg.into()),
+                        "inc " => Instruction::Inc(reg.into()),
+                        _ => unreachable!(),
+                    },
+                )
+            })
+        }
+
+        fn jie_jio_instruction(input: &str) -> IResult<&str, Instruction> {
+            (
+                alt((tag("jio "), tag("jie "))),
+                terminated(complete::one_of("ab"), tag(", ")),
+                complete::i64,
+            )
+                .parse(input)
+                .map(|(s, (instr, reg, offset))| {
+                    (
+                        s,
+                        match instr {
+                            "jio " => Instruction::Jio(reg.into(), offset),
+                            "jie " => Instruction::Jie(reg.into(), offset),
+                            _ => unreachable!(),
+                        },
+                    )
+                })
+        }
+
+        fn jmp_instruction(input: &str) -> IResult<&str, Instruction> {
+            pair(tag("jmp "), complete::i64)
+                .parse(input)
+                .map(|(s, (instr, offset))| {
+                    (
+                        s,
+                        match instr {
+                            "jmp " => Instruction::Jmp(offset),
+                            _ => unreachable!(),
+                        },
+                    )
+                })
+        }
+
+        parse::parse(
+            input,
+            alt((
+                hlf_tpl_inc_instruction,
+                jie_jio_instruction,
+                jmp_instruction,
+            )),
+        )
+    }
+}
+
+fn parse_input(input: &mut impl BufRead) -> Result<Vec<Instruction>, ParseError> {
+    parse::parse_lines(input, |line| Instruction::try_from(line))
+}
+
+fn run_program_with_starting_value_for_a(instructions: &[Instruction], value: u64) -> Answer {
+    let mut cpu = Cpu {
+        a: value,
+        b: 0,
+        pc: 0,
+    };
+
+    loop {
+        if cpu.pc >= 0 && cpu.pc < instructions.len().try_into().unwrap() {
+            cpu.execute_instruction(&instructions[TryInto::<usize>::try_into(cpu.pc).unwrap()]);
+        } else {
+            break;
+        }
+    }
+
+    cpu.b.into()
+}
+
+fn part1(instructions: &[Instruction]) -> Answer {
+    run_program_with_starting_value_for_a(instructions, 0)
+}
+
+fn part2(instructions: &[Instruction]) -> Answer {
+    run_program_with_starting_value_for_a(instructions, 1)
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 23;
+
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &mut impl BufRead) -> Result<Vec<Instruction>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Vec<Instruction>) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Vec<Instruction>) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn parser_tests() {
+        init();
+
+        assert_eq!(
+            Instruction::try_from("hlf a"),
+            Ok(Instruction::Hlf('a'.into()))
+        );
+        assert_eq!(
+            Instruction::try_from("hlf b"),
+            Ok(Instruction::Hlf('b'.into()))
+        );
+        assert_eq!(
+            Instruction::try_from("tpl a"),
+            Ok(Instruction::Tpl('a'.into()))
+        );
+        assert_eq!(
+            Instruction::try_from("tpl b"),
+            Ok(Instruction::Tpl('b'.into()))
+        );
+        assert_eq!(
+            Instruction::try_from("inc a"),
+            Ok(Instruction::Inc('a'.into()))
+        );
+        assert_eq!(
+            Instruction::try_from("inc b"),
+            Ok(Instruction::Inc('b'.into()))
+        );
+        assert_eq!(Instruction::try_from("jmp +23"), Ok(Instruction::Jmp(23)));
+        assert_eq!(Instruction::try_from("jmp -23"), Ok(Instruction::Jmp(-23)));
+        assert_eq!(
+            Instruction::try_from("jie a, +10"),
+            Ok(Instruction::Jie('a'.into(), 10))
+        );
+        assert_eq!(
+            Instruction::try_from("jie b, -10"),
+            Ok(Instruction::Jie('b'.into(), -10))
+        );
+        assert_eq!(
+            Instruction::try_from("jio a, -5"),
+            Ok(Instruction::Jio('a'.into(), -5))
+        );
+        assert_eq!(
+            Instruction::try_from("jio b, 5"),
+            Ok(Instruction::Jio('b'.into(), 5))
+        );
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day23>();
+    }
+}
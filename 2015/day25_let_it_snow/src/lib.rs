@@ -0,0 +1,59 @@
+use aoc_common::{answers::Answer, math, parse::ParseError, scan, Solution};
+use std::io::BufRead;
+
+fn parse_input(input: &mut impl BufRead) -> Result<(u64, u64), ParseError> {
+    let text = aoc_common::read_input(input)?;
+
+    scan!(
+        text.trim_end(),
+        "To continue, please consult the code grid in the manual.  Enter the code at row "
+        {u64} ", column " {u64} "."
+    )
+}
+
+// The codes are filled in one diagonal at a time, going up and to the right
+fn get_code_index(row: u64, column: u64) -> u64 {
+    let diagonal = row + column - 1;
+    diagonal * (diagonal + 1) / 2 - row + 1
+}
+
+// Each code is the one before it times 252533, so the nth one is the first
+// code times 252533^(n - 1)
+fn part1(row: u64, column: u64) -> Answer {
+    let index = get_code_index(row, column);
+
+    (20151125 * math::modpow(252533, index - 1, 33554393) % 33554393).into()
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const YEAR: u16 = 2015;
+    const DAY: u8 = 25;
+
+    type Input = (u64, u64);
+
+    fn parse(input: &mut impl BufRead) -> Result<(u64, u64), ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(&(row, column): &(u64, u64)) -> Result<Answer, ParseError> {
+        Ok(part1(row, column))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day25>();
+    }
+}
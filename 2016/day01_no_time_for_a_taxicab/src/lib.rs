@@ -0,0 +1,145 @@
+use aoc_common::{
+    answers::Answer,
+    geometry::{Direction, Point2},
+    parse::{self, ParseError},
+    Solution,
+};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{self, char},
+    combinator::value,
+    multi::separated_list1,
+    Parser,
+};
+use std::{collections::HashSet, io::BufRead, ops::ControlFlow};
+
+#[derive(Clone)]
+enum Turn {
+    Left,
+    Right,
+}
+
+pub struct Instruction {
+    turn: Turn,
+    blocks: i64,
+}
+
+fn parse_input(input: &mut impl BufRead) -> Result<Vec<Instruction>, ParseError> {
+    let text = aoc_common::read_input(input)?;
+
+    parse::parse(
+        text.trim_end(),
+        separated_list1(
+            tag(", "),
+            (
+                alt((value(Turn::Left, char('L')), value(Turn::Right, char('R')))),
+                complete::i64,
+            )
+                .map(|(turn, blocks)| Instruction { turn, blocks }),
+        ),
+    )
+}
+
+struct Player {
+    direction: Direction,
+    location: Point2,
+    visited_locations: HashSet<Point2>,
+}
+
+impl Player {
+    fn turn(&mut self, turn: &Turn) {
+        self.direction = match turn {
+            Turn::Left => self.direction.turn_left(),
+            Turn::Right => self.direction.turn_right(),
+        }
+    }
+
+    // If we're interested in the visited locations then log them and return
+    // immediately if we visit the same one twice
+    fn walk(&mut self, blocks: i64, log_locations: bool) -> Option<Point2> {
+        let start = self.location;
+        self.location += self.direction.offset() * blocks;
+
+        if log_locations {
+            (1..=blocks)
+                .map(|block| start + self.direction.offset() * block)
+                .find(|&location| !self.visited_locations.insert(location))
+        } else {
+            None
+        }
+    }
+}
+
+fn find_easter_bunny_hq(instructions: &[Instruction], log_locations: bool) -> Answer {
+    let mut player = Player {
+        direction: Direction::North,
+        location: Point2::ORIGIN,
+        visited_locations: HashSet::new(),
+    };
+
+    let _ = instructions.iter().try_for_each(|instruction| {
+        player.turn(&instruction.turn);
+        match player.walk(instruction.blocks, log_locations) {
+            Some(location) => {
+                player.location = location;
+                ControlFlow::Break(())
+            }
+            None => ControlFlow::Continue(()),
+        }
+    });
+
+    player.location.manhattan(Point2::ORIGIN).into()
+}
+
+fn part1(instructions: &[Instruction]) -> Answer {
+    find_easter_bunny_hq(instructions, false)
+}
+
+fn part2(instructions: &[Instruction]) -> Answer {
+    find_easter_bunny_hq(instructions, true)
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2016;
+    const DAY: u8 = 1;
+
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &mut impl BufRead) -> Result<Vec<Instruction>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Vec<Instruction>) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Vec<Instruction>) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day01>();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day01>();
+    }
+}
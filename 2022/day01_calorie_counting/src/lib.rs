@@ -0,0 +1,84 @@
+use aoc_common::{
+    answers::Answer,
+    parse::{self, ParseError},
+    Solution,
+};
+use nom::{
+    bytes::complete::tag,
+    character::complete::{self, line_ending},
+    multi::separated_list1,
+};
+use std::{collections::BinaryHeap, io::BufRead};
+
+// Each elf's inventory is separated by an empty line, only the total
+// amount of calories per elf matters
+fn parse_input(input: &mut impl BufRead) -> Result<BinaryHeap<u32>, ParseError> {
+    let text = aoc_common::read_input(input)?;
+
+    let inventories = parse::parse(
+        text.trim_end(),
+        separated_list1(tag("\n\n"), separated_list1(line_ending, complete::u32)),
+    )?;
+
+    Ok(inventories
+        .iter()
+        .map(|inventory| inventory.iter().sum())
+        .collect())
+}
+
+fn part1(calories: &BinaryHeap<u32>) -> Answer {
+    calories.peek().unwrap().into()
+}
+
+fn part2(calories: &BinaryHeap<u32>) -> Result<Answer, ParseError> {
+    if calories.len() < 3 {
+        return Err(ParseError::no_answer("there are fewer than three elves"));
+    }
+    let mut calories = calories.clone();
+
+    Ok((0..3).map(|_| calories.pop().unwrap()).sum::<u32>().into())
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 1;
+
+    type Input = BinaryHeap<u32>;
+
+    fn parse(input: &mut impl BufRead) -> Result<BinaryHeap<u32>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &BinaryHeap<u32>) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &BinaryHeap<u32>) -> Option<Result<Answer, ParseError>> {
+        Some(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day01>();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day01>();
+    }
+}
@@ -0,0 +1,105 @@
+use aoc_common::{
+    answers::Answer,
+    parse::{self, ParseError},
+    scan, Solution,
+};
+use nom::character::complete::one_of;
+use std::io::BufRead;
+
+const WIN: u32 = 6;
+const DRAW: u32 = 3;
+const LOSS: u32 = 0;
+const ROCK: u32 = 1; // A|X
+const PAPER: u32 = 2; // B|Y
+const SCISSORS: u32 = 3; // C|Z
+
+// The second column is what to play
+fn score_part1(round: &(char, char)) -> u32 {
+    match round {
+        ('A', 'X') => DRAW + ROCK,
+        ('A', 'Y') => WIN + PAPER,
+        ('A', 'Z') => LOSS + SCISSORS,
+        ('B', 'X') => LOSS + ROCK,
+        ('B', 'Y') => DRAW + PAPER,
+        ('B', 'Z') => WIN + SCISSORS,
+        ('C', 'X') => WIN + ROCK,
+        ('C', 'Y') => LOSS + PAPER,
+        ('C', 'Z') => DRAW + SCISSORS,
+        _ => unreachable!(),
+    }
+}
+
+// The second column is how the round needs to end
+fn score_part2(round: &(char, char)) -> u32 {
+    match round {
+        ('A', 'X') => SCISSORS + LOSS,
+        ('A', 'Y') => ROCK + DRAW,
+        ('A', 'Z') => PAPER + WIN,
+        ('B', 'X') => ROCK + LOSS,
+        ('B', 'Y') => PAPER + DRAW,
+        ('B', 'Z') => SCISSORS + WIN,
+        ('C', 'X') => PAPER + LOSS,
+        ('C', 'Y') => SCISSORS + DRAW,
+        ('C', 'Z') => ROCK + WIN,
+        _ => unreachable!(),
+    }
+}
+
+fn parse_input(input: &mut impl BufRead) -> Result<Vec<(char, char)>, ParseError> {
+    parse::parse_lines(
+        input,
+        |line| scan!(line, {one_of("ABC")} " " {one_of("XYZ")}),
+    )
+}
+
+fn part1(rounds: &[(char, char)]) -> Answer {
+    rounds.iter().map(score_part1).sum::<u32>().into()
+}
+
+fn part2(rounds: &[(char, char)]) -> Answer {
+    rounds.iter().map(score_part2).sum::<u32>().into()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 2;
+
+    type Input = Vec<(char, char)>;
+
+    fn parse(input: &mut impl BufRead) -> Result<Vec<(char, char)>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Vec<(char, char)>) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Vec<(char, char)>) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day02>();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day02>();
+    }
+}
@@ -0,0 +1,96 @@
+use aoc_common::{
+    answers::Answer,
+    parse::{self, ParseError},
+    Solution,
+};
+use itertools::Itertools;
+use nom::character::complete::alpha1;
+use std::{collections::HashSet, io::BufRead};
+
+fn priority(item: char) -> u32 {
+    match item {
+        'a'..='z' => item as u32 - 'a' as u32 + 1,
+        'A'..='Z' => item as u32 - 'A' as u32 + 27,
+        _ => unreachable!(),
+    }
+}
+
+fn parse_input(input: &mut impl BufRead) -> Result<Vec<String>, ParseError> {
+    parse::parse_lines(input, |line| parse::parse(line, alpha1).map(str::to_string))
+}
+
+fn part1(rucksacks: &[String]) -> Answer {
+    rucksacks
+        .iter()
+        .map(|rucksack| {
+            let (compartment1, compartment2) = rucksack.split_at(rucksack.len() / 2);
+
+            let compartment1 = compartment1.chars().collect::<HashSet<_>>();
+            let compartment2 = compartment2.chars().collect::<HashSet<_>>();
+
+            priority(*compartment1.intersection(&compartment2).next().unwrap())
+        })
+        .sum::<u32>()
+        .into()
+}
+
+fn part2(rucksacks: &[String]) -> Answer {
+    rucksacks
+        .iter()
+        .map(|rucksack| rucksack.chars().collect::<HashSet<_>>())
+        .chunks(3)
+        .into_iter()
+        .map(|group| {
+            let badge = group
+                .reduce(|shared, rucksack| &shared & &rucksack)
+                .unwrap();
+
+            priority(*badge.iter().next().unwrap())
+        })
+        .sum::<u32>()
+        .into()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 3;
+
+    type Input = Vec<String>;
+
+    fn parse(input: &mut impl BufRead) -> Result<Vec<String>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Vec<String>) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Vec<String>) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day03>();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day03>();
+    }
+}
@@ -0,0 +1,82 @@
+use aoc_common::{
+    answers::Answer,
+    parse::{self, ParseError},
+    ranges::RangeSet,
+    scan, Solution,
+};
+use std::io::BufRead;
+
+// The sections of a pair of elves
+pub type Assignment = (RangeSet, RangeSet);
+
+fn parse_input(input: &mut impl BufRead) -> Result<Vec<Assignment>, ParseError> {
+    parse::parse_lines(input, |line| {
+        scan!(
+            line,
+            {start1: i64} "-" {end1: i64} "," {start2: i64} "-" {end2: i64}
+            => ((start1..=end1).into(), (start2..=end2).into())
+        )
+    })
+}
+
+fn part1(assignments: &[Assignment]) -> Answer {
+    assignments
+        .iter()
+        .filter(|(sections1, sections2)| {
+            sections1.is_subset(sections2) || sections2.is_subset(sections1)
+        })
+        .count()
+        .into()
+}
+
+fn part2(assignments: &[Assignment]) -> Answer {
+    assignments
+        .iter()
+        .filter(|(sections1, sections2)| sections1.overlaps(sections2))
+        .count()
+        .into()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 4;
+
+    type Input = Vec<Assignment>;
+
+    fn parse(input: &mut impl BufRead) -> Result<Vec<Assignment>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Vec<Assignment>) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Vec<Assignment>) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day04>();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day04>();
+    }
+}
@@ -0,0 +1,135 @@
+use aoc_common::{
+    answers::Answer,
+    parse::{self, ParseError},
+    Solution,
+};
+use nom::{bytes::complete::tag, character::complete, error::Error, IResult, Parser};
+use std::io::BufRead;
+
+pub struct Move {
+    amount: usize,
+    from: usize,
+    to: usize,
+}
+
+// The stacks are drawn on top of a line with their numbers, each crate
+// taking up 4 columns ("[X] "). The drawing is followed by an empty line
+// and the list of moves.
+fn parse_input(input: &mut impl BufRead) -> Result<(Vec<Vec<char>>, Vec<Move>), ParseError> {
+    fn parse_move(input: &str) -> IResult<&str, Move, Error<&str>> {
+        (
+            tag("move "),
+            complete::u32,
+            tag(" from "),
+            complete::u32,
+            tag(" to "),
+            complete::u32,
+        )
+            .map(|(_, amount, _, from, _, to)| Move {
+                amount: amount as usize,
+                from: from as usize - 1,
+                to: to as usize - 1,
+            })
+            .parse(input)
+    }
+
+    let mut lines = input.lines().collect::<Result<Vec<_>, _>>()?.into_iter();
+
+    let drawing: Vec<String> = lines.by_ref().take_while(|line| !line.is_empty()).collect();
+    let (numbers, crates) = drawing
+        .split_last()
+        .ok_or_else(|| ParseError::missing(1, "the drawing of the stacks"))?;
+
+    let mut stacks = vec![Vec::new(); numbers.split_whitespace().count()];
+    crates.iter().rev().for_each(|line| {
+        line.chars()
+            .skip(1)
+            .step_by(4)
+            .zip(stacks.iter_mut())
+            .filter(|(c, _)| c.is_ascii_uppercase())
+            .for_each(|(c, stack)| stack.push(c));
+    });
+
+    // The moves start after the drawing and the empty line
+    let moves = lines
+        .zip(drawing.len() + 2..)
+        .map(|(line, number)| parse::parse(&line, parse_move).map_err(|e| e.at_line(number)))
+        .collect::<Result<_, _>>()?;
+
+    Ok((stacks, moves))
+}
+
+fn top_crates(stacks: &[Vec<char>]) -> String {
+    stacks.iter().filter_map(|stack| stack.last()).collect()
+}
+
+fn part1((stacks, moves): &(Vec<Vec<char>>, Vec<Move>)) -> Answer {
+    let mut stacks = stacks.clone();
+
+    // Crates are moved one at a time
+    moves.iter().for_each(|m| {
+        (0..m.amount).for_each(|_| {
+            let c = stacks[m.from].pop().unwrap();
+            stacks[m.to].push(c);
+        });
+    });
+
+    top_crates(&stacks).into()
+}
+
+fn part2((stacks, moves): &(Vec<Vec<char>>, Vec<Move>)) -> Answer {
+    let mut stacks = stacks.clone();
+
+    // Crates are moved all at once so they keep their order
+    moves.iter().for_each(|m| {
+        let from_len = stacks[m.from].len();
+        let moved = stacks[m.from].split_off(from_len - m.amount);
+        stacks[m.to].extend(moved);
+    });
+
+    top_crates(&stacks).into()
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 5;
+
+    type Input = (Vec<Vec<char>>, Vec<Move>);
+
+    fn parse(input: &mut impl BufRead) -> Result<(Vec<Vec<char>>, Vec<Move>), ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &(Vec<Vec<char>>, Vec<Move>)) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &(Vec<Vec<char>>, Vec<Move>)) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day05>();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day05>();
+    }
+}
@@ -0,0 +1,77 @@
+use aoc_common::{
+    answers::Answer,
+    parse::{self, ParseError},
+    Solution,
+};
+use itertools::Itertools;
+use nom::character::complete::alpha1;
+use std::io::BufRead;
+
+fn parse_input(input: &mut impl BufRead) -> Result<String, ParseError> {
+    let text = aoc_common::read_input(input)?;
+
+    parse::parse(text.trim_end(), alpha1).map(str::to_string)
+}
+
+// Position right after the first window of `size` characters that are all
+// different from each other
+fn find_marker(datastream: &str, size: usize) -> usize {
+    datastream
+        .as_bytes()
+        .windows(size)
+        .position(|window| window.iter().all_unique())
+        .unwrap()
+        + size
+}
+
+fn part1(datastream: &str) -> Answer {
+    find_marker(datastream, 4).into()
+}
+
+fn part2(datastream: &str) -> Answer {
+    find_marker(datastream, 14).into()
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 6;
+
+    type Input = String;
+
+    fn parse(input: &mut impl BufRead) -> Result<String, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &String) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &String) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day06>();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day06>();
+    }
+}
@@ -0,0 +1,142 @@
+use aoc_common::{
+    answers::Answer,
+    parse::{self, ParseError},
+    Solution,
+};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete,
+    combinator::{rest, value},
+    sequence::{preceded, terminated},
+    Parser,
+};
+use std::io::BufRead;
+
+const DISK_SIZE: u32 = 70000000;
+const NEEDED_SPACE: u32 = 30000000;
+
+// The names of the directories and files don't matter, only where the
+// commands go and how large the files are
+#[derive(Clone)]
+pub enum Output {
+    CdRoot,
+    CdUp,
+    Cd,
+    Ls,
+    Dir,
+    File(u32),
+}
+
+fn parse_input(input: &mut impl BufRead) -> Result<Vec<Output>, ParseError> {
+    parse::parse_lines1(input, "the terminal output", |line| {
+        parse::parse(
+            line,
+            alt((
+                value(Output::CdRoot, tag("$ cd /")),
+                value(Output::CdUp, tag("$ cd ..")),
+                value(Output::Cd, preceded(tag("$ cd "), rest)),
+                value(Output::Ls, tag("$ ls")),
+                value(Output::Dir, preceded(tag("dir "), rest)),
+                terminated(complete::u32, preceded(tag(" "), rest)).map(Output::File),
+            )),
+        )
+    })
+}
+
+// Only the total size of each directory matters, so instead of building
+// the whole tree keep track of the directories leading to the current one
+// and add every file's size to all of them.
+fn directory_sizes(output: &[Output]) -> Vec<u32> {
+    let mut sizes = Vec::new();
+    let mut path: Vec<usize> = Vec::new();
+
+    output.iter().for_each(|line| match line {
+        Output::CdRoot => {
+            path.truncate(1);
+            if path.is_empty() {
+                sizes.push(0);
+                path.push(sizes.len() - 1);
+            }
+        }
+        Output::CdUp => {
+            path.pop();
+        }
+        Output::Cd => {
+            sizes.push(0);
+            path.push(sizes.len() - 1);
+        }
+        Output::Ls | Output::Dir => {}
+        Output::File(size) => {
+            path.iter().for_each(|&directory| sizes[directory] += size);
+        }
+    });
+
+    sizes
+}
+
+fn part1(output: &[Output]) -> Answer {
+    directory_sizes(output)
+        .iter()
+        .filter(|&&size| size < 100000)
+        .sum::<u32>()
+        .into()
+}
+
+fn part2(output: &[Output]) -> Answer {
+    let sizes = directory_sizes(output);
+
+    // The root directory is always the first one
+    let unused_space = DISK_SIZE - sizes[0];
+
+    sizes
+        .iter()
+        .filter(|&&size| unused_space + size >= NEEDED_SPACE)
+        .min()
+        .unwrap()
+        .into()
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 7;
+
+    type Input = Vec<Output>;
+
+    fn parse(input: &mut impl BufRead) -> Result<Vec<Output>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Vec<Output>) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Vec<Output>) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day07>();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day07>();
+    }
+}
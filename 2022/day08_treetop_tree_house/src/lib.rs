@@ -0,0 +1,146 @@
+use aoc_common::{
+    answers::Answer,
+    parse::{self, ParseError},
+    Solution,
+};
+use nom::{character::complete::satisfy, multi::many1, Parser};
+use std::io::BufRead;
+
+#[derive(Clone, Debug)]
+pub struct Tree {
+    height: i8,
+    visible: bool,
+}
+
+fn parse_input(input: &mut impl BufRead) -> Result<Vec<Vec<Tree>>, ParseError> {
+    parse::parse_lines1(input, "a row of trees", |line| {
+        parse::parse(
+            line,
+            many1(satisfy(|c| c.is_ascii_digit()).map(|number| Tree {
+                height: number.to_digit(10).unwrap() as i8,
+                visible: false,
+            })),
+        )
+    })
+}
+
+// Number of trees that can be seen before (and including) the first tree
+// that is at least as tall as the one at the starting point
+fn viewing_distance(height: i8, trees: impl Iterator<Item = i8>) -> u32 {
+    let mut distance = 0;
+
+    for tree in trees {
+        distance += 1;
+        if tree >= height {
+            break;
+        }
+    }
+
+    distance
+}
+
+fn calculate_scenic_score(forest: &[Vec<Tree>], tree_x: usize, tree_y: usize) -> u32 {
+    let height = forest[tree_x][tree_y].height;
+
+    let left_view = viewing_distance(height, (0..tree_y).rev().map(|j| forest[tree_x][j].height));
+    let right_view = viewing_distance(
+        height,
+        (tree_y + 1..forest[tree_x].len()).map(|j| forest[tree_x][j].height),
+    );
+    let up_view = viewing_distance(height, (0..tree_x).rev().map(|i| forest[i][tree_y].height));
+    let down_view = viewing_distance(
+        height,
+        (tree_x + 1..forest.len()).map(|i| forest[i][tree_y].height),
+    );
+
+    left_view * right_view * up_view * down_view
+}
+
+// A tree is visible if it is taller than all the trees before it when
+// walking along a row or a column from the edge of the forest
+fn mark_visible<'a>(trees: impl Iterator<Item = &'a mut Tree>) {
+    let mut max_visible: i8 = -1;
+
+    trees.for_each(|tree| {
+        if tree.height > max_visible {
+            tree.visible = true;
+            max_visible = tree.height;
+        }
+    });
+}
+
+fn part1(forest: &[Vec<Tree>]) -> Answer {
+    let mut forest = forest.to_vec();
+
+    // Go through the rows back and forth
+    forest.iter_mut().for_each(|row| {
+        mark_visible(row.iter_mut());
+        mark_visible(row.iter_mut().rev());
+    });
+
+    // Go through the columns back and forth
+    (0..forest[0].len()).for_each(|j| {
+        mark_visible(forest.iter_mut().map(|row| &mut row[j]));
+        mark_visible(forest.iter_mut().rev().map(|row| &mut row[j]));
+    });
+
+    forest
+        .iter()
+        .flatten()
+        .filter(|tree| tree.visible)
+        .count()
+        .into()
+}
+
+fn part2(forest: &[Vec<Tree>]) -> Answer {
+    (0..forest.len())
+        .flat_map(|i| (0..forest[i].len()).map(move |j| (i, j)))
+        .map(|(i, j)| calculate_scenic_score(forest, i, j))
+        .max()
+        .unwrap()
+        .into()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 8;
+
+    type Input = Vec<Vec<Tree>>;
+
+    fn parse(input: &mut impl BufRead) -> Result<Vec<Vec<Tree>>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Vec<Vec<Tree>>) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Vec<Vec<Tree>>) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day08>();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day08>();
+    }
+}
@@ -0,0 +1,104 @@
+use aoc_common::{
+    answers::Answer,
+    geometry::{self, Direction, Point2},
+    parse::{self, ParseError},
+    Solution,
+};
+use nom::{
+    character::complete::{self, space1},
+    error::Error,
+    IResult, Parser,
+};
+use std::{collections::HashSet, io::BufRead};
+
+// The direction the head moves in and how many steps it takes
+pub type Motion = (Direction, u32);
+
+fn parse_input(input: &mut impl BufRead) -> Result<Vec<Motion>, ParseError> {
+    fn parse_line(input: &str) -> IResult<&str, Motion, Error<&str>> {
+        (geometry::direction, space1, complete::u32)
+            .map(|(direction, _, distance)| (direction, distance))
+            .parse(input)
+    }
+
+    parse::parse_lines(input, |line| parse::parse(line, parse_line))
+}
+
+// If the knot is no longer touching the one in front of it, it moves one
+// step towards it, diagonally if they're not on the same row or column
+fn snap(head: Point2, tail: &mut Point2) {
+    let distance = head - *tail;
+
+    if distance.x.abs() > 1 || distance.y.abs() > 1 {
+        *tail += distance.signum();
+    }
+}
+
+fn simulate(motions: &[Motion], rope_size: usize) -> Answer {
+    let mut rope = vec![Point2::ORIGIN; rope_size];
+    let mut visited = HashSet::from([Point2::ORIGIN]);
+
+    motions.iter().for_each(|&(direction, distance)| {
+        (0..distance).for_each(|_| {
+            rope[0] += direction.offset();
+
+            (1..rope_size).for_each(|knot| snap(rope[knot - 1], &mut rope[knot]));
+
+            visited.insert(rope[rope_size - 1]);
+        })
+    });
+
+    visited.len().into()
+}
+
+fn part1(motions: &[Motion]) -> Answer {
+    simulate(motions, 2)
+}
+
+fn part2(motions: &[Motion]) -> Answer {
+    simulate(motions, 10)
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 9;
+
+    type Input = Vec<Motion>;
+
+    fn parse(input: &mut impl BufRead) -> Result<Vec<Motion>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Vec<Motion>) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Vec<Motion>) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day09>();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day09>();
+    }
+}
@@ -0,0 +1,130 @@
+use aoc_common::{
+    answers::Answer,
+    ocr,
+    parse::{self, ParseError},
+    Solution,
+};
+use log::debug;
+use nom::{
+    branch::alt, bytes::complete::tag, character::complete, combinator::value, error::Error,
+    sequence::preceded, IResult, Parser,
+};
+use std::io::BufRead;
+
+const CRT_WIDTH: usize = 40;
+const CRT_HEIGHT: usize = 6;
+
+#[derive(Clone, Debug)]
+pub enum Instruction {
+    Noop,
+    Addx(i32),
+}
+
+fn parse_input(input: &mut impl BufRead) -> Result<Vec<Instruction>, ParseError> {
+    fn parse_line(input: &str) -> IResult<&str, Instruction, Error<&str>> {
+        alt((
+            value(Instruction::Noop, tag("noop")),
+            preceded(tag("addx "), complete::i32).map(Instruction::Addx),
+        ))
+        .parse(input)
+    }
+
+    parse::parse_lines(input, |line| parse::parse(line, parse_line))
+}
+
+// The value of the X register during each cycle, starting with cycle 1
+fn register_values(instructions: &[Instruction]) -> Vec<i32> {
+    let mut x = 1;
+
+    instructions
+        .iter()
+        .flat_map(|instruction| match instruction {
+            Instruction::Noop => vec![x],
+            Instruction::Addx(value) => {
+                let during = vec![x, x];
+                x += value;
+                during
+            }
+        })
+        .collect()
+}
+
+fn part1(instructions: &[Instruction]) -> Answer {
+    register_values(instructions)
+        .iter()
+        .zip(1..)
+        .filter(|(_, cycle)| [20, 60, 100, 140, 180, 220].contains(cycle))
+        .map(|(x, cycle)| cycle * x)
+        .sum::<i32>()
+        .into()
+}
+
+// The answer is whatever letters show up on the screen
+fn part2(instructions: &[Instruction]) -> Answer {
+    let mut crt = [['.'; CRT_WIDTH]; CRT_HEIGHT];
+
+    register_values(instructions)
+        .iter()
+        .take(CRT_WIDTH * CRT_HEIGHT)
+        .enumerate()
+        .for_each(|(pixel, sprite)| {
+            let (row, column) = (pixel / CRT_WIDTH, pixel % CRT_WIDTH);
+            if (sprite - column as i32).abs() <= 1 {
+                crt[row][column] = '#';
+            }
+        });
+
+    // The example doesn't draw letters, so that one is answered with the
+    // picture itself
+    match ocr::read(crt) {
+        Ok(letters) => Answer::Text(letters),
+        Err(e) => {
+            debug!("{}", e);
+            Answer::grid(crt)
+        }
+    }
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 10;
+
+    type Input = Vec<Instruction>;
+
+    fn parse(input: &mut impl BufRead) -> Result<Vec<Instruction>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Vec<Instruction>) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Vec<Instruction>) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day10>();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day10>();
+    }
+}
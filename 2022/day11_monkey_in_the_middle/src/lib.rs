@@ -0,0 +1,181 @@
+use aoc_common::{
+    answers::Answer,
+    math,
+    parse::{self, ParseError},
+    Solution,
+};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{self, multispace0, multispace1},
+    combinator::{cut, value},
+    error::Error,
+    multi::{many1, separated_list1},
+    sequence::{preceded, terminated},
+    IResult, Parser,
+};
+use std::io::BufRead;
+
+#[derive(Clone, Debug)]
+enum Operation {
+    Add(u64),
+    Multiply(u64),
+    Square,
+}
+
+#[derive(Clone, Debug)]
+pub struct Monkey {
+    items: Vec<u64>,
+    operation: Operation,
+    divisor: u64,
+    test_true: usize,
+    test_false: usize,
+}
+
+// The notes for each monkey span several lines so the whole input is parsed
+// in one go instead of line by line
+fn parse_input(input: &mut impl BufRead) -> Result<Vec<Monkey>, ParseError> {
+    fn parse_operation(input: &str) -> IResult<&str, Operation, Error<&str>> {
+        preceded(
+            tag("Operation: new = old "),
+            alt((
+                value(Operation::Square, tag("* old")),
+                preceded(tag("* "), complete::u64).map(Operation::Multiply),
+                preceded(tag("+ "), complete::u64).map(Operation::Add),
+            )),
+        )
+        .parse(input)
+    }
+
+    // Once a monkey has started, a mistake in its notes is reported where it
+    // happens instead of many1 just stopping before that monkey
+    fn parse_monkey(input: &str) -> IResult<&str, Monkey, Error<&str>> {
+        preceded(
+            tag("Monkey "),
+            cut((
+                terminated(complete::u32, tag(":")),
+                preceded(
+                    (multispace1, tag("Starting items: ")),
+                    separated_list1(tag(", "), complete::u64),
+                ),
+                preceded(multispace1, parse_operation),
+                preceded((multispace1, tag("Test: divisible by ")), complete::u64),
+                preceded(
+                    (multispace1, tag("If true: throw to monkey ")),
+                    complete::u32,
+                ),
+                preceded(
+                    (multispace1, tag("If false: throw to monkey ")),
+                    complete::u32,
+                ),
+                multispace0,
+            )),
+        )
+        .map(
+            |(_, items, operation, divisor, test_true, test_false, _)| Monkey {
+                items,
+                operation,
+                divisor,
+                test_true: test_true as usize,
+                test_false: test_false as usize,
+            },
+        )
+        .parse(input)
+    }
+
+    let text = aoc_common::read_input(input)?;
+
+    parse::parse(&text, many1(parse_monkey))
+}
+
+fn monkey_business(monkeys: &[Monkey], rounds: u32, relief: bool) -> Answer {
+    let mut monkeys = monkeys.to_vec();
+    let mut inspections = vec![0u64; monkeys.len()];
+
+    // The monkeys only care about divisibility, so the worry levels can be
+    // kept modulo the lcm of all the divisors without changing where the
+    // items end up. Without this they overflow in part 2.
+    let modulus = math::lcm_all(monkeys.iter().map(|monkey| monkey.divisor));
+
+    (0..rounds).for_each(|_| {
+        (0..monkeys.len()).for_each(|index| {
+            let monkey = monkeys[index].clone();
+            monkeys[index].items.clear();
+            inspections[index] += monkey.items.len() as u64;
+
+            monkey.items.iter().for_each(|item| {
+                let mut worry_level = match monkey.operation {
+                    Operation::Add(value) => item + value,
+                    Operation::Multiply(value) => item * value,
+                    Operation::Square => item * item,
+                };
+                if relief {
+                    worry_level /= 3;
+                }
+                worry_level %= modulus;
+
+                let target = if worry_level.is_multiple_of(monkey.divisor) {
+                    monkey.test_true
+                } else {
+                    monkey.test_false
+                };
+                monkeys[target].items.push(worry_level);
+            });
+        })
+    });
+
+    inspections.sort_unstable_by(|a, b| b.cmp(a));
+    (inspections[0] * inspections[1]).into()
+}
+
+fn part1(monkeys: &[Monkey]) -> Answer {
+    monkey_business(monkeys, 20, true)
+}
+
+fn part2(monkeys: &[Monkey]) -> Answer {
+    monkey_business(monkeys, 10000, false)
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 11;
+
+    type Input = Vec<Monkey>;
+
+    fn parse(input: &mut impl BufRead) -> Result<Vec<Monkey>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Vec<Monkey>) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Vec<Monkey>) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day11>();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day11>();
+    }
+}
@@ -0,0 +1,126 @@
+use aoc_common::{
+    answers::Answer,
+    grid::Grid,
+    parse::ParseError,
+    search::{self, SearchProblem},
+    Solution,
+};
+use std::io::BufRead;
+
+pub struct HeightMap {
+    heights: Grid<u8>,
+    start: (usize, usize),
+    end: (usize, usize),
+}
+
+fn parse_input(input: &mut impl BufRead) -> Result<HeightMap, ParseError> {
+    let map = Grid::parse(input, |c| match c {
+        'a'..='z' | 'S' | 'E' => Some(c),
+        _ => None,
+    })?;
+
+    // The start and the end are at the lowest and the highest elevations
+    let heights = map.map(|&c| match c {
+        'S' => b'a',
+        'E' => b'z',
+        _ => c as u8,
+    });
+
+    let missing = |what| ParseError::missing(map.nrows() + 1, what);
+    Ok(HeightMap {
+        heights,
+        start: map.find(&'S').ok_or_else(|| missing("a start S"))?,
+        end: map.find(&'E').ok_or_else(|| missing("an end E"))?,
+    })
+}
+
+// A walk from any of the starting points to the end
+struct Hike<'a> {
+    map: &'a HeightMap,
+    starts: Vec<(usize, usize)>,
+}
+
+impl SearchProblem for Hike<'_> {
+    type State = (usize, usize);
+
+    fn starts(&self) -> impl IntoIterator<Item = (usize, usize)> {
+        self.starts.clone()
+    }
+
+    fn successors(
+        &self,
+        &position: &(usize, usize),
+    ) -> impl IntoIterator<Item = ((usize, usize), u64)> {
+        let heights = &self.map.heights;
+
+        // Can climb at most one step up but can jump down any distance
+        heights
+            .neighbours4(position)
+            .filter(move |&neighbour| heights[neighbour] <= heights[position] + 1)
+            .map(|neighbour| (neighbour, 1))
+    }
+
+    fn is_goal(&self, &position: &(usize, usize)) -> bool {
+        position == self.map.end
+    }
+}
+
+// BFS from all the starting points at once, which gives the shortest path
+// from whichever of them is closest to the end
+fn shortest_path_length(map: &HeightMap, starts: Vec<(usize, usize)>) -> Option<u64> {
+    search::bfs(&Hike { map, starts }).map(|path| path.cost)
+}
+
+fn part1(map: &HeightMap) -> Answer {
+    shortest_path_length(map, vec![map.start]).unwrap().into()
+}
+
+fn part2(map: &HeightMap) -> Answer {
+    let starts = map.heights.find_all(&b'a').collect();
+
+    shortest_path_length(map, starts).unwrap().into()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 12;
+
+    type Input = HeightMap;
+
+    fn parse(input: &mut impl BufRead) -> Result<HeightMap, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &HeightMap) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &HeightMap) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day12>();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day12>();
+    }
+}
@@ -0,0 +1,142 @@
+use aoc_common::{
+    answers::Answer,
+    parse::{self, ParseError},
+    Solution,
+};
+use nom::{
+    branch::alt, bytes::complete::tag, character::complete, error::Error, multi::separated_list0,
+    sequence::delimited, IResult, Parser,
+};
+use std::{cmp::Ordering, io::BufRead};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Packet {
+    Number(u32),
+    List(Vec<Packet>),
+}
+
+impl Ord for Packet {
+    fn cmp(&self, other: &Self) -> Ordering {
+        match (self, other) {
+            (Packet::Number(x), Packet::Number(y)) => x.cmp(y),
+            // A number compared to a list is treated as a list with just
+            // that number in it
+            (Packet::Number(x), Packet::List(_)) => {
+                Packet::List(vec![Packet::Number(*x)]).cmp(other)
+            }
+            (Packet::List(_), Packet::Number(y)) => {
+                self.cmp(&Packet::List(vec![Packet::Number(*y)]))
+            }
+            // Slices already compare element by element with the shorter one
+            // coming first, which is exactly what the puzzle asks for
+            (Packet::List(x), Packet::List(y)) => x.cmp(y),
+        }
+    }
+}
+
+impl PartialOrd for Packet {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+fn parse_packet(input: &str) -> IResult<&str, Packet, Error<&str>> {
+    alt((
+        complete::u32.map(Packet::Number),
+        delimited(tag("["), separated_list0(tag(","), parse_packet), tag("]")).map(Packet::List),
+    ))
+    .parse(input)
+}
+
+// The pairs are separated by empty lines, which don't matter for part 2
+fn parse_input(input: &mut impl BufRead) -> Result<Vec<Packet>, ParseError> {
+    let packets: Vec<_> = parse::parse_lines(input, |line| {
+        if line.is_empty() {
+            Ok(None)
+        } else {
+            parse::parse(line, parse_packet).map(Some)
+        }
+    })?;
+    let end = packets.len() + 1;
+
+    let packets: Vec<Packet> = packets.into_iter().flatten().collect();
+    if !packets.len().is_multiple_of(2) {
+        return Err(ParseError::missing(
+            end,
+            "the other packet of the last pair",
+        ));
+    }
+
+    Ok(packets)
+}
+
+fn part1(packets: &[Packet]) -> Answer {
+    packets
+        .chunks(2)
+        .zip(1..)
+        .filter(|(pair, _)| pair[0] < pair[1])
+        .map(|(_, index)| index)
+        .sum::<u32>()
+        .into()
+}
+
+fn part2(packets: &[Packet]) -> Answer {
+    let dividers = [
+        Packet::List(vec![Packet::List(vec![Packet::Number(2)])]),
+        Packet::List(vec![Packet::List(vec![Packet::Number(6)])]),
+    ];
+
+    let mut packets = packets.to_vec();
+    packets.extend(dividers.iter().cloned());
+    packets.sort();
+
+    dividers
+        .iter()
+        .map(|divider| packets.binary_search(divider).unwrap() + 1)
+        .product::<usize>()
+        .into()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 13;
+
+    type Input = Vec<Packet>;
+
+    fn parse(input: &mut impl BufRead) -> Result<Vec<Packet>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Vec<Packet>) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Vec<Packet>) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day13>();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day13>();
+    }
+}
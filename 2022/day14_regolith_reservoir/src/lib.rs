@@ -0,0 +1,231 @@
+use aoc_common::{
+    answers::Answer,
+    geometry::Point2,
+    parse::{self, ParseError},
+    sparse_grid::SparseGrid,
+    Solution,
+};
+use log::debug;
+use nom::{
+    bytes::complete::tag,
+    character::complete,
+    error::{Error, ErrorKind},
+    multi::separated_list1,
+    sequence::separated_pair,
+    IResult, Parser,
+};
+use std::io::BufRead;
+
+const SAND_SOURCE: Point2 = Point2::new(500, 0);
+
+// Straight down first, then down and to the left, then down and to the right
+const FALLS: [Point2; 3] = [Point2::new(0, 1), Point2::new(-1, 1), Point2::new(1, 1)];
+
+enum SandState {
+    Settled,
+    Abyss,
+    StartingPoint,
+}
+
+// The rock is drawn as '#' and the sand that came to rest as 'o'
+#[derive(Clone, Debug)]
+pub struct Cave {
+    blocked: SparseGrid<char>,
+    lowest_rock: i64,
+    // Where the last unit of sand went through on its way down
+    path: Vec<Point2>,
+}
+
+// The rock only goes straight across or straight down, a diagonal segment
+// would never get to its end when it's drawn
+fn check_straight(line: &str, path: &[Point2]) -> Result<(), ParseError> {
+    let Some(segment) = path
+        .windows(2)
+        .position(|segment| segment[0].x != segment[1].x && segment[0].y != segment[1].y)
+    else {
+        return Ok(());
+    };
+
+    // Pointing at the end of the segment
+    let offset: usize = line
+        .split(" -> ")
+        .take(segment + 1)
+        .map(|point| point.len() + " -> ".len())
+        .sum();
+    Err(ParseError::new(
+        line,
+        nom::Err::Error(Error::new(&line[offset..], ErrorKind::Verify)),
+    ))
+}
+
+fn parse_input(input: &mut impl BufRead) -> Result<Cave, ParseError> {
+    type InputLine = Vec<Point2>;
+    fn parse_line(input: &str) -> IResult<&str, InputLine, Error<&str>> {
+        separated_list1(
+            tag(" -> "),
+            separated_pair(complete::i64, tag(","), complete::i64).map(|(x, y)| Point2::new(x, y)),
+        )
+        .parse(input)
+    }
+
+    let paths: Vec<_> = parse::parse_lines(input, |line| {
+        let path = parse::parse(line, parse_line)?;
+        check_straight(line, &path)?;
+        Ok(path)
+    })?;
+
+    let mut blocked = SparseGrid::new();
+    paths.into_iter().for_each(|path| {
+        path.windows(2).for_each(|segment| {
+            let (mut rock, end) = (segment[0], segment[1]);
+            let direction = (end - rock).signum();
+
+            blocked.insert(rock, '#');
+            while rock != end {
+                rock += direction;
+                blocked.insert(rock, '#');
+            }
+        });
+    });
+
+    let lowest_rock = blocked.bounds().map_or(0, |(_, max)| max.y);
+
+    Ok(Cave {
+        blocked,
+        lowest_rock,
+        path: Vec::new(),
+    })
+}
+
+// With a floor the sand can't fall past it, without one it falls forever
+// once it's below all the rock
+fn simulate_sand(cave: &mut Cave, floor: Option<i64>) -> SandState {
+    // Each unit of sand falls the same way as the one before it until right
+    // above where that one came to rest, so it can start from there
+    let mut sand = cave.path.pop().unwrap_or(SAND_SOURCE);
+
+    loop {
+        if floor.is_none() && sand.y > cave.lowest_rock {
+            return SandState::Abyss;
+        }
+
+        match FALLS
+            .iter()
+            .map(|&fall| sand + fall)
+            .find(|&next| !cave.blocked.contains(next) && Some(next.y) != floor)
+        {
+            Some(next) => {
+                cave.path.push(sand);
+                sand = next;
+            }
+            None => {
+                cave.blocked.insert(sand, 'o');
+
+                if sand == SAND_SOURCE {
+                    return SandState::StartingPoint;
+                } else {
+                    return SandState::Settled;
+                }
+            }
+        }
+    }
+}
+
+fn part1(cave: &Cave) -> Answer {
+    let mut cave = cave.clone();
+    let mut settled_sand = 0;
+
+    loop {
+        match simulate_sand(&mut cave, None) {
+            SandState::Settled => settled_sand += 1,
+            SandState::StartingPoint => unreachable!(),
+            SandState::Abyss => break,
+        }
+    }
+    debug!("\n{}", cave.blocked);
+
+    settled_sand.into()
+}
+
+// The floor goes on forever to both sides
+fn part2(cave: &Cave) -> Answer {
+    let mut cave = cave.clone();
+    let mut settled_sand = 0;
+
+    let floor = cave.lowest_rock + 2;
+
+    loop {
+        match simulate_sand(&mut cave, Some(floor)) {
+            SandState::Settled => settled_sand += 1,
+            SandState::StartingPoint => {
+                settled_sand += 1;
+                break;
+            }
+            SandState::Abyss => unreachable!(),
+        }
+    }
+    debug!("\n{}", cave.blocked);
+
+    settled_sand.into()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 14;
+
+    type Input = Cave;
+
+    fn parse(input: &mut impl BufRead) -> Result<Cave, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Cave) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Cave) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::examples::Example;
+    use std::io::Cursor;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day14>();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day14>();
+    }
+
+    #[test]
+    fn diagonal_tests() {
+        init();
+
+        let input = Example::load("examples/example.txt")
+            .unwrap()
+            .input
+            .replace("503,4 -> 502,4", "503,4 -> 502,5");
+        let error = parse_input(&mut Cursor::new(input)).unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.kind),
+            (2, 10, ErrorKind::Verify)
+        );
+    }
+}
@@ -0,0 +1,153 @@
+use aoc_common::{
+    answers::Answer,
+    examples,
+    geometry::Point2,
+    parse::{self, ParseError},
+    ranges::RangeSet,
+    scan, Solution,
+};
+use std::{collections::HashSet, io::BufRead};
+
+#[derive(Debug)]
+pub struct Sensor {
+    position: Point2,
+    beacon: Point2,
+    range: i64,
+}
+
+// Positions are (x, y) like in the input
+fn parse_input(input: &mut impl BufRead) -> Result<Vec<Sensor>, ParseError> {
+    parse::parse_lines1(input, "a sensor", |line| {
+        let (position, beacon) = scan!(
+            line,
+            "Sensor at x=" {sensor_x: i64} ", y=" {sensor_y: i64}
+            ": closest beacon is at x=" {beacon_x: i64} ", y=" {beacon_y: i64}
+            => (Point2::new(sensor_x, sensor_y), Point2::new(beacon_x, beacon_y))
+        )?;
+
+        Ok(Sensor {
+            position,
+            beacon,
+            range: position.manhattan(beacon),
+        })
+    })
+}
+
+// The columns of the row that the sensors can see
+fn covered_columns(sensors: &[Sensor], row: i64) -> RangeSet {
+    sensors
+        .iter()
+        .filter_map(|sensor| {
+            let width = sensor.range - (row - sensor.position.y).abs();
+
+            (width >= 0).then_some(sensor.position.x - width..sensor.position.x + width + 1)
+        })
+        .collect()
+}
+
+fn part1(sensors: &[Sensor], row: i64) -> Answer {
+    let covered = covered_columns(sensors, row).len();
+
+    // The beacons that are on the row are covered but obviously can have a
+    // beacon there
+    let beacons = sensors
+        .iter()
+        .filter(|sensor| sensor.beacon.y == row)
+        .map(|sensor| sensor.beacon)
+        .collect::<HashSet<_>>()
+        .len() as i64;
+
+    (covered - beacons).into()
+}
+
+// The distress beacon is the only position that no sensor can see, so it has
+// to be right outside the range of several sensors. The edges of the area
+// just outside a sensor's range lie on lines of the form x + y = c and
+// x - y = c, and the beacon sits where two of these lines cross.
+fn part2(sensors: &[Sensor], search_space: i64) -> Result<Answer, ParseError> {
+    let mut sums = HashSet::new();
+    let mut differences = HashSet::new();
+    sensors.iter().for_each(|sensor| {
+        let Point2 { x, y } = sensor.position;
+        let reach = sensor.range + 1;
+
+        sums.extend([x + y - reach, x + y + reach]);
+        differences.extend([x - y - reach, x - y + reach]);
+    });
+
+    let (x, y) = sums
+        .iter()
+        .flat_map(|sum| differences.iter().map(move |difference| (sum, difference)))
+        .filter(|(sum, difference)| (*sum + *difference) % 2 == 0)
+        .map(|(sum, difference)| ((sum + difference) / 2, (sum - difference) / 2))
+        .find(|&(x, y)| {
+            (0..=search_space).contains(&x)
+                && (0..=search_space).contains(&y)
+                && sensors
+                    .iter()
+                    .all(|sensor| sensor.position.manhattan(Point2::new(x, y)) > sensor.range)
+        })
+        .ok_or_else(|| ParseError::no_answer("the sensors can see every position"))?;
+
+    Ok((x * 4000000 + y).into())
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 15;
+
+    // The sensors, the row that part 1 looks at and how far part 2 searches
+    type Input = (Vec<Sensor>, i64, i64);
+
+    fn parse(input: &mut impl BufRead) -> Result<(Vec<Sensor>, i64, i64), ParseError> {
+        Ok((parse_input(input)?, 2000000, 4000000))
+    }
+
+    fn set_parameter(
+        (_, row, search_space): &mut (Vec<Sensor>, i64, i64),
+        name: &str,
+        value: &str,
+    ) -> Result<(), String> {
+        match name {
+            "row" => *row = examples::parameter(name, value)?,
+            "search_space" => *search_space = examples::parameter(name, value)?,
+            _ => return Err(format!("unknown parameter {}", name)),
+        }
+        Ok(())
+    }
+
+    fn part1((sensors, row, _): &(Vec<Sensor>, i64, i64)) -> Result<Answer, ParseError> {
+        Ok(part1(sensors, *row))
+    }
+
+    fn part2(
+        (sensors, _, search_space): &(Vec<Sensor>, i64, i64),
+    ) -> Option<Result<Answer, ParseError>> {
+        Some(part2(sensors, *search_space))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day15>();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day15>();
+    }
+}
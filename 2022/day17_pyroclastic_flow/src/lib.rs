@@ -0,0 +1,199 @@
+use aoc_common::{
+    answers::Answer,
+    cycle,
+    parse::{self, ParseError},
+    Solution,
+};
+use nom::{branch::alt, character::complete::char, combinator::value, multi::many1};
+use std::io::BufRead;
+
+const WIDTH: usize = 7;
+
+// Cells of each rock as (column, row) offsets from its bottom left corner,
+// rows going up
+const ROCKS: [&[(usize, usize)]; 5] = [
+    // ####
+    &[(0, 0), (1, 0), (2, 0), (3, 0)],
+    // .#.
+    // ###
+    // .#.
+    &[(1, 0), (0, 1), (1, 1), (2, 1), (1, 2)],
+    // ..#
+    // ..#
+    // ###
+    &[(0, 0), (1, 0), (2, 0), (2, 1), (2, 2)],
+    // #
+    // #
+    // #
+    // #
+    &[(0, 0), (0, 1), (0, 2), (0, 3)],
+    // ##
+    // ##
+    &[(0, 0), (1, 0), (0, 1), (1, 1)],
+];
+
+#[derive(Clone, Copy, Debug)]
+pub enum JetDirection {
+    Left,
+    Right,
+}
+
+fn parse_input(input: &mut impl BufRead) -> Result<Vec<JetDirection>, ParseError> {
+    let text = aoc_common::read_input(input)?;
+
+    parse::parse(
+        text.trim_end(),
+        many1(alt((
+            value(JetDirection::Left, char('<')),
+            value(JetDirection::Right, char('>')),
+        ))),
+    )
+}
+
+// Each row of the chamber is a bitmask of the columns taken by rocks
+fn collides(chamber: &[u8], rock: &[(usize, usize)], column: usize, row: usize) -> bool {
+    rock.iter().any(|(dx, dy)| {
+        column + dx >= WIDTH
+            || chamber
+                .get(row + dy)
+                .is_some_and(|cells| cells & (1 << (column + dx)) != 0)
+    })
+}
+
+// How far below the top of the tower the highest rock in each column is
+fn surface(chamber: &[u8]) -> [usize; WIDTH] {
+    let mut depths = [chamber.len(); WIDTH];
+
+    (0..WIDTH).for_each(|column| {
+        if let Some(depth) = chamber
+            .iter()
+            .rev()
+            .position(|cells| cells & (1 << column) != 0)
+        {
+            depths[column] = depth;
+        }
+    });
+
+    depths
+}
+
+// The rocks that have come to rest, and what comes next: which rock falls
+// and which jet pushes it first
+struct Chamber<'a> {
+    rows: Vec<u8>,
+    jets: &'a [JetDirection],
+    rock_index: usize,
+    jet_index: usize,
+}
+
+impl Chamber<'_> {
+    fn drop_rock(&mut self) {
+        let rock = ROCKS[self.rock_index];
+        let (mut column, mut row): (usize, usize) = (2, self.rows.len() + 3);
+
+        loop {
+            let pushed = match self.jets[self.jet_index] {
+                JetDirection::Left => column.checked_sub(1),
+                JetDirection::Right => Some(column + 1),
+            };
+            self.jet_index = (self.jet_index + 1) % self.jets.len();
+
+            if let Some(pushed) = pushed.filter(|&pushed| !collides(&self.rows, rock, pushed, row))
+            {
+                column = pushed;
+            }
+
+            if row == 0 || collides(&self.rows, rock, column, row - 1) {
+                break;
+            }
+            row -= 1;
+        }
+
+        rock.iter().for_each(|(dx, dy)| {
+            if row + dy >= self.rows.len() {
+                self.rows.resize(row + dy + 1, 0);
+            }
+            self.rows[row + dy] |= 1 << (column + dx);
+        });
+        self.rock_index = (self.rock_index + 1) % ROCKS.len();
+    }
+}
+
+fn tower_height(jets: &[JetDirection], rocks: usize) -> Answer {
+    let chamber = Chamber {
+        rows: Vec::new(),
+        jets,
+        rock_index: 0,
+        jet_index: 0,
+    };
+
+    // Once the same rock falls with the same jet coming up onto the same
+    // surface, everything that happens from there on repeats, and the tower
+    // grows by the same height each time around
+    cycle::extrapolate(
+        chamber,
+        rocks,
+        Chamber::drop_rock,
+        |chamber| {
+            (
+                chamber.rock_index,
+                chamber.jet_index,
+                surface(&chamber.rows),
+            )
+        },
+        |chamber| chamber.rows.len() as i64,
+    )
+    .into()
+}
+
+fn part1(jets: &[JetDirection]) -> Answer {
+    tower_height(jets, 2022)
+}
+
+fn part2(jets: &[JetDirection]) -> Answer {
+    tower_height(jets, 1_000_000_000_000)
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 17;
+
+    type Input = Vec<JetDirection>;
+
+    fn parse(input: &mut impl BufRead) -> Result<Vec<JetDirection>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Vec<JetDirection>) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Vec<JetDirection>) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day17>();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day17>();
+    }
+}
@@ -0,0 +1,121 @@
+use aoc_common::{
+    answers::Answer,
+    geometry::Point3,
+    parse::{self, ParseError},
+    Solution,
+};
+use nom::{bytes::complete::tag, character::complete, error::Error, IResult, Parser};
+use std::{
+    collections::{HashSet, VecDeque},
+    io::BufRead,
+};
+
+fn parse_input(input: &mut impl BufRead) -> Result<HashSet<Point3>, ParseError> {
+    fn parse_line(input: &str) -> IResult<&str, Point3, Error<&str>> {
+        (
+            complete::i64,
+            tag(","),
+            complete::i64,
+            tag(","),
+            complete::i64,
+        )
+            .map(|(x, _, y, _, z)| Point3::new(x, y, z))
+            .parse(input)
+    }
+
+    parse::parse_lines1(input, "a cube", |line| parse::parse(line, parse_line))
+}
+
+fn part1(droplet: &HashSet<Point3>) -> Answer {
+    droplet
+        .iter()
+        .flat_map(|cube| cube.neighbours6())
+        .filter(|neighbor| !droplet.contains(neighbor))
+        .count()
+        .into()
+}
+
+// Flood fill the air around the droplet, starting from outside of it. Every
+// time the water touches the droplet it's touching one of the outer sides.
+fn part2(droplet: &HashSet<Point3>) -> Answer {
+    // Leave a layer of air around the droplet so that the water can get
+    // all the way around it
+    let min = droplet
+        .iter()
+        .map(|cube| cube.x.min(cube.y).min(cube.z))
+        .min()
+        .unwrap()
+        - 1;
+    let max = droplet
+        .iter()
+        .map(|cube| cube.x.max(cube.y).max(cube.z))
+        .max()
+        .unwrap()
+        + 1;
+    let bounds = min..=max;
+
+    let corner = Point3::new(min, min, min);
+    let mut visited = HashSet::from([corner]);
+    let mut queue = VecDeque::from([corner]);
+    let mut touched_sides = 0;
+
+    while let Some(cube) = queue.pop_front() {
+        cube.neighbours6().for_each(|neighbor| {
+            if droplet.contains(&neighbor) {
+                touched_sides += 1;
+            } else if bounds.contains(&neighbor.x)
+                && bounds.contains(&neighbor.y)
+                && bounds.contains(&neighbor.z)
+                && visited.insert(neighbor)
+            {
+                queue.push_back(neighbor);
+            }
+        });
+    }
+
+    touched_sides.into()
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 18;
+
+    type Input = HashSet<Point3>;
+
+    fn parse(input: &mut impl BufRead) -> Result<HashSet<Point3>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &HashSet<Point3>) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &HashSet<Point3>) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day18>();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day18>();
+    }
+}
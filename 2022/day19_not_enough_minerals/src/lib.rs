@@ -0,0 +1,290 @@
+use aoc_common::{
+    answers::Answer,
+    parse::{self, ParseError},
+    search::{self, SearchProblem},
+    Solution,
+};
+use nom::{bytes::complete::tag, character::complete, error::Error, IResult, Parser};
+use std::io::BufRead;
+
+const ORE: usize = 0;
+const CLAY: usize = 1;
+const OBSIDIAN: usize = 2;
+const GEODE: usize = 3;
+
+#[derive(Debug)]
+pub struct Blueprint {
+    id: u32,
+    // costs[robot][resource], nothing ever costs clay or geodes except the
+    // obsidian robot which costs clay
+    costs: [[u32; 3]; 4],
+}
+
+fn parse_input(input: &mut impl BufRead) -> Result<Vec<Blueprint>, ParseError> {
+    fn parse_line(input: &str) -> IResult<&str, Blueprint, Error<&str>> {
+        (
+            tag("Blueprint "),
+            complete::u32,
+            tag(": Each ore robot costs "),
+            complete::u32,
+            tag(" ore. Each clay robot costs "),
+            complete::u32,
+            tag(" ore. Each obsidian robot costs "),
+            complete::u32,
+            tag(" ore and "),
+            complete::u32,
+            tag(" clay. Each geode robot costs "),
+            complete::u32,
+            tag(" ore and "),
+            complete::u32,
+            tag(" obsidian."),
+        )
+            .map(
+                |(
+                    _,
+                    id,
+                    _,
+                    ore,
+                    _,
+                    clay,
+                    _,
+                    obsidian_ore,
+                    _,
+                    obsidian_clay,
+                    _,
+                    geode_ore,
+                    _,
+                    geode_obsidian,
+                    _,
+                )| {
+                    Blueprint {
+                        id,
+                        costs: [
+                            [ore, 0, 0],
+                            [clay, 0, 0],
+                            [obsidian_ore, obsidian_clay, 0],
+                            [geode_ore, 0, geode_obsidian],
+                        ],
+                    }
+                },
+            )
+            .parse(input)
+    }
+
+    parse::parse_lines(input, |line| parse::parse(line, parse_line))
+}
+
+// The geodes that a geode robot built with `time_left` minutes to go
+// opens, added up for one built at every minute before that
+fn geode_slots(time_left: u32) -> u32 {
+    time_left * time_left.saturating_sub(1) / 2
+}
+
+// The factory right after it built a robot (or when it starts)
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+struct Factory {
+    time_left: u32,
+    robots: [u32; 3],
+    resources: [u32; 3],
+}
+
+// Instead of deciding what to do every minute, decide which robot to build
+// next and skip ahead to the minute it gets built. Opening the most geodes is
+// the same as wasting the fewest of the geode_slots(), so that's the cost:
+// every minute that doesn't end with a geode robot wastes the geodes that a
+// robot built then would have opened.
+struct Mining<'a> {
+    blueprint: &'a Blueprint,
+    max_robots: [u32; 3],
+    minutes: u32,
+}
+
+impl SearchProblem for Mining<'_> {
+    type State = Factory;
+
+    fn starts(&self) -> impl IntoIterator<Item = Factory> {
+        [Factory {
+            time_left: self.minutes,
+            robots: [1, 0, 0],
+            resources: [0, 0, 0],
+        }]
+    }
+
+    fn successors(&self, factory: &Factory) -> impl IntoIterator<Item = (Factory, u64)> {
+        let Factory {
+            time_left,
+            robots,
+            resources,
+        } = *factory;
+
+        if time_left == 0 {
+            return Vec::new();
+        }
+
+        // Stop building, all that's left goes to waste
+        let mut next = vec![(
+            Factory {
+                time_left: 0,
+                robots: [0; 3],
+                resources: [0; 3],
+            },
+            geode_slots(time_left) as u64,
+        )];
+
+        (ORE..=GEODE).rev().for_each(|robot| {
+            // Only one robot can be built per minute so there's no point in
+            // collecting more of a resource per minute than can be spent
+            if robot != GEODE && robots[robot] >= self.max_robots[robot] {
+                return;
+            }
+
+            let cost = self.blueprint.costs[robot];
+            let wait = (ORE..=OBSIDIAN)
+                .map(|resource| {
+                    if cost[resource] <= resources[resource] {
+                        Some(0)
+                    } else if robots[resource] == 0 {
+                        None
+                    } else {
+                        Some((cost[resource] - resources[resource]).div_ceil(robots[resource]))
+                    }
+                })
+                .try_fold(0, |wait, resource_wait| resource_wait.map(|w| wait.max(w)));
+
+            // Building it also takes a minute, and one built in the last minute
+            // doesn't get to collect anything
+            let Some(wait) = wait.filter(|wait| wait + 1 < time_left) else {
+                return;
+            };
+            let next_time_left = time_left - wait - 1;
+
+            let mut next_resources = [0; 3];
+            (ORE..=OBSIDIAN).for_each(|resource| {
+                next_resources[resource] =
+                    resources[resource] + robots[resource] * (wait + 1) - cost[resource];
+            });
+
+            let mut next_robots = robots;
+            let wasted = if robot == GEODE {
+                geode_slots(time_left) - geode_slots(next_time_left + 1)
+            } else {
+                next_robots[robot] += 1;
+                geode_slots(time_left) - geode_slots(next_time_left)
+            };
+
+            next.push((
+                Factory {
+                    time_left: next_time_left,
+                    robots: next_robots,
+                    resources: next_resources,
+                },
+                wasted as u64,
+            ));
+        });
+
+        next
+    }
+
+    fn is_goal(&self, factory: &Factory) -> bool {
+        factory.time_left == 0
+    }
+
+    // Pretend that ore and clay are free and that an obsidian robot gets
+    // built every minute on top of the geode robots, then nothing can open
+    // more geodes than building a geode robot whenever there's obsidian
+    fn heuristic(&self, factory: &Factory) -> u64 {
+        let geode_cost = self.blueprint.costs[GEODE][OBSIDIAN];
+        let mut obsidian = factory.resources[OBSIDIAN];
+        let mut obsidian_robots = factory.robots[OBSIDIAN];
+        let mut geodes = 0;
+
+        (0..factory.time_left).rev().for_each(|time_left| {
+            let build = obsidian >= geode_cost;
+
+            obsidian += obsidian_robots;
+            obsidian_robots += 1;
+            if build {
+                obsidian -= geode_cost;
+                geodes += time_left;
+            }
+        });
+
+        (geode_slots(factory.time_left) - geodes) as u64
+    }
+}
+
+fn open_geodes(blueprint: &Blueprint, minutes: u32) -> u32 {
+    let mining = Mining {
+        blueprint,
+        max_robots: [
+            blueprint.costs.iter().map(|cost| cost[ORE]).max().unwrap(),
+            blueprint.costs[OBSIDIAN][CLAY],
+            blueprint.costs[GEODE][OBSIDIAN],
+        ],
+        minutes,
+    };
+    let wasted = search::astar(&mining).unwrap().cost as u32;
+
+    geode_slots(minutes) - wasted
+}
+
+fn part1(blueprints: &[Blueprint]) -> Answer {
+    blueprints
+        .iter()
+        .map(|blueprint| blueprint.id * open_geodes(blueprint, 24))
+        .sum::<u32>()
+        .into()
+}
+
+fn part2(blueprints: &[Blueprint]) -> Answer {
+    blueprints
+        .iter()
+        .take(3)
+        .map(|blueprint| open_geodes(blueprint, 32))
+        .product::<u32>()
+        .into()
+}
+
+pub struct Day19;
+
+impl Solution for Day19 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 19;
+
+    type Input = Vec<Blueprint>;
+
+    fn parse(input: &mut impl BufRead) -> Result<Vec<Blueprint>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Vec<Blueprint>) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Vec<Blueprint>) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day19>();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day19>();
+    }
+}
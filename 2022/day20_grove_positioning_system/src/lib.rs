@@ -0,0 +1,98 @@
+use aoc_common::{
+    answers::Answer,
+    parse::{self, ParseError},
+    Solution,
+};
+use nom::character::complete;
+use std::io::BufRead;
+
+const DECRYPTION_KEY: i64 = 811589153;
+
+fn parse_input(input: &mut impl BufRead) -> Result<Vec<i64>, ParseError> {
+    parse::parse_lines1(input, "a number", |line| parse::parse(line, complete::i64))
+}
+
+// Mixing moves around the indices of the numbers instead of the numbers
+// themselves since the same number can show up more than once
+fn grove_coordinates_sum(numbers: &[i64], rounds: usize) -> Result<i64, ParseError> {
+    let mut order: Vec<usize> = (0..numbers.len()).collect();
+
+    // While a number moves it isn't part of the list, so it wraps around
+    // one position earlier. A number on its own has nowhere to go.
+    let wrap = (numbers.len() as i64 - 1).max(1);
+
+    (0..rounds).for_each(|_| {
+        (0..numbers.len()).for_each(|index| {
+            let position = order.iter().position(|&i| i == index).unwrap();
+            order.remove(position);
+
+            let new_position = (position as i64 + numbers[index]).rem_euclid(wrap) as usize;
+            order.insert(new_position, index);
+        })
+    });
+
+    let mixed: Vec<i64> = order.iter().map(|&index| numbers[index]).collect();
+    let zero = mixed
+        .iter()
+        .position(|&n| n == 0)
+        .ok_or_else(|| ParseError::no_answer("there is no 0 to count from"))?;
+
+    Ok([1000, 2000, 3000]
+        .iter()
+        .map(|offset| mixed[(zero + offset) % mixed.len()])
+        .sum())
+}
+
+fn part1(numbers: &[i64]) -> Result<Answer, ParseError> {
+    grove_coordinates_sum(numbers, 1).map(Answer::from)
+}
+
+fn part2(numbers: &[i64]) -> Result<Answer, ParseError> {
+    let numbers: Vec<i64> = numbers.iter().map(|n| n * DECRYPTION_KEY).collect();
+
+    grove_coordinates_sum(&numbers, 10).map(Answer::from)
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 20;
+
+    type Input = Vec<i64>;
+
+    fn parse(input: &mut impl BufRead) -> Result<Vec<i64>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Vec<i64>) -> Result<Answer, ParseError> {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<i64>) -> Option<Result<Answer, ParseError>> {
+        Some(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day20>();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day20>();
+    }
+}
@@ -0,0 +1,185 @@
+use aoc_common::{
+    answers::Answer,
+    parse::{self, ParseError},
+    Solution,
+};
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{self, alpha1, one_of},
+    error::Error,
+    sequence::separated_pair,
+    IResult, Parser,
+};
+use std::{collections::HashMap, io::BufRead};
+
+#[derive(Debug)]
+pub enum Job {
+    Number(i64),
+    Operation(String, char, String),
+}
+
+fn parse_input(input: &mut impl BufRead) -> Result<HashMap<String, Job>, ParseError> {
+    type InputLine = (String, Job);
+    fn parse_line(input: &str) -> IResult<&str, InputLine, Error<&str>> {
+        separated_pair(
+            alpha1.map(String::from),
+            tag(": "),
+            alt((
+                complete::i64.map(Job::Number),
+                (alpha1, tag(" "), one_of("+-*/"), tag(" "), alpha1).map(
+                    |(monkey1, _, operation, _, monkey2): (&str, _, _, _, &str)| {
+                        Job::Operation(monkey1.to_string(), operation, monkey2.to_string())
+                    },
+                ),
+            )),
+        )
+        .parse(input)
+    }
+
+    let monkeys: HashMap<String, Job> =
+        parse::parse_lines(input, |line| parse::parse(line, parse_line))?;
+
+    // Every monkey that is listened to has a job of its own
+    let complete = monkeys.contains_key("root")
+        && monkeys.values().all(|job| match job {
+            Job::Number(_) => true,
+            Job::Operation(monkey1, _, monkey2) => {
+                monkeys.contains_key(monkey1) && monkeys.contains_key(monkey2)
+            }
+        });
+    if !complete {
+        return Err(ParseError::missing(
+            monkeys.len() + 1,
+            "a job for root and for every monkey that's listened to",
+        ));
+    }
+
+    Ok(monkeys)
+}
+
+fn yell(monkey: &str, monkeys: &HashMap<String, Job>) -> i64 {
+    match &monkeys[monkey] {
+        Job::Number(value) => *value,
+        Job::Operation(monkey1, operation, monkey2) => {
+            let (value1, value2) = (yell(monkey1, monkeys), yell(monkey2, monkeys));
+
+            match operation {
+                '+' => value1 + value2,
+                '-' => value1 - value2,
+                '*' => value1 * value2,
+                '/' => value1 / value2,
+                _ => unreachable!(),
+            }
+        }
+    }
+}
+
+fn depends_on_human(monkey: &str, monkeys: &HashMap<String, Job>) -> bool {
+    monkey == "humn"
+        || match &monkeys[monkey] {
+            Job::Number(_) => false,
+            Job::Operation(monkey1, _, monkey2) => {
+                depends_on_human(monkey1, monkeys) || depends_on_human(monkey2, monkeys)
+            }
+        }
+}
+
+// Walk down from the monkey towards humn, undoing each operation on the way
+// so that the monkey ends up yelling the expected value. This relies on humn
+// showing up only once, which is the case for the puzzle inputs.
+fn solve_for_human(monkey: &str, expected: i64, monkeys: &HashMap<String, Job>) -> i64 {
+    if monkey == "humn" {
+        return expected;
+    }
+
+    let Job::Operation(monkey1, operation, monkey2) = &monkeys[monkey] else {
+        unreachable!()
+    };
+
+    if depends_on_human(monkey1, monkeys) {
+        let value2 = yell(monkey2, monkeys);
+        let expected = match operation {
+            '+' => expected - value2,
+            '-' => expected + value2,
+            '*' => expected / value2,
+            '/' => expected * value2,
+            _ => unreachable!(),
+        };
+
+        solve_for_human(monkey1, expected, monkeys)
+    } else {
+        let value1 = yell(monkey1, monkeys);
+        let expected = match operation {
+            '+' => expected - value1,
+            '-' => value1 - expected,
+            '*' => expected / value1,
+            '/' => value1 / expected,
+            _ => unreachable!(),
+        };
+
+        solve_for_human(monkey2, expected, monkeys)
+    }
+}
+
+fn part1(monkeys: &HashMap<String, Job>) -> Answer {
+    yell("root", monkeys).into()
+}
+
+// root checks whether its two monkeys yell the same number
+fn part2(monkeys: &HashMap<String, Job>) -> Answer {
+    let Job::Operation(monkey1, _, monkey2) = &monkeys["root"] else {
+        unreachable!()
+    };
+
+    if depends_on_human(monkey1, monkeys) {
+        solve_for_human(monkey1, yell(monkey2, monkeys), monkeys)
+    } else {
+        solve_for_human(monkey2, yell(monkey1, monkeys), monkeys)
+    }
+    .into()
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 21;
+
+    type Input = HashMap<String, Job>;
+
+    fn parse(input: &mut impl BufRead) -> Result<HashMap<String, Job>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &HashMap<String, Job>) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &HashMap<String, Job>) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day21>();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day21>();
+    }
+}
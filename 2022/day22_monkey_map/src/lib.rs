@@ -0,0 +1,325 @@
+use aoc_common::{
+    answers::Answer,
+    geometry::{Direction, Point2, Point3},
+    grid::Grid,
+    parse::{self, ParseError},
+    Solution,
+};
+use nom::{
+    branch::alt, character::complete, combinator::value, error::Error, multi::many1, IResult,
+    Parser,
+};
+use std::{
+    collections::{hash_map::Entry, HashMap, VecDeque},
+    io::BufRead,
+};
+
+// Facings in the order the puzzle scores them
+fn score(facing: Direction) -> usize {
+    match facing {
+        Direction::East => 0,
+        Direction::South => 1,
+        Direction::West => 2,
+        Direction::North => 3,
+    }
+}
+
+#[derive(Clone, Debug)]
+enum Move {
+    Forward(usize),
+    TurnLeft,
+    TurnRight,
+}
+
+pub struct Board {
+    tiles: Grid<u8>,
+    path: Vec<Move>,
+}
+
+// (row, column, facing)
+type Position = (usize, usize, Direction);
+
+fn parse_input(input: &mut impl BufRead) -> Result<Board, ParseError> {
+    fn parse_path(input: &str) -> IResult<&str, Vec<Move>, Error<&str>> {
+        many1(alt((
+            complete::u32.map(|steps| Move::Forward(steps as usize)),
+            value(Move::TurnLeft, complete::char('L')),
+            value(Move::TurnRight, complete::char('R')),
+        )))
+        .parse(input)
+    }
+
+    let mut lines = input.lines().collect::<Result<Vec<_>, _>>()?.into_iter();
+
+    let rows: Vec<String> = lines.by_ref().take_while(|line| !line.is_empty()).collect();
+
+    // The lines of the map aren't padded on the right, do it here so that
+    // they make a grid
+    let width = rows
+        .iter()
+        .map(|row| row.len())
+        .max()
+        .ok_or_else(|| ParseError::missing(1, "the map"))?;
+    let map: String = rows
+        .iter()
+        .map(|row| format!("{:width$}\n", row, width = width))
+        .collect();
+    let tiles = Grid::parse(&mut map.as_bytes(), |c| {
+        " .#".contains(c).then_some(c as u8)
+    })?;
+
+    // The path comes after the map and the empty line
+    let path = lines
+        .next()
+        .ok_or_else(|| ParseError::missing(tiles.nrows() + 2, "the path"))?;
+    let path = parse::parse(&path, parse_path).map_err(|e| e.at_line(tiles.nrows() + 2))?;
+
+    Ok(Board { tiles, path })
+}
+
+// The blanks around the map are as much off the map as what's past the
+// edges of the grid
+fn on_map(tiles: &Grid<u8>, position: Option<(usize, usize)>) -> Option<(usize, usize)> {
+    position.filter(|&position| tiles[position] != b' ')
+}
+
+fn follow_path(board: &Board, wrap: impl Fn(Position) -> Position) -> Answer {
+    let start = board
+        .tiles
+        .row(0)
+        .iter()
+        .position(|&tile| tile == b'.')
+        .unwrap();
+    let (mut row, mut column, mut facing) = (0, start, Direction::East);
+
+    board.path.iter().for_each(|step| match step {
+        Move::TurnLeft => facing = facing.turn_left(),
+        Move::TurnRight => facing = facing.turn_right(),
+        Move::Forward(steps) => {
+            for _ in 0..*steps {
+                let next = match on_map(&board.tiles, board.tiles.step((row, column), facing)) {
+                    Some((row, column)) => (row, column, facing),
+                    None => wrap((row, column, facing)),
+                };
+
+                if board.tiles[(next.0, next.1)] == b'#' {
+                    break;
+                }
+                (row, column, facing) = next;
+            }
+        }
+    });
+
+    (1000 * (row + 1) + 4 * (column + 1) + score(facing)).into()
+}
+
+fn part1(board: &Board) -> Answer {
+    // Walking off the map comes back in on the opposite side, which is
+    // wherever walking backwards runs out of map
+    follow_path(board, |(mut row, mut column, facing)| {
+        while let Some(back) = on_map(
+            &board.tiles,
+            board.tiles.step((row, column), facing.reverse()),
+        ) {
+            (row, column) = back;
+        }
+
+        (row, column, facing)
+    })
+}
+
+// Where a face of the net ends up once it is folded into a cube: its
+// outward normal and the directions its columns and rows go towards
+#[derive(Clone, Copy, Debug)]
+struct Face {
+    normal: Point3,
+    right: Point3,
+    down: Point3,
+}
+
+impl Face {
+    fn direction(&self, facing: Direction) -> Point3 {
+        match facing {
+            Direction::East => self.right,
+            Direction::South => self.down,
+            Direction::West => -self.right,
+            Direction::North => -self.down,
+        }
+    }
+
+    // Folding over one of the edges of this face
+    fn neighbor(&self, facing: Direction) -> Face {
+        let Face {
+            normal,
+            right,
+            down,
+        } = *self;
+
+        match facing {
+            Direction::East => Face {
+                normal: right,
+                right: -normal,
+                down,
+            },
+            Direction::South => Face {
+                normal: down,
+                right,
+                down: -normal,
+            },
+            Direction::West => Face {
+                normal: -right,
+                right: normal,
+                down,
+            },
+            Direction::North => Face {
+                normal: -down,
+                right,
+                down: normal,
+            },
+        }
+    }
+}
+
+// Fold the net into a cube by walking from one face to its neighbors. The
+// faces are keyed by their position in the net.
+fn fold(tiles: &Grid<u8>, side: usize) -> HashMap<(usize, usize), Face> {
+    let start = (
+        0,
+        tiles.row(0).iter().position(|&tile| tile != b' ').unwrap() / side,
+    );
+    let mut faces = HashMap::from([(
+        start,
+        Face {
+            normal: Point3::new(0, 0, 1),
+            right: Point3::new(1, 0, 0),
+            down: Point3::new(0, 1, 0),
+        },
+    )]);
+    let mut queue = VecDeque::from([start]);
+
+    while let Some((face_row, face_column)) = queue.pop_front() {
+        let face = faces[&(face_row, face_column)];
+
+        Direction::ALL.into_iter().for_each(|facing| {
+            let Point2 { x, y } = facing.offset();
+            let corner = tiles.offset(
+                (face_row * side, face_column * side),
+                (y as isize * side as isize, x as isize * side as isize),
+            );
+
+            if let Some((row, column)) = on_map(tiles, corner) {
+                let position = (row / side, column / side);
+                if let Entry::Vacant(entry) = faces.entry(position) {
+                    entry.insert(face.neighbor(facing));
+                    queue.push_back(position);
+                }
+            }
+        });
+    }
+
+    faces
+}
+
+// Walking off a face of the cube continues on the face whose normal points
+// the way we were walking, heading away from the face we left. The cells
+// are matched up through their positions on the cube, measured in half
+// cells from its center so that everything stays an integer.
+fn part2(board: &Board) -> Answer {
+    let cells = board
+        .tiles
+        .iter()
+        .filter(|&(_, &tile)| tile != b' ')
+        .count();
+    let side = ((cells / 6) as f64).sqrt() as usize;
+    let faces = fold(&board.tiles, side);
+    let n = side as i64;
+
+    follow_path(board, |(row, column, facing)| {
+        let from = faces[&(row / side, column / side)];
+        let (i, j) = ((row % side) as i64, (column % side) as i64);
+
+        let position =
+            from.normal * n + from.right * (2 * j - (n - 1)) + from.down * (2 * i - (n - 1));
+        let direction = from.direction(facing);
+        let position = position + direction - from.normal;
+
+        let (&(face_row, face_column), to) = faces
+            .iter()
+            .find(|(_, face)| face.normal == direction)
+            .unwrap();
+        let facing = Direction::ALL
+            .into_iter()
+            .find(|&facing| to.direction(facing) == -from.normal)
+            .unwrap();
+
+        let i = ((position.dot(to.down) + n - 1) / 2) as usize;
+        let j = ((position.dot(to.right) + n - 1) / 2) as usize;
+
+        (face_row * side + i, face_column * side + j, facing)
+    })
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 22;
+
+    type Input = Board;
+
+    fn parse(input: &mut impl BufRead) -> Result<Board, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Board) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Board) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::io::Cursor;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day22>();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day22>();
+    }
+
+    #[test]
+    fn malformed_input_tests() {
+        init();
+
+        assert_eq!(
+            parse_input(&mut Cursor::new("")).err(),
+            Some(ParseError::missing(1, "the map"))
+        );
+        assert_eq!(
+            parse_input(&mut Cursor::new("  ..#\n  #..\n")).err(),
+            Some(ParseError::missing(4, "the path"))
+        );
+
+        let error = parse_input(&mut Cursor::new("  ..#\n  #x.\n\n10R5\n"))
+            .err()
+            .unwrap();
+        assert_eq!((error.line, error.column), (2, 4));
+        assert_eq!(error.kind, nom::error::ErrorKind::Char);
+    }
+}
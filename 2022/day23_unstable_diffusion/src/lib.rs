@@ -0,0 +1,154 @@
+use aoc_common::{answers::Answer, grid::Grid, parse::ParseError, Solution};
+use std::{
+    collections::{HashMap, HashSet},
+    io::BufRead,
+};
+
+type Elf = (i64, i64);
+
+// The three positions an elf looks at before proposing to move in a
+// direction, the first one being where it would move to
+const DIRECTIONS: [[Elf; 3]; 4] = [
+    // North
+    [(-1, 0), (-1, -1), (-1, 1)],
+    // South
+    [(1, 0), (1, -1), (1, 1)],
+    // West
+    [(0, -1), (-1, -1), (1, -1)],
+    // East
+    [(0, 1), (-1, 1), (1, 1)],
+];
+
+fn parse_input(input: &mut impl BufRead) -> Result<HashSet<Elf>, ParseError> {
+    let grove = Grid::parse(input, |c| match c {
+        '#' => Some(true),
+        '.' => Some(false),
+        _ => None,
+    })?;
+
+    let elves: HashSet<Elf> = grove
+        .find_all(&true)
+        .map(|(i, j)| (i as i64, j as i64))
+        .collect();
+    if elves.is_empty() {
+        return Err(ParseError::missing(grove.nrows() + 1, "an elf"));
+    }
+
+    Ok(elves)
+}
+
+// Returns whether any of the elves moved
+fn spread_out(elves: &mut HashSet<Elf>, round: usize) -> bool {
+    let free = |(x, y): Elf, offsets: &[Elf]| {
+        offsets
+            .iter()
+            .all(|(dx, dy)| !elves.contains(&(x + dx, y + dy)))
+    };
+
+    let mut proposals: HashMap<Elf, Vec<Elf>> = HashMap::new();
+
+    elves.iter().for_each(|&elf| {
+        // Elves with nobody around them don't do anything
+        if DIRECTIONS.iter().all(|offsets| free(elf, offsets)) {
+            return;
+        }
+
+        // The direction considered first moves to the back every round
+        if let Some(offsets) = (0..DIRECTIONS.len())
+            .map(|i| &DIRECTIONS[(round + i) % DIRECTIONS.len()])
+            .find(|offsets| free(elf, *offsets))
+        {
+            let (dx, dy) = offsets[0];
+            proposals
+                .entry((elf.0 + dx, elf.1 + dy))
+                .or_default()
+                .push(elf);
+        }
+    });
+
+    let mut moved = false;
+    proposals
+        .into_iter()
+        .filter(|(_, proposing)| proposing.len() == 1)
+        .for_each(|(destination, proposing)| {
+            elves.remove(&proposing[0]);
+            elves.insert(destination);
+            moved = true;
+        });
+
+    moved
+}
+
+fn part1(elves: &HashSet<Elf>) -> Answer {
+    let mut elves = elves.clone();
+
+    (0..10).for_each(|round| {
+        spread_out(&mut elves, round);
+    });
+
+    let (min_x, max_x) = (
+        elves.iter().map(|elf| elf.0).min().unwrap(),
+        elves.iter().map(|elf| elf.0).max().unwrap(),
+    );
+    let (min_y, max_y) = (
+        elves.iter().map(|elf| elf.1).min().unwrap(),
+        elves.iter().map(|elf| elf.1).max().unwrap(),
+    );
+
+    ((max_x - min_x + 1) * (max_y - min_y + 1) - elves.len() as i64).into()
+}
+
+fn part2(elves: &HashSet<Elf>) -> Answer {
+    let mut elves = elves.clone();
+
+    let mut round = 0;
+    while spread_out(&mut elves, round) {
+        round += 1;
+    }
+
+    (round + 1).into()
+}
+
+pub struct Day23;
+
+impl Solution for Day23 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 23;
+
+    type Input = HashSet<Elf>;
+
+    fn parse(input: &mut impl BufRead) -> Result<HashSet<Elf>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &HashSet<Elf>) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &HashSet<Elf>) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day23>();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day23>();
+    }
+}
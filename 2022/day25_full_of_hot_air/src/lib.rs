@@ -0,0 +1,98 @@
+use aoc_common::{
+    answers::Answer,
+    parse::{self, ParseError},
+    Solution,
+};
+use nom::{bytes::complete::take_while1, error::Error, IResult, Parser};
+use std::io::BufRead;
+
+fn snafu_to_decimal(number: &str) -> i64 {
+    number.chars().fold(0, |result, digit| {
+        result * 5
+            + match digit {
+                '2' => 2,
+                '1' => 1,
+                '0' => 0,
+                '-' => -1,
+                '=' => -2,
+                _ => unreachable!(),
+            }
+    })
+}
+
+// The digits 3 and 4 don't exist, they're written as a -2 or -1 with a
+// carry into the next digit instead
+fn decimal_to_snafu(number: i64) -> String {
+    let mut result = Vec::new();
+    let mut remainder = number;
+
+    while remainder > 0 {
+        let (digit, carry) = match remainder % 5 {
+            0 => ('0', 0),
+            1 => ('1', 0),
+            2 => ('2', 0),
+            3 => ('=', 1),
+            4 => ('-', 1),
+            _ => unreachable!(),
+        };
+
+        result.push(digit);
+        remainder = remainder / 5 + carry;
+    }
+
+    result.iter().rev().collect()
+}
+
+fn parse_input(input: &mut impl BufRead) -> Result<Vec<i64>, ParseError> {
+    fn parse_line(input: &str) -> IResult<&str, i64, Error<&str>> {
+        take_while1(|c| "210-=".contains(c))
+            .map(snafu_to_decimal)
+            .parse(input)
+    }
+
+    parse::parse_lines(input, |line| parse::parse(line, parse_line))
+}
+
+fn part1(numbers: &[i64]) -> Answer {
+    decimal_to_snafu(numbers.iter().sum()).into()
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const YEAR: u16 = 2022;
+    const DAY: u8 = 25;
+
+    type Input = Vec<i64>;
+
+    fn parse(input: &mut impl BufRead) -> Result<Vec<i64>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Vec<i64>) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day25>();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day25>();
+    }
+}
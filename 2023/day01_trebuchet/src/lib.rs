@@ -0,0 +1,157 @@
+use aoc_common::{
+    answers::Answer,
+    parse::{self, ParseError},
+    Solution,
+};
+use log::debug;
+use nom::character::complete::alphanumeric1;
+use std::io::BufRead;
+
+fn parse_input(input: &mut impl BufRead) -> Result<Vec<String>, ParseError> {
+    parse::parse_lines(input, |line| {
+        parse::parse(line, alphanumeric1).map(str::to_string)
+    })
+}
+
+fn part1(lines: &[String]) -> Answer {
+    lines
+        .iter()
+        .map(|line| {
+            let first_digit_pos = line.find(|c: char| c.is_ascii_digit()).unwrap();
+            let last_digit_pos = line.rfind(|c: char| c.is_ascii_digit()).unwrap();
+
+            let first_digit = line.chars().nth(first_digit_pos).unwrap();
+            let last_digit = line.chars().nth(last_digit_pos).unwrap();
+
+            format!("{}{}", first_digit, last_digit)
+                .parse::<u32>()
+                .unwrap()
+        })
+        .sum::<u32>()
+        .into()
+}
+
+fn find_digit(line: &str, forward_search: bool) -> u32 {
+    let spelled_out_digits = [
+        /* Would it be possible to have this here and have the array be an
+        array of Patterns that could be passed into find and rfind? */
+        // |c: char| c.is_ascii_digit()
+        "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    ];
+
+    /* Find all positions of all digits (both ascii ones and
+    spelled out). This means we're going to traverse each input
+    string 10 times. */
+
+    // First we find the positions of all spelled out digits
+    let mut all_digits_pos: Vec<(usize, u32)> = spelled_out_digits
+        .iter()
+        /* Index the results, this is important for identifying which call the
+        result belongs to. (pos, 1) will belong to the `[r]find("one")` call
+        (pos, 2) will belong to the `[r]find("two")` call and so on */
+        .zip(1..=9u32)
+        .map(|(s, i)| {
+            if forward_search {
+                (line.find(s), i)
+            } else {
+                (line.rfind(s), i)
+            }
+        })
+        // We're not interested in empty results so take them out
+        .filter(|(s, _)| s.is_some())
+        // Convert the results from (Option(pos), index) to (pos, index)
+        .map(|(pos, i)| (pos.unwrap(), i))
+        .collect();
+
+    // Then look for an ascii digit and store the result as (pos, 0)
+    if let Some(pos) = if forward_search {
+        line.find(|c: char| c.is_ascii_digit())
+    } else {
+        line.rfind(|c: char| c.is_ascii_digit())
+    } {
+        all_digits_pos.push((pos, 0))
+    }
+
+    /* Depending on the search direction look for the left-most (its position
+    is the minimum of all found positions) or right-most (its position is the
+    maximum of all found positions) digit*/
+    let digit_pos = if forward_search {
+        all_digits_pos
+            .iter()
+            .min_by(|(a, _), (b, _)| a.cmp(b))
+            .unwrap()
+    } else {
+        all_digits_pos
+            .iter()
+            .max_by(|(a, _), (b, _)| a.cmp(b))
+            .unwrap()
+    };
+
+    match digit_pos {
+        /* If the left or right-most digit has index 0 then that means it
+        is an ascii digit so handle it just like in part1 */
+        (pos, 0) => line.chars().nth(*pos).unwrap().to_digit(10).unwrap(),
+        /* Otherwise one of the spelled out digits was left or right-most
+        and its value is the index itself */
+        (_, index) => *index,
+    }
+}
+
+fn part2(lines: &[String]) -> Answer {
+    lines
+        .iter()
+        .map(|line| {
+            let first_digit = find_digit(line, true);
+            let second_digit = find_digit(line, false);
+
+            debug!("{}", first_digit * 10 + second_digit);
+
+            first_digit * 10 + second_digit
+        })
+        .sum::<u32>()
+        .into()
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 1;
+
+    type Input = Vec<String>;
+
+    fn parse(input: &mut impl BufRead) -> Result<Vec<String>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Vec<String>) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Vec<String>) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day01>();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day01>();
+    }
+}
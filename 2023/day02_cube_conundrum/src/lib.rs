@@ -0,0 +1,159 @@
+use aoc_common::{
+    answers::Answer,
+    parse::{self, ParseError},
+    Solution,
+};
+use log::debug;
+use nom::{
+    branch::alt, bytes::complete::tag, character::complete, multi::separated_list1, sequence::pair,
+    IResult, Parser,
+};
+use std::{cmp::max, io::BufRead};
+
+#[derive(Debug)]
+pub struct Reveal {
+    red_cubes: u32,
+    green_cubes: u32,
+    blue_cubes: u32,
+}
+
+#[derive(Debug)]
+pub struct Game {
+    index: u32,
+    reveals: Vec<Reveal>,
+}
+
+const MAX_RED_CUBES: u32 = 12;
+const MAX_GREEN_CUBES: u32 = 13;
+const MAX_BLUE_CUBES: u32 = 14;
+
+fn parse_input(input: &mut impl BufRead) -> Result<Vec<Game>, ParseError> {
+    fn color_parser(input: &str) -> IResult<&str, (u32, &str)> {
+        pair(
+            complete::u32,
+            alt((tag(" red"), tag(" green"), tag(" blue"))),
+        )
+        .parse(input)
+    }
+
+    fn reveal_parser(input: &str) -> IResult<&str, Reveal> {
+        fn get_number_of_cubes(array_of_cubes: &[(u32, &str)], color: &str) -> u32 {
+            array_of_cubes
+                .iter()
+                .find(|(_, c)| c == &color)
+                .map_or(0, |(number_of_cubes, _)| *number_of_cubes)
+        }
+
+        separated_list1(tag(", "), color_parser)
+            .parse(input)
+            .map(|(s, v)| {
+                (
+                    s,
+                    Reveal {
+                        red_cubes: get_number_of_cubes(&v, " red"),
+                        green_cubes: get_number_of_cubes(&v, " green"),
+                        blue_cubes: get_number_of_cubes(&v, " blue"),
+                    },
+                )
+            })
+    }
+
+    parse::parse_lines(input, |line| {
+        let (_, index, _, reveals) = parse::parse(
+            line,
+            (
+                tag("Game "),
+                complete::u32,
+                tag(": "),
+                separated_list1(tag("; "), reveal_parser),
+            ),
+        )?;
+
+        Ok(Game { index, reveals })
+    })
+}
+
+fn part1(games: &[Game]) -> Answer {
+    let possible_games_id_sum = games
+        .iter()
+        .filter(|game| {
+            game.reveals.iter().all(|reveal| {
+                reveal.red_cubes <= MAX_RED_CUBES
+                    && reveal.green_cubes <= MAX_GREEN_CUBES
+                    && reveal.blue_cubes <= MAX_BLUE_CUBES
+            })
+        })
+        .fold(0, |acc, game| acc + game.index);
+
+    debug!("{:?}", games);
+
+    possible_games_id_sum.into()
+}
+
+fn part2(games: &[Game]) -> Answer {
+    let power_of_sets_sum: u32 = games
+        .iter()
+        .map(|game| {
+            game.reveals
+                .iter()
+                .fold((0, 0, 0), |(red_cubes, green_cubes, blue_cubes), reveal| {
+                    (
+                        max(red_cubes, reveal.red_cubes),
+                        max(green_cubes, reveal.green_cubes),
+                        max(blue_cubes, reveal.blue_cubes),
+                    )
+                })
+        })
+        .map(
+            |(fewest_red_cubes, fewest_green_cubes, fewest_blue_cubes)| {
+                fewest_red_cubes * fewest_green_cubes * fewest_blue_cubes
+            },
+        )
+        .sum();
+
+    power_of_sets_sum.into()
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 2;
+
+    type Input = Vec<Game>;
+
+    fn parse(input: &mut impl BufRead) -> Result<Vec<Game>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Vec<Game>) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Vec<Game>) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day02>();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day02>();
+    }
+}
@@ -0,0 +1,165 @@
+use aoc_common::{answers::Answer, grid::Grid, parse::ParseError, Solution};
+use log::debug;
+use std::{
+    collections::{HashMap, HashSet},
+    io::BufRead,
+};
+
+#[derive(Debug)]
+pub struct EngineSchematic {
+    numbers_and_symbols: Grid<char>,
+}
+
+impl EngineSchematic {
+    fn parse(input: &mut impl BufRead) -> Result<Self, ParseError> {
+        Ok(EngineSchematic {
+            numbers_and_symbols: Grid::parse(input, Some)?,
+        })
+    }
+
+    fn analyze_schematic(&self) -> (u32, u32) {
+        /* Given a coordinate (i, j) and an array of numbers and symbols
+        find out if the element at the coordinate has any adjacent
+        symbols (part1) and return a list of adjacent gears (part2) */
+        fn analyze_neighbours(
+            position: (usize, usize),
+            numbers_and_symbols: &Grid<char>,
+        ) -> (bool, Vec<(usize, usize)>) {
+            let mut adjacent_gears: Vec<(usize, usize)> = Vec::new();
+            let mut has_adjacent_symbols: bool = false;
+
+            numbers_and_symbols
+                .neighbours8(position)
+                .for_each(|neighbour_position| {
+                    let neighbour = numbers_and_symbols[neighbour_position];
+
+                    if neighbour == '*' {
+                        adjacent_gears.push(neighbour_position);
+                    }
+                    if neighbour.is_ascii_punctuation() && neighbour != '.' {
+                        has_adjacent_symbols |= true;
+                    }
+                });
+
+            (has_adjacent_symbols, adjacent_gears)
+        }
+
+        let (rows, columns) = (
+            self.numbers_and_symbols.nrows(),
+            self.numbers_and_symbols.ncols(),
+        );
+
+        /* Use these to keep track of the current number as we're analyzing
+        each digit. They will be reset when we reach the end of a number
+        (either we encounter a non digit or we reach the end of a row) */
+        let mut is_part_number: bool = false;
+        let mut current_number: String = Default::default();
+        let mut current_number_adjacent_gears: HashSet<(usize, usize)> = HashSet::new();
+
+        let mut sum_of_part_numbers: u32 = 0;
+
+        /* Mapping between a gear (identified by its coordinate) and all
+        the numbers it is adjacent with */
+        let mut gears_to_part_numbers: HashMap<(usize, usize), Vec<u32>> = HashMap::new();
+
+        for i in 0..rows {
+            for j in 0..columns {
+                /* If we found a digit then keep track of the (potential)
+                larger number it is a part of and analyze its neighbours
+                to see if has adjacent symbols or gears */
+                if self.numbers_and_symbols[(i, j)].is_ascii_digit() {
+                    let (has_adjacent_symbols, adjacent_gears) =
+                        analyze_neighbours((i, j), &self.numbers_and_symbols);
+                    current_number.push(self.numbers_and_symbols[(i, j)]);
+                    current_number_adjacent_gears.extend(adjacent_gears.iter());
+                    is_part_number |= has_adjacent_symbols;
+                }
+
+                /* If we found something that is not a digit or we've reached
+                the end of the row then check if we have a part number. If
+                yes, then add it to the sum and to the lists of part numbers
+                associated with each gear it is a neighbour of. Then reset
+                the variables we use to keep track of the current number. */
+                if (!self.numbers_and_symbols[(i, j)].is_ascii_digit() || j == columns - 1)
+                    && !current_number.is_empty()
+                {
+                    if is_part_number {
+                        let part_number = current_number.parse::<u32>().unwrap();
+                        sum_of_part_numbers += part_number;
+                        current_number_adjacent_gears.iter().for_each(|gear| {
+                            gears_to_part_numbers
+                                .entry(*gear)
+                                .and_modify(|part_numbers| part_numbers.push(part_number))
+                                .or_insert_with(|| vec![part_number]);
+                        });
+                    }
+                    is_part_number = false;
+                    current_number_adjacent_gears.clear();
+                    current_number.clear();
+                }
+            }
+        }
+
+        let sum_of_gear_ratios: u32 = gears_to_part_numbers
+            .values()
+            .filter(|part_numbers| part_numbers.len() == 2)
+            .map(|part_numbers| part_numbers.iter().product::<u32>())
+            .sum();
+
+        (sum_of_part_numbers, sum_of_gear_ratios)
+    }
+}
+
+fn part1(grid: &EngineSchematic) -> Answer {
+    debug!("{:?}", grid);
+
+    grid.analyze_schematic().0.into()
+}
+
+fn part2(grid: &EngineSchematic) -> Answer {
+    grid.analyze_schematic().1.into()
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 3;
+
+    type Input = EngineSchematic;
+
+    fn parse(input: &mut impl BufRead) -> Result<EngineSchematic, ParseError> {
+        EngineSchematic::parse(input)
+    }
+
+    fn part1(input: &EngineSchematic) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &EngineSchematic) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day03>();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day03>();
+    }
+}
@@ -0,0 +1,193 @@
+use aoc_common::{
+    answers::Answer,
+    parse::{self, ParseError},
+    Solution,
+};
+use log::debug;
+use nom::{
+    bytes::complete::tag,
+    character::complete::{self, multispace1},
+    error::Error,
+    multi::separated_list1,
+    IResult, Parser,
+};
+use std::{collections::HashSet, io::BufRead};
+
+#[derive(Debug)]
+pub struct Scratchcard {
+    index: usize,
+    numbers_you_have: HashSet<u32>,
+    winning_numbers: HashSet<u32>,
+}
+
+impl TryFrom<&str> for Scratchcard {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        type InputLine<'a> = (
+            &'a str,
+            &'a str,
+            u32,
+            &'a str,
+            &'a str,
+            Vec<u32>,
+            &'a str,
+            &'a str,
+            &'a str,
+            Vec<u32>,
+        );
+        fn parse_line(input: &str) -> IResult<&str, InputLine<'_>, Error<&str>> {
+            (
+                tag("Card"),
+                multispace1,
+                complete::u32,
+                tag(":"),
+                multispace1,
+                separated_list1(multispace1, complete::u32),
+                multispace1,
+                tag("|"),
+                multispace1,
+                separated_list1(multispace1, complete::u32),
+            )
+                .parse(input)
+        }
+
+        let (_, _, index, _, _, numbers_you_have, _, _, _, winning_numbers) =
+            parse::parse(input, parse_line)?;
+
+        Ok(Scratchcard {
+            // Have the index start from 0 to simplify some calculations below
+            index: (index - 1).try_into().unwrap(),
+            numbers_you_have: numbers_you_have.into_iter().collect(),
+            winning_numbers: winning_numbers.into_iter().collect(),
+        })
+    }
+}
+
+fn parse_input(input: &mut impl BufRead) -> Result<Vec<Scratchcard>, ParseError> {
+    parse::parse_lines(input, |line| Scratchcard::try_from(line))
+}
+
+fn part1(scratchcards: &[Scratchcard]) -> Answer {
+    debug!("{:?}", scratchcards);
+
+    scratchcards
+        .iter()
+        .map(|scratchcard| {
+            let matching_numbers: u32 = scratchcard
+                .numbers_you_have
+                .intersection(&scratchcard.winning_numbers)
+                .count()
+                .try_into()
+                .unwrap();
+
+            if matching_numbers > 0 {
+                2u32.pow(matching_numbers - 1)
+            } else {
+                0
+            }
+        })
+        .sum::<u32>()
+        .into()
+}
+
+fn part2(scratchcards: &[Scratchcard]) -> Answer {
+    // We initially start with one of each scratchcard (the "original")
+    let mut scratchcard_instances = vec![1; scratchcards.len()];
+
+    scratchcards.iter().for_each(|scratchcard| {
+        let matching_numbers = scratchcard
+            .numbers_you_have
+            .intersection(&scratchcard.winning_numbers)
+            .count();
+        let current_scratchard_instances = scratchcard_instances[scratchcard.index];
+
+        debug!(
+            "Card {} has {} matching numbers",
+            scratchcard.index + 1,
+            matching_numbers
+        );
+
+        for instances in &mut scratchcard_instances
+            [scratchcard.index + 1..scratchcard.index + 1 + matching_numbers]
+            .iter_mut()
+        {
+            *instances += current_scratchard_instances;
+        }
+
+        debug!("{:?}", scratchcard_instances);
+    });
+
+    scratchcard_instances.iter().sum::<usize>().into()
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 4;
+
+    type Input = Vec<Scratchcard>;
+
+    fn parse(input: &mut impl BufRead) -> Result<Vec<Scratchcard>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Vec<Scratchcard>) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Vec<Scratchcard>) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::io::Cursor;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day04>();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day04>();
+    }
+
+    #[test]
+    fn malformed_input_tests() {
+        init();
+
+        let error = parse_input(&mut Cursor::new(
+            "Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53\n\
+             Card 2:  1 21 53 59 44 / 69 82 63 72 16 21 14  1\n",
+        ))
+        .unwrap_err();
+
+        assert_eq!(
+            (error.line, error.column, error.kind),
+            (2, 24, nom::error::ErrorKind::Tag)
+        );
+        assert_eq!(
+            error.to_string(),
+            [
+                "line 2, column 24: Tag",
+                "  |",
+                "2 | Card 2:  1 21 53 59 44 / 69 82 63 72 16 21 14  1",
+                "  |                        ^",
+            ]
+            .join("\n")
+        );
+    }
+}
@@ -0,0 +1,104 @@
+use rand::{rngs::StdRng, seq::SliceRandom, Rng, SeedableRng};
+use std::collections::BTreeSet;
+
+const CATEGORIES: [&str; 8] = [
+    "seed",
+    "soil",
+    "fertilizer",
+    "water",
+    "light",
+    "temperature",
+    "humidity",
+    "location",
+];
+
+// The numbers in the real almanacs all fit in 32 bits
+const SPAN: i64 = 1 << 32;
+
+// Cuts 0..SPAN into `count` ranges of random lengths and moves them about, so
+// that every number ends up somewhere else and no two end up in the same
+// place
+fn map(count: usize, rng: &mut StdRng) -> Vec<(i64, i64, i64)> {
+    let mut cuts = BTreeSet::from([0, SPAN]);
+    while cuts.len() < count + 1 {
+        cuts.insert(rng.random_range(1..SPAN));
+    }
+
+    let cuts: Vec<i64> = cuts.into_iter().collect();
+    let mut sources: Vec<(i64, i64)> = cuts
+        .windows(2)
+        .map(|cut| (cut[0], cut[1] - cut[0]))
+        .collect();
+    sources.shuffle(rng);
+
+    let mut destination = 0;
+    let mut ranges: Vec<(i64, i64, i64)> = sources
+        .into_iter()
+        .map(|(source, length)| {
+            destination += length;
+            (destination - length, source, length)
+        })
+        .collect();
+    ranges.shuffle(rng);
+
+    ranges
+}
+
+// An almanac with `size` seed ranges and `size` ranges in each of its maps
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+    let size = size.max(1);
+
+    let seeds: Vec<String> = (0..size)
+        .flat_map(|_| {
+            let start = rng.random_range(0..SPAN - 1);
+            let length = rng.random_range(1..=(SPAN - start).min(SPAN / size as i64));
+            [start, length]
+        })
+        .map(|number| number.to_string())
+        .collect();
+
+    let maps: Vec<String> = CATEGORIES
+        .windows(2)
+        .map(|categories| {
+            let ranges: String = map(size, &mut rng)
+                .into_iter()
+                .map(|(destination, source, length)| {
+                    format!("{} {} {}\n", destination, source, length)
+                })
+                .collect();
+
+            format!("{}-to-{} map:\n{}", categories[0], categories[1], ranges)
+        })
+        .collect();
+
+    format!("seeds: {}\n\n{}", seeds.join(" "), maps.join("\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{generate::*, Day05};
+    use aoc_common::Solution;
+
+    #[test]
+    fn generate_tests() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut ranges = map(50, &mut rng);
+        assert_eq!(ranges.len(), 50);
+
+        // Both the sources and the destinations cover everything once
+        ranges.sort_unstable_by_key(|&(_, source, _)| source);
+        assert!(ranges.windows(2).all(|r| r[0].1 + r[0].2 == r[1].1));
+        ranges.sort_unstable_by_key(|&(destination, _, _)| destination);
+        assert!(ranges.windows(2).all(|r| r[0].0 + r[0].2 == r[1].0));
+        assert_eq!(ranges.iter().map(|r| r.2).sum::<i64>(), SPAN);
+
+        let input = generate(20, 7);
+        assert_eq!(input, generate(20, 7));
+        assert_ne!(input, generate(20, 8));
+
+        let parsed = Day05::parse(&mut input.as_bytes()).unwrap();
+        assert!(Day05::part1(&parsed).is_ok());
+        assert!(Day05::part2(&parsed).unwrap().is_ok());
+    }
+}
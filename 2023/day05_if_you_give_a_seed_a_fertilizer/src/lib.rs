@@ -0,0 +1,246 @@
+mod generate;
+
+use aoc_common::{
+    answers::Answer,
+    parse::{self, ParseError},
+    ranges::{RangeMap, RangeSet},
+    Solution,
+};
+use log::debug;
+use nom::{
+    bytes::complete::tag,
+    character::complete::{self, alphanumeric1, multispace0, newline},
+    combinator::verify,
+    error::Error,
+    multi::separated_list1,
+    sequence::terminated,
+    IResult, Parser,
+};
+use std::io::BufRead;
+
+// Each map moves the numbers in its source ranges by however far the
+// destination range is from the source range
+fn parse_input(input: &mut impl BufRead) -> Result<(Vec<i64>, Vec<RangeMap>), ParseError> {
+    fn range_parser(input: &str) -> IResult<&str, (i64, i64, i64)> {
+        (
+            terminated(complete::i64, tag(" ")),
+            terminated(complete::i64, tag(" ")),
+            complete::i64,
+        )
+            .parse(input)
+    }
+
+    fn map_parser(input: &str) -> IResult<&str, RangeMap> {
+        (
+            terminated(
+                (alphanumeric1, tag("-to-"), alphanumeric1, tag(" map")),
+                tag(":"),
+            ),
+            newline,
+            separated_list1(newline, range_parser),
+            newline,
+        )
+            .parse(input)
+            .map(|(s, (_, _, ranges, _))| {
+                let mut map = RangeMap::new();
+                ranges
+                    .into_iter()
+                    .for_each(|(destination, source, length)| {
+                        map.insert(source..source + length, destination - source)
+                    });
+
+                (s, map)
+            })
+    }
+
+    let lines = aoc_common::read_input(input)?;
+
+    // The seeds and the maps are parsed in one go so that the position of a
+    // parsing error is relative to the whole input. Part 2 reads the seeds as
+    // pairs, so there has to be an even number of them.
+    let (_, seeds, _, _, maps, _) = parse::parse(
+        &lines,
+        (
+            tag::<&str, &str, Error<&str>>("seeds: "),
+            verify(
+                separated_list1(tag(" "), complete::i64),
+                |seeds: &Vec<i64>| seeds.len().is_multiple_of(2),
+            ),
+            newline,
+            newline,
+            separated_list1(newline, map_parser),
+            multispace0,
+        ),
+    )?;
+
+    Ok((seeds, maps))
+}
+
+// The seeds go through the maps all at once, one range of them at a time
+fn lowest_location(seeds: RangeSet, maps: &[RangeMap]) -> Result<Answer, ParseError> {
+    let locations = maps.iter().fold(seeds, |numbers, map| map.apply(&numbers));
+    debug!("{:?}", locations);
+
+    locations
+        .min()
+        .map(Answer::from)
+        .ok_or_else(|| ParseError::no_answer("there are no seeds to plant"))
+}
+
+fn part1((seeds, maps): &(Vec<i64>, Vec<RangeMap>)) -> Result<Answer, ParseError> {
+    lowest_location(seeds.iter().map(|&seed| seed..seed + 1).collect(), maps)
+}
+
+// The seed numbers come in pairs of the start and the length of a range,
+// which can all be empty
+fn part2((seeds, maps): &(Vec<i64>, Vec<RangeMap>)) -> Result<Answer, ParseError> {
+    lowest_location(
+        seeds
+            .chunks(2)
+            .map(|pair| pair[0]..pair[0] + pair[1])
+            .collect(),
+        maps,
+    )
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 5;
+
+    type Input = (Vec<i64>, Vec<RangeMap>);
+
+    fn parse(input: &mut impl BufRead) -> Result<(Vec<i64>, Vec<RangeMap>), ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &(Vec<i64>, Vec<RangeMap>)) -> Result<Answer, ParseError> {
+        part1(input)
+    }
+
+    fn part2(input: &(Vec<i64>, Vec<RangeMap>)) -> Option<Result<Answer, ParseError>> {
+        Some(part2(input))
+    }
+
+    fn generate(size: usize, seed: u64) -> Option<String> {
+        Some(generate::generate(size, seed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::{
+        differential::{self, Pair},
+        examples::Example,
+    };
+    use std::io::Cursor;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day05>();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day05>();
+    }
+
+    #[test]
+    fn malformed_input_tests() {
+        init();
+
+        let input = Example::load("examples/example.txt")
+            .unwrap()
+            .input
+            .replace("37 52 2", "37 52 x");
+        let error = parse_input(&mut Cursor::new(input)).unwrap_err();
+
+        assert_eq!(error.line, 9);
+        assert_eq!(error.text, "37 52 x");
+
+        let input = Example::load("examples/example.txt")
+            .unwrap()
+            .input
+            .replace("seeds: 79 14 55 13", "seeds: 79 14 55");
+        let error = parse_input(&mut Cursor::new(input)).unwrap_err();
+
+        assert_eq!((error.line, error.column), (1, 8));
+        assert_eq!(error.kind, nom::error::ErrorKind::Verify);
+    }
+
+    #[test]
+    fn no_seeds_tests() {
+        init();
+
+        let input = Example::load("examples/example.txt")
+            .unwrap()
+            .input
+            .replace("seeds: 79 14 55 13", "seeds: 79 0 55 0");
+        let input = parse_input(&mut Cursor::new(input)).unwrap();
+
+        assert_eq!(
+            part2(&input).unwrap_err().to_string(),
+            "no answer: there are no seeds to plant"
+        );
+    }
+
+    // Each seed through the maps on its own, by looking for the range that
+    // it's in
+    fn lowest_location_one_by_one(input: &str) -> Answer {
+        let mut sections = input.split("\n\n");
+        let seeds: Vec<i64> = sections
+            .next()
+            .unwrap()
+            .strip_prefix("seeds: ")
+            .unwrap()
+            .split(' ')
+            .map(|seed| seed.parse().unwrap())
+            .collect();
+        let maps: Vec<Vec<Vec<i64>>> = sections
+            .map(|map| {
+                map.lines()
+                    .skip(1)
+                    .map(|range| range.split(' ').map(|n| n.parse().unwrap()).collect())
+                    .collect()
+            })
+            .collect();
+
+        seeds
+            .iter()
+            .map(|&seed| {
+                maps.iter().fold(seed, |number, ranges| {
+                    ranges
+                        .iter()
+                        .find(|range| (range[1]..range[1] + range[2]).contains(&number))
+                        .map_or(number, |range| number - range[1] + range[0])
+                })
+            })
+            .min()
+            .unwrap()
+            .into()
+    }
+
+    #[test]
+    fn differential_tests() {
+        init();
+
+        differential::check_pair::<Day05, _>(
+            &Pair {
+                name: "lowest_location",
+                reference: lowest_location_one_by_one,
+                candidate: |input| part1(&parse_input(&mut Cursor::new(input)).unwrap()).unwrap(),
+            },
+            1..=10,
+        );
+    }
+}
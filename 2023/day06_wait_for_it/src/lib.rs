@@ -0,0 +1,146 @@
+use aoc_common::{answers::Answer, parse::ParseError, scan, Solution};
+use log::debug;
+use nom::{
+    character::complete::{self, space1},
+    multi::separated_list1,
+};
+use std::io::BufRead;
+
+fn parse_input(input: &mut impl BufRead) -> Result<(Vec<u64>, Vec<u64>), ParseError> {
+    let lines = aoc_common::read_input(input)?;
+
+    scan!(
+        lines.trim_end(),
+        "Time:" {_: space1} {separated_list1(space1, complete::u64)}
+        "\nDistance:" {_: space1} {separated_list1(space1, complete::u64)}
+    )
+}
+
+fn compute_ways_to_beat_record(times: &[u64], distances: &[u64]) -> Answer {
+    let ways = times
+        .iter()
+        .zip(distances.iter())
+        .map(|(t, d)| {
+            // The equation we solve is:
+            //   x(t-x) > d
+            //
+            //   -x^2 + xt - d > 0
+            //
+            //   x^2 - xt + d < 0
+            //
+            //   1 * x^2 - t * x + d < 0
+            //   ^       ^^^       ^
+            //   a       b         c
+            //
+            // The roots of the quadratic equation are:
+            //   x = (-b +- sqrt(b^2 - 4ac)) / 2
+            //
+            //   where a = 1, b = -t, c = d
+            //
+            //   x = (t +- sqrt(t^2 - 4d)) / 2
+            //
+            // We assume that there are no complex roots
+
+            let t: f64 = *t as f64;
+            let d: f64 = *d as f64;
+            let leftmost_root: f64 = (t - f64::sqrt(t.powi(2) - 4.0 * d)) / 2.0;
+            let rightmost_root: f64 = (t + f64::sqrt(t.powi(2) - 4.0 * d)) / 2.0;
+
+            debug!("{} {}", leftmost_root, rightmost_root);
+
+            // Since the leading coefficient (a) is 1 then the functions will
+            // be concave downward which means they will be smaller than 0
+            // between the roots. So the values that "beat the record" are
+            // the integers betwen the ceiling of the leftmost root and the
+            // floor of the rightmost root. Adjust -/+ 1 in case the roots are
+            // integers.
+
+            let rightmost_integer = if rightmost_root.fract() == 0.0 {
+                rightmost_root - 1.0
+            } else {
+                rightmost_root.floor()
+            };
+            let leftmost_integer = if leftmost_root.fract() == 0.0 {
+                leftmost_root + 1.0
+            } else {
+                leftmost_root.ceil()
+            };
+
+            rightmost_integer - leftmost_integer + 1.0
+        })
+        .product::<f64>();
+
+    // Every factor is a whole number of ways
+    Answer::from(ways as u64)
+}
+
+fn part1((times, distances): &(Vec<u64>, Vec<u64>)) -> Answer {
+    debug!("{:?}", times);
+    debug!("{:?}", distances);
+
+    compute_ways_to_beat_record(times, distances)
+}
+
+fn part2((times, distances): &(Vec<u64>, Vec<u64>)) -> Answer {
+    let concatenated_times: u64 = times
+        .iter()
+        .map(|t| t.to_string())
+        .collect::<String>()
+        .parse()
+        .unwrap();
+    let concatenated_distances: u64 = distances
+        .iter()
+        .map(|d| d.to_string())
+        .collect::<String>()
+        .parse()
+        .unwrap();
+
+    debug!("{}", concatenated_times);
+    debug!("{}", concatenated_distances);
+
+    compute_ways_to_beat_record(&[concatenated_times], &[concatenated_distances])
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 6;
+
+    type Input = (Vec<u64>, Vec<u64>);
+
+    fn parse(input: &mut impl BufRead) -> Result<(Vec<u64>, Vec<u64>), ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &(Vec<u64>, Vec<u64>)) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &(Vec<u64>, Vec<u64>)) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day06>();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day06>();
+    }
+}
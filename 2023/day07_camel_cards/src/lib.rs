@@ -0,0 +1,269 @@
+use aoc_common::{
+    answers::Answer,
+    parse::{self, ParseError},
+    Solution,
+};
+use log::debug;
+use nom::{
+    bytes::complete::{is_a, tag},
+    character::complete,
+    error::Error,
+};
+use std::io::BufRead;
+use std::{cmp::Ordering, collections::HashMap};
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
+enum Category {
+    HighCard,
+    OnePair,
+    TwoPair,
+    ThreeOfAKind,
+    FullHouse,
+    FourOfAKind,
+    FiveOfAKind,
+}
+
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Card {
+    Joker,
+    Two,
+    Three,
+    Four,
+    Five,
+    Six,
+    Seven,
+    Eight,
+    Nine,
+    T,
+    J,
+    Q,
+    K,
+    A,
+}
+
+impl From<(char, bool)> for Card {
+    fn from((input, j_is_joker): (char, bool)) -> Self {
+        match input {
+            '2' => Card::Two,
+            '3' => Card::Three,
+            '4' => Card::Four,
+            '5' => Card::Five,
+            '6' => Card::Six,
+            '7' => Card::Seven,
+            '8' => Card::Eight,
+            '9' => Card::Nine,
+            'T' => Card::T,
+            'J' => {
+                if j_is_joker {
+                    Card::Joker
+                } else {
+                    Card::J
+                }
+            }
+            'Q' => Card::Q,
+            'K' => Card::K,
+            'A' => Card::A,
+            _ => unreachable!(),
+        }
+    }
+}
+
+#[derive(Debug, Eq)]
+struct Hand {
+    cards: Vec<Card>,
+    bid: u32,
+    category: Category,
+}
+
+impl Ord for Hand {
+    fn cmp(&self, other: &Self) -> Ordering {
+        if self.category == other.category {
+            match self
+                .cards
+                .iter()
+                .zip(other.cards.iter())
+                .find(|(card1, card2)| card1 != card2)
+            {
+                Some((card1, card2)) => card1.cmp(card2),
+                None => Ordering::Equal,
+            }
+        } else {
+            self.category.cmp(&other.category)
+        }
+    }
+}
+
+impl PartialEq for Hand {
+    fn eq(&self, other: &Self) -> bool {
+        if self.category == other.category {
+            self.cards
+                .iter()
+                .zip(other.cards.iter())
+                .any(|(card1, card2)| card1 != card2)
+        } else {
+            false
+        }
+    }
+}
+
+impl PartialOrd for Hand {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl From<(&str, u32, bool)> for Hand {
+    fn from((card_str, bid, j_is_joker): (&str, u32, bool)) -> Self {
+        // Convert the input string to our Card enum
+        let cards: Vec<Card> = card_str.chars().map(|c| (c, j_is_joker).into()).collect();
+
+        // Get the frequency of each Card in the hand
+        let mut card_frequency_map: HashMap<&Card, u32> = HashMap::new();
+        cards.iter().for_each(|card| {
+            let frequency = card_frequency_map.entry(card).or_insert(0);
+            *frequency += 1;
+        });
+
+        // Sort the frequencies so that we don't have to write all permutations
+        // in the match arms below
+        let mut card_frequency_values: Vec<&u32> = card_frequency_map.values().collect();
+        card_frequency_values.sort();
+
+        let category = match card_frequency_values[..] {
+            [5] => Category::FiveOfAKind,
+            [1, 4] => {
+                if card_frequency_map.contains_key(&Card::Joker) {
+                    Category::FiveOfAKind
+                } else {
+                    Category::FourOfAKind
+                }
+            }
+            [2, 3] => {
+                if card_frequency_map.contains_key(&Card::Joker) {
+                    Category::FiveOfAKind
+                } else {
+                    Category::FullHouse
+                }
+            }
+            [1, 1, 3] => {
+                if card_frequency_map.contains_key(&Card::Joker) {
+                    Category::FourOfAKind
+                } else {
+                    Category::ThreeOfAKind
+                }
+            }
+            [1, 2, 2] => match card_frequency_map.get(&Card::Joker) {
+                Some(frequency) if *frequency == 1 => Category::FullHouse,
+                Some(frequency) if *frequency == 2 => Category::FourOfAKind,
+                _ => Category::TwoPair,
+            },
+            [1, 1, 1, 2] => {
+                if card_frequency_map.contains_key(&Card::Joker) {
+                    Category::ThreeOfAKind
+                } else {
+                    Category::OnePair
+                }
+            }
+            [1, 1, 1, 1, 1] => {
+                if card_frequency_map.contains_key(&Card::Joker) {
+                    Category::OnePair
+                } else {
+                    Category::HighCard
+                }
+            }
+            _ => unreachable!(),
+        };
+
+        Hand {
+            cards,
+            bid,
+            category,
+        }
+    }
+}
+
+fn parse_input(input: &mut impl BufRead) -> Result<Vec<(String, u32)>, ParseError> {
+    parse::parse_lines(input, |line| {
+        let (card_str, _, bid) = parse::parse(
+            line,
+            (
+                is_a::<&str, &str, Error<&str>>("23456789TJQKA"),
+                tag(" "),
+                complete::u32,
+            ),
+        )?;
+
+        Ok((card_str.to_string(), bid))
+    })
+}
+
+fn hands(input: &[(String, u32)], j_is_joker: bool) -> Vec<Hand> {
+    input
+        .iter()
+        .map(|(card_str, bid)| (card_str.as_str(), *bid, j_is_joker).into())
+        .collect()
+}
+
+fn compute_winnings(hands: &mut [Hand]) -> u32 {
+    hands.sort();
+
+    debug!("{:?}", hands);
+
+    hands
+        .iter()
+        .enumerate()
+        .map(|(rank, hand)| hand.bid * (rank + 1) as u32)
+        .sum::<u32>()
+}
+
+fn part1(input: &[(String, u32)]) -> Answer {
+    compute_winnings(&mut hands(input, false)).into()
+}
+
+fn part2(input: &[(String, u32)]) -> Answer {
+    compute_winnings(&mut hands(input, true)).into()
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 7;
+
+    type Input = Vec<(String, u32)>;
+
+    fn parse(input: &mut impl BufRead) -> Result<Vec<(String, u32)>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Vec<(String, u32)>) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Vec<(String, u32)>) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day07>();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day07>();
+    }
+}
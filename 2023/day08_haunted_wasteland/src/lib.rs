@@ -0,0 +1,255 @@
+use aoc_common::{
+    answers::Answer,
+    cycle, math,
+    parse::{self, ParseError},
+    Solution,
+};
+use log::debug;
+use nom::bytes::complete::{is_a, tag};
+use nom::character::complete::{alphanumeric1, multispace0, newline};
+use nom::error::Error;
+use nom::multi::separated_list1;
+use nom::IResult;
+use nom::Parser;
+use std::{collections::HashMap, io::BufRead};
+
+#[derive(Debug)]
+pub enum Instruction {
+    Left,
+    Right,
+}
+
+type Network = HashMap<String, (String, String)>;
+
+fn parse_input(input: &mut impl BufRead) -> Result<(Vec<Instruction>, Network), ParseError> {
+    fn node_parser(input: &str) -> IResult<&str, (&str, &str, &str)> {
+        (
+            alphanumeric1,
+            tag(" = ("),
+            alphanumeric1,
+            tag(", "),
+            alphanumeric1,
+            tag(")"),
+        )
+            .parse(input)
+            .map(|(s, (node, _, node_l, _, node_r, _))| (s, (node, node_l, node_r)))
+    }
+
+    let lines = aoc_common::read_input(input)?;
+
+    let (instructions, _, _, node_mappings, _) = parse::parse(
+        &lines,
+        (
+            is_a::<&str, &str, Error<&str>>("LR"),
+            newline,
+            newline,
+            separated_list1(newline, node_parser),
+            multispace0,
+        ),
+    )?;
+
+    let instructions = instructions
+        .chars()
+        .map(|c| match c {
+            'L' => Instruction::Left,
+            'R' => Instruction::Right,
+            _ => unreachable!(),
+        })
+        .collect::<Vec<Instruction>>();
+
+    let mut network: Network = HashMap::new();
+    node_mappings.iter().for_each(|(node, node_l, node_r)| {
+        network.insert(node.to_string(), (node_l.to_string(), node_r.to_string()));
+    });
+
+    Ok((instructions, network))
+}
+
+fn number_of_steps(
+    start_node: &str,
+    is_end_node: fn(&str) -> bool,
+    instructions: &[Instruction],
+    network: &Network,
+) -> u64 {
+    let mut instruction_iterator = instructions.iter().cycle();
+    let mut current_node = start_node;
+    let mut steps: u64 = 0;
+
+    while !is_end_node(current_node) {
+        let next_nodes = network.get(current_node).unwrap();
+        match instruction_iterator.next().unwrap() {
+            Instruction::Left => current_node = &next_nodes.0,
+            Instruction::Right => current_node = &next_nodes.1,
+        }
+        steps += 1;
+    }
+
+    steps
+}
+
+fn part1((instructions, network): &(Vec<Instruction>, Network)) -> Answer {
+    debug!("{:?}", instructions);
+    debug!("{:?}", network);
+
+    number_of_steps("AAA", |node| node == "ZZZ", instructions, network).into()
+}
+
+// When a ghost is on a node that ends with Z: the times before its walk
+// starts going around in a loop, and the times during the first time around
+// the loop, which then come back every `length` steps
+struct Ghost {
+    early: Vec<u64>,
+    looping: Vec<u64>,
+    start: u64,
+    length: u64,
+}
+
+impl Ghost {
+    // The walk is in a loop once the ghost is back on the same node at the
+    // same point of the instructions
+    fn new<'a>(start_node: &'a str, instructions: &[Instruction], network: &'a Network) -> Self {
+        let step = |(node, index): &mut (&'a str, usize)| {
+            let next_nodes = network.get(*node).unwrap();
+            *node = match instructions[*index] {
+                Instruction::Left => &next_nodes.0,
+                Instruction::Right => &next_nodes.1,
+            };
+            *index = (*index + 1) % instructions.len();
+        };
+        let cycle = cycle::find((start_node, 0), &step, |&state| state);
+
+        let mut ghost = Ghost {
+            early: Vec::new(),
+            looping: Vec::new(),
+            start: cycle.start as u64,
+            length: cycle.length as u64,
+        };
+
+        let mut state = (start_node, 0);
+        (0..(cycle.start + cycle.length) as u64).for_each(|time| {
+            if state.0.ends_with('Z') {
+                if time < ghost.start {
+                    ghost.early.push(time);
+                } else {
+                    ghost.looping.push(time);
+                }
+            }
+            step(&mut state);
+        });
+
+        ghost
+    }
+
+    fn at_end(&self, time: u64) -> bool {
+        self.early.contains(&time)
+            || (time >= self.start
+                && self
+                    .looping
+                    .iter()
+                    .any(|&end| (time as i64 - end as i64) % self.length as i64 == 0))
+    }
+}
+
+// Each ghost's loop comes with its own times of being at the end, which turn
+// into a time for all of them with the Chinese remainder theorem. The loops
+// don't have to be the same length as the way to them, nor go through a
+// single Z node. When none of the times fit every ghost, they never all
+// get to the end together.
+fn part2((instructions, network): &(Vec<Instruction>, Network)) -> Result<Answer, ParseError> {
+    let ghosts: Vec<Ghost> = network
+        .keys()
+        .filter(|node| node.ends_with('A'))
+        .map(|node| Ghost::new(node, instructions, network))
+        .collect();
+
+    // Some of the ghosts may not be in their loops yet
+    let early = ghosts
+        .iter()
+        .flat_map(|ghost| ghost.early.iter().copied())
+        .filter(|&time| ghosts.iter().all(|ghost| ghost.at_end(time)));
+
+    let congruences = ghosts.iter().fold(vec![(0, 1)], |congruences, ghost| {
+        congruences
+            .iter()
+            .flat_map(|&congruence| {
+                ghost.looping.iter().filter_map(move |&end| {
+                    math::crt([congruence, (end as i64, ghost.length as i64)])
+                })
+            })
+            .collect()
+    });
+
+    // The times that fit have to be after all the ghosts are in their loops
+    let after = ghosts.iter().map(|ghost| ghost.start).max().unwrap_or(0) as i64;
+    let looping = congruences
+        .iter()
+        .map(|&(remainder, modulus)| (after + (remainder - after).rem_euclid(modulus)) as u64);
+
+    early
+        .chain(looping)
+        .min()
+        .map(Answer::from)
+        .ok_or_else(|| ParseError::no_answer("the ghosts are never all at the end at once"))
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 8;
+
+    type Input = (Vec<Instruction>, Network);
+
+    fn parse(input: &mut impl BufRead) -> Result<(Vec<Instruction>, Network), ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &(Vec<Instruction>, Network)) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &(Vec<Instruction>, Network)) -> Option<Result<Answer, ParseError>> {
+        Some(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use std::io::Cursor;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day08>();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day08>();
+    }
+
+    // One ghost is at the end on the odd steps, the other on the even ones
+    #[test]
+    fn never_together_tests() {
+        init();
+
+        let input = "LR\n\n11A = (11Z, 11Z)\n11Z = (11A, 11A)\n\
+                     22A = (22B, 22B)\n22B = (22Z, 22Z)\n22Z = (22B, 22B)\n";
+        let input = parse_input(&mut Cursor::new(input)).unwrap();
+
+        assert_eq!(
+            part2(&input),
+            Err(ParseError::no_answer(
+                "the ghosts are never all at the end at once"
+            ))
+        );
+    }
+}
@@ -0,0 +1,264 @@
+use aoc_common::{answers::Answer, grid::Grid, parse::ParseError, Solution};
+use log::debug;
+use std::collections::VecDeque;
+use std::io::BufRead;
+
+#[derive(Clone, Debug)]
+struct Tile {
+    kind: char,
+    visited: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct Tiles {
+    pipes_and_ground: Grid<Tile>,
+    start: (usize, usize),
+}
+
+impl Tiles {
+    fn parse(input: &mut impl BufRead) -> Result<Self, ParseError> {
+        let pipes_and_ground = Grid::parse(input, |c| {
+            "|-LJ7FS.".contains(c).then_some(Tile {
+                kind: c,
+                visited: false,
+            })
+        })?;
+        let start = pipes_and_ground
+            .iter()
+            .find(|(_, tile)| tile.kind == 'S')
+            .map(|(position, _)| position)
+            .ok_or_else(|| ParseError::missing(pipes_and_ground.nrows() + 1, "a start tile S"))?;
+
+        Ok(Tiles {
+            pipes_and_ground,
+            start,
+        })
+    }
+
+    // The neighbour in the given direction, as long as its pipe connects
+    // back to us
+    fn connected_neighbour(
+        &self,
+        position: (usize, usize),
+        offset: (isize, isize),
+        pipes: [char; 3],
+    ) -> Option<(usize, usize)> {
+        self.pipes_and_ground
+            .offset(position, offset)
+            .filter(|&neighbour| pipes.contains(&self.pipes_and_ground[neighbour].kind))
+    }
+
+    fn north_neighbour(&self, position: (usize, usize)) -> Option<(usize, usize)> {
+        self.connected_neighbour(position, (-1, 0), ['7', 'F', '|'])
+    }
+
+    fn south_neighbour(&self, position: (usize, usize)) -> Option<(usize, usize)> {
+        self.connected_neighbour(position, (1, 0), ['L', 'J', '|'])
+    }
+
+    fn west_neighbour(&self, position: (usize, usize)) -> Option<(usize, usize)> {
+        self.connected_neighbour(position, (0, -1), ['L', 'F', '-'])
+    }
+
+    fn east_neighbour(&self, position: (usize, usize)) -> Option<(usize, usize)> {
+        self.connected_neighbour(position, (0, 1), ['7', 'J', '-'])
+    }
+}
+
+fn get_valid_neighbours(position: (usize, usize), tiles: &mut Tiles) -> Vec<(usize, usize)> {
+    let north = tiles.north_neighbour(position);
+    let south = tiles.south_neighbour(position);
+    let west = tiles.west_neighbour(position);
+    let east = tiles.east_neighbour(position);
+
+    let valid_neighbours = match tiles.pipes_and_ground[position].kind {
+        '|' => [north, south],
+        '-' => [west, east],
+        'L' => [north, east],
+        'J' => [north, west],
+        '7' => [west, south],
+        'F' => [east, south],
+        'S' => {
+            // Replace 'S' with the actual pipe symbol while we're here,
+            // so that we don't have to do any special handling for it in part 2
+            tiles.pipes_and_ground[position].kind = match (
+                north.is_some(),
+                south.is_some(),
+                east.is_some(),
+                west.is_some(),
+            ) {
+                (true, true, false, false) => '|',
+                (false, false, true, true) => '-',
+                (true, false, true, false) => 'L',
+                (true, false, false, true) => 'J',
+                (false, true, false, true) => '7',
+                (false, true, true, false) => 'F',
+                _ => unreachable!(),
+            };
+
+            return [north, south, west, east].into_iter().flatten().collect();
+        }
+        _ => unreachable!(),
+    };
+
+    valid_neighbours.into_iter().flatten().collect()
+}
+
+fn find_steps_to_farthest_point(tiles: &mut Tiles) -> (usize, Vec<(usize, usize)>) {
+    let starting_position = tiles.start;
+
+    let mut pipe_loop: Vec<(usize, usize)> = vec![(starting_position)];
+    let mut tiles_to_visit: VecDeque<((usize, usize), usize)> =
+        VecDeque::from([(starting_position, 0)]);
+    let mut max_steps: usize = usize::MIN;
+
+    // Regular BFS
+    loop {
+        debug!("{:?}", tiles_to_visit);
+
+        let (current_tile_pos, current_tile_steps) = tiles_to_visit.pop_front().unwrap();
+        let neighbours = get_valid_neighbours(current_tile_pos, tiles);
+
+        if max_steps < current_tile_steps {
+            max_steps = current_tile_steps;
+        }
+
+        debug!("{:?}", neighbours);
+
+        neighbours.iter().for_each(|neighbour_pos| {
+            if !tiles.pipes_and_ground[*neighbour_pos].visited {
+                tiles.pipes_and_ground[*neighbour_pos].visited = true;
+                tiles_to_visit.push_back((*neighbour_pos, current_tile_steps + 1));
+                pipe_loop.push(*neighbour_pos);
+            }
+        });
+
+        if tiles_to_visit.is_empty() {
+            break;
+        }
+    }
+
+    (max_steps, pipe_loop)
+}
+
+fn part1(tiles: &Tiles) -> Answer {
+    let mut tiles = tiles.clone();
+
+    debug!("{:?}", tiles);
+
+    let (steps, _) = find_steps_to_farthest_point(&mut tiles);
+
+    steps.into()
+}
+
+fn part2(tiles: &Tiles) -> Answer {
+    let mut tiles = tiles.clone();
+
+    debug!("{:?}", tiles);
+
+    let (_, pipe_loop) = find_steps_to_farthest_point(&mut tiles);
+
+    // We'll reuse the visited field to mark the tiles outside of the loop
+    // so reset it here since it's been used in find_steps_to_farthest_point
+    for (_, tile) in tiles.pipes_and_ground.iter_mut() {
+        tile.visited = false;
+    }
+
+    debug!("{:?}", pipe_loop);
+
+    // Ray casting algorithm
+    for i in 0..tiles.pipes_and_ground.nrows() {
+        let mut number_of_intersections = 0;
+        let mut line_start: char = ' ';
+
+        for j in 0..tiles.pipes_and_ground.ncols() {
+            if pipe_loop.contains(&(i, j)) {
+                // The following are considered polygon edges
+                // |
+                // L7, L-7, L--7, L---7, ...
+                // FJ, F-J, F--J, F---J, ...
+                //
+                // Anything else is not an edge
+                match tiles.pipes_and_ground[(i, j)].kind {
+                    '|' => {
+                        number_of_intersections += 1;
+                    }
+                    '-' => {}
+                    'L' => {
+                        line_start = 'L';
+                    }
+                    'J' => {
+                        if line_start == 'F' {
+                            number_of_intersections += 1;
+                            line_start = ' ';
+                        }
+                    }
+                    '7' => {
+                        if line_start == 'L' {
+                            number_of_intersections += 1;
+                            line_start = ' ';
+                        }
+                    }
+                    'F' => {
+                        line_start = 'F';
+                    }
+                    _ => unreachable!(),
+                }
+            } else if number_of_intersections % 2 != 0 {
+                tiles.pipes_and_ground[(i, j)].visited = true;
+            }
+        }
+    }
+
+    let enclosed = tiles
+        .pipes_and_ground
+        .iter()
+        .filter(|(_, tile)| tile.visited)
+        .count();
+
+    enclosed.into()
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 10;
+
+    type Input = Tiles;
+
+    fn parse(input: &mut impl BufRead) -> Result<Tiles, ParseError> {
+        Tiles::parse(input)
+    }
+
+    fn part1(input: &Tiles) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Tiles) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day10>();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day10>();
+    }
+}
@@ -0,0 +1,167 @@
+use aoc_common::{answers::Answer, examples, grid::Grid, parse::ParseError, Solution};
+use itertools::Itertools;
+use log::debug;
+use std::cmp;
+use std::io::BufRead;
+
+#[derive(Debug)]
+pub struct Image {
+    empty_space_and_galaxies: Grid<char>,
+}
+
+impl Image {
+    fn parse(input: &mut impl BufRead) -> Result<Self, ParseError> {
+        let empty_space_and_galaxies = Grid::parse(input, |c| ".#".contains(c).then_some(c))?;
+
+        Ok(Image {
+            empty_space_and_galaxies,
+        })
+    }
+}
+
+fn taxicab_distance(
+    (galaxy1_row, galaxy1_col): (usize, usize),
+    (galaxy2_row, galaxy2_col): (usize, usize),
+    expanding_rows: &[usize],
+    expanding_cols: &[usize],
+    expansion_size: usize,
+) -> i64 {
+    let rows_between_galaxies = expanding_rows
+        .iter()
+        .filter(|row| {
+            **row >= cmp::min(galaxy1_row, galaxy2_row)
+                && **row <= cmp::max(galaxy1_row, galaxy2_row)
+        })
+        .count();
+    let cols_between_galaxies = expanding_cols
+        .iter()
+        .filter(|col| {
+            **col >= cmp::min(galaxy1_col, galaxy2_col)
+                && **col <= cmp::max(galaxy1_col, galaxy2_col)
+        })
+        .count();
+
+    let dist = (galaxy1_row as i64 - galaxy2_row as i64).abs()
+        + (galaxy1_col as i64 - galaxy2_col as i64).abs()
+        + (rows_between_galaxies * (expansion_size - 1)) as i64
+        + (cols_between_galaxies * (expansion_size - 1)) as i64;
+
+    debug!("{:?}", dist);
+
+    dist
+}
+
+fn sum_of_lengths(image: &Image, expansion_size: usize) -> i64 {
+    let expanding_rows = image
+        .empty_space_and_galaxies
+        .as_array()
+        .rows()
+        .into_iter()
+        .enumerate()
+        .filter_map(|(row_index, row)| {
+            if row.iter().all(|space| *space == '.') {
+                Some(row_index)
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<usize>>();
+
+    let expanding_cols = image
+        .empty_space_and_galaxies
+        .as_array()
+        .columns()
+        .into_iter()
+        .enumerate()
+        .filter_map(|(col_index, col)| {
+            if col.iter().all(|space| *space == '.') {
+                Some(col_index)
+            } else {
+                None
+            }
+        })
+        .collect::<Vec<usize>>();
+
+    debug!("{:?}", expanding_rows);
+    debug!("{:?}", expanding_cols);
+
+    image
+        .empty_space_and_galaxies
+        .find_all(&'#')
+        .combinations(2)
+        .map(|galaxies| {
+            taxicab_distance(
+                galaxies[0],
+                galaxies[1],
+                &expanding_rows,
+                &expanding_cols,
+                expansion_size,
+            )
+        })
+        .sum::<i64>()
+}
+
+fn part1(image: &Image) -> Answer {
+    sum_of_lengths(image, 2).into()
+}
+
+fn part2(image: &Image, expansion_size: usize) -> Answer {
+    sum_of_lengths(image, expansion_size).into()
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 11;
+
+    // The image, and how many times bigger part 2 makes the empty space
+    type Input = (Image, usize);
+
+    fn parse(input: &mut impl BufRead) -> Result<(Image, usize), ParseError> {
+        Ok((Image::parse(input)?, 1000000))
+    }
+
+    fn set_parameter(
+        (_, expansion_size): &mut (Image, usize),
+        name: &str,
+        value: &str,
+    ) -> Result<(), String> {
+        match name {
+            "expansion" => *expansion_size = examples::parameter(name, value)?,
+            _ => return Err(format!("unknown parameter {}", name)),
+        }
+        Ok(())
+    }
+
+    fn part1((image, _): &(Image, usize)) -> Result<Answer, ParseError> {
+        Ok(part1(image))
+    }
+
+    fn part2((image, expansion_size): &(Image, usize)) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(image, *expansion_size)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day11>();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day11>();
+    }
+}
@@ -0,0 +1,67 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+// A row of springs that has at least one arrangement: the groups of damaged
+// springs are laid out first, and then about half of the springs are hidden
+// behind a ?. The rows are as long as the real ones, up to 20 springs.
+fn row(rng: &mut StdRng) -> String {
+    let length: usize = rng.random_range(5..=20);
+    let mut springs = String::new();
+    let mut groups = Vec::new();
+
+    loop {
+        let gap = rng.random_range(if groups.is_empty() { 0 } else { 1 }..=3);
+        springs.push_str(&".".repeat(gap));
+
+        let room = length.saturating_sub(springs.len());
+        if room == 0 {
+            break;
+        }
+        let group = rng.random_range(1..=room.min(5));
+        springs.push_str(&"#".repeat(group));
+        groups.push(group.to_string());
+    }
+    springs.truncate(length);
+
+    let hidden: String = springs
+        .chars()
+        .map(|spring| if rng.random_bool(0.5) { '?' } else { spring })
+        .collect();
+
+    format!("{} {}", hidden, groups.join(","))
+}
+
+// `size` rows of springs
+pub fn generate(size: usize, seed: u64) -> String {
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    (0..size).map(|_| row(&mut rng) + "\n").collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{generate::*, Day12};
+    use aoc_common::{answers::Answer, Solution};
+
+    #[test]
+    fn generate_tests() {
+        let input = generate(100, 7);
+        assert_eq!(input.lines().count(), 100);
+        assert_eq!(input, generate(100, 7));
+        assert_ne!(input, generate(100, 8));
+
+        input.lines().for_each(|line| {
+            let (springs, groups) = line.split_once(' ').unwrap();
+            assert!((5..=20).contains(&springs.len()));
+            assert!(groups
+                .split(',')
+                .all(|group| group.parse::<usize>().is_ok()));
+        });
+
+        // Every row has at least the arrangement that it was made from
+        let parsed = Day12::parse(&mut input.as_bytes()).unwrap();
+        let Ok(Answer::Integer(arrangements)) = Day12::part1(&parsed) else {
+            panic!("no answer");
+        };
+        assert!(arrangements >= 100);
+    }
+}
@@ -0,0 +1,256 @@
+mod generate;
+
+use aoc_common::{
+    answers::Answer,
+    parse::{self, ParseError},
+    Solution,
+};
+use log::debug;
+use nom::{
+    bytes::complete::{is_a, tag},
+    character::complete,
+    error::Error,
+    multi::separated_list1,
+    IResult, Parser,
+};
+use std::{collections::VecDeque, io::BufRead};
+
+#[derive(Debug)]
+pub struct Row {
+    springs: String,
+    damaged_pattern: Vec<usize>,
+}
+
+fn parse_input(input: &mut impl BufRead) -> Result<Vec<Row>, ParseError> {
+    fn parse_line(input: &str) -> IResult<&str, Row, Error<&str>> {
+        (
+            is_a("?.#"),
+            tag(" "),
+            separated_list1(tag(","), complete::u32),
+        )
+            .map(|(springs, _, damaged_pattern): (&str, _, Vec<u32>)| Row {
+                springs: springs.to_string(),
+                damaged_pattern: damaged_pattern.into_iter().map(|n| n as usize).collect(),
+            })
+            .parse(input)
+    }
+
+    parse::parse_lines(input, |line| parse::parse(line, parse_line))
+}
+
+impl Row {
+    fn count_arrangements(&self) -> usize {
+        fn count_arrangements_rec(
+            current_arrangement: String,
+            index: usize,
+            damaged_pattern: &mut VecDeque<usize>,
+            need_operational: bool,
+            need_damaged: bool,
+        ) -> usize {
+            debug!(
+                "{}, {}, {:?}, {}",
+                current_arrangement, index, damaged_pattern, need_operational
+            );
+
+            if index == current_arrangement.len() && damaged_pattern.is_empty() {
+                1
+            } else if index == current_arrangement.len()
+                || (damaged_pattern.is_empty() && current_arrangement[index..].contains('#'))
+            {
+                0
+            } else if damaged_pattern.is_empty() && !current_arrangement[index..].contains('#') {
+                1
+            } else {
+                let current_spring = current_arrangement.chars().nth(index).unwrap();
+
+                match current_spring {
+                    // Operational spring
+                    '.' => {
+                        if need_damaged {
+                            0
+                        } else {
+                            count_arrangements_rec(
+                                current_arrangement,
+                                index + 1,
+                                &mut damaged_pattern.clone(),
+                                false,
+                                false,
+                            )
+                        }
+                    }
+                    // Damaged spring
+                    '#' => {
+                        if need_operational {
+                            0
+                        } else if damaged_pattern[0] == 1 {
+                            damaged_pattern.pop_front();
+                            count_arrangements_rec(
+                                current_arrangement,
+                                index + 1,
+                                &mut damaged_pattern.clone(),
+                                true,
+                                false,
+                            )
+                        } else {
+                            damaged_pattern[0] -= 1;
+                            count_arrangements_rec(
+                                current_arrangement,
+                                index + 1,
+                                &mut damaged_pattern.clone(),
+                                false,
+                                true,
+                            )
+                        }
+                    }
+                    // Unknown spring
+                    '?' => {
+                        let mut new_arrangement_with_operational_spring =
+                            current_arrangement.clone();
+                        new_arrangement_with_operational_spring
+                            .replace_range(index..index + 1, ".");
+                        let mut new_arrangement_with_damaged_spring = current_arrangement.clone();
+                        new_arrangement_with_damaged_spring.replace_range(index..index + 1, "#");
+                        count_arrangements_rec(
+                            new_arrangement_with_operational_spring,
+                            index,
+                            damaged_pattern,
+                            need_operational,
+                            need_damaged,
+                        ) + count_arrangements_rec(
+                            new_arrangement_with_damaged_spring,
+                            index,
+                            &mut damaged_pattern.clone(),
+                            need_operational,
+                            need_damaged,
+                        )
+                    }
+                    _ => unreachable!(),
+                }
+            }
+        }
+
+        count_arrangements_rec(
+            self.springs.clone(),
+            0,
+            &mut self.damaged_pattern.iter().copied().collect(),
+            false,
+            false,
+        )
+    }
+}
+
+fn part1(rows: &[Row]) -> Answer {
+    debug!("{:?}", rows);
+
+    rows.iter()
+        .map(|row| row.count_arrangements())
+        .sum::<usize>()
+        .into()
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 12;
+
+    type Input = Vec<Row>;
+
+    fn parse(input: &mut impl BufRead) -> Result<Vec<Row>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Vec<Row>) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn generate(size: usize, seed: u64) -> Option<String> {
+        Some(generate::generate(size, seed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+    use aoc_common::differential::{self, Pair};
+    use std::io::Cursor;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day12>();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day12>();
+    }
+    // Every way of filling in the ?s, keeping the ones whose groups of
+    // damaged springs are the right ones
+    fn count_by_brute_force(input: &str) -> Vec<usize> {
+        input
+            .lines()
+            .map(|line| {
+                let (springs, pattern) = line.split_once(' ').unwrap();
+                let groups: Vec<usize> = pattern
+                    .split(',')
+                    .map(|group| group.parse().unwrap())
+                    .collect();
+                let unknown = springs.matches('?').count();
+
+                (0..1u32 << unknown)
+                    .filter(|damaged| {
+                        let mut bit = 0;
+                        let filled: String = springs
+                            .chars()
+                            .map(|spring| match spring {
+                                '?' => {
+                                    bit += 1;
+                                    if damaged >> (bit - 1) & 1 == 1 {
+                                        '#'
+                                    } else {
+                                        '.'
+                                    }
+                                }
+                                _ => spring,
+                            })
+                            .collect();
+
+                        filled
+                            .split('.')
+                            .filter(|group| !group.is_empty())
+                            .map(str::len)
+                            .eq(groups.iter().copied())
+                    })
+                    .count()
+            })
+            .collect()
+    }
+
+    #[test]
+    fn differential_tests() {
+        init();
+
+        differential::check_pair::<Day12, _>(
+            &Pair {
+                name: "count_arrangements",
+                reference: count_by_brute_force,
+                candidate: |input| {
+                    parse_input(&mut Cursor::new(input))
+                        .unwrap()
+                        .iter()
+                        .map(Row::count_arrangements)
+                        .collect()
+                },
+            },
+            1..=10,
+        );
+    }
+}
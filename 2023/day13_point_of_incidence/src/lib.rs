@@ -0,0 +1,211 @@
+use aoc_common::{answers::Answer, grid::Grid, parse::ParseError, Solution};
+use log::debug;
+use ndarray::ArrayView1;
+use std::io::BufRead;
+use std::ops::Range;
+
+// The patterns are separated by blank lines
+fn parse_input(input: &mut impl BufRead) -> Result<Vec<Grid<char>>, ParseError> {
+    let text = aoc_common::read_input(input)?;
+    let mut first_line = 0;
+
+    text.split("\n\n")
+        .map(|pattern| {
+            let mirror_array =
+                Grid::parse(&mut pattern.as_bytes(), |c| "#.".contains(c).then_some(c)).map_err(
+                    |e| {
+                        let line = first_line + e.line;
+                        e.at_line(line)
+                    },
+                );
+            first_line += pattern.lines().count() + 1;
+
+            mirror_array
+        })
+        .collect()
+}
+
+#[derive(Debug, Copy, Clone)]
+enum ReflectionType {
+    Row,
+    Column,
+    None,
+}
+
+// Given a mirror array and a reflection criteria, find the reflection line
+// (this can be either a row or a column, or none)
+//
+// is_reflection is a function that takes two ranges of either rows or columns
+// and checks if they are reflected
+fn find_reflection_line(
+    mirror_array: &Grid<char>,
+    is_reflection: impl Fn(&Grid<char>, Range<usize>, Range<usize>, ReflectionType) -> bool,
+) -> (ReflectionType, usize) {
+    let number_of_rows = mirror_array.nrows();
+    let number_of_cols = mirror_array.ncols();
+
+    if let Some(horizontal_line) = (1..number_of_rows).find(|horizontal_line| {
+        let rows_above = 0..*horizontal_line;
+        let rows_below = *horizontal_line..number_of_rows;
+
+        debug!("{:?} {:?}", rows_above, rows_below);
+
+        is_reflection(mirror_array, rows_above, rows_below, ReflectionType::Row)
+    }) {
+        return (ReflectionType::Row, horizontal_line);
+    }
+
+    if let Some(vertical_line) = (1..number_of_cols).find(|vertical_line| {
+        let columns_to_the_left = 0..*vertical_line;
+        let columns_to_the_right = *vertical_line..number_of_cols;
+
+        debug!("{:?} {:?}", columns_to_the_left, columns_to_the_right);
+
+        is_reflection(
+            mirror_array,
+            columns_to_the_left,
+            columns_to_the_right,
+            ReflectionType::Column,
+        )
+    }) {
+        return (ReflectionType::Column, vertical_line);
+    }
+
+    (ReflectionType::None, 0)
+}
+
+fn get_array_view(
+    mirror_array: &Grid<char>,
+    index: usize,
+    reflection_type: ReflectionType,
+) -> ArrayView1<'_, char> {
+    match reflection_type {
+        ReflectionType::Row => mirror_array.row(index),
+        ReflectionType::Column => mirror_array.column(index),
+        ReflectionType::None => unreachable!(),
+    }
+}
+
+// A pattern without a line of reflection has no summary, and so neither do
+// the patterns altogether
+fn get_summary(
+    mirror_arrays: &[Grid<char>],
+    reflection_criteria: impl Fn(&Grid<char>, Range<usize>, Range<usize>, ReflectionType) -> bool,
+) -> Result<usize, ParseError> {
+    mirror_arrays
+        .iter()
+        .zip(1..)
+        .map(|(mirror_array, number)| {
+            let reflection_line = find_reflection_line(mirror_array, &reflection_criteria);
+
+            debug!("{:?}", reflection_line);
+
+            match reflection_line {
+                (ReflectionType::Row, line) => Ok(line * 100),
+                (ReflectionType::Column, col) => Ok(col),
+                (ReflectionType::None, _) => Err(ParseError::no_answer(format!(
+                    "pattern {} has no line of reflection",
+                    number
+                ))),
+            }
+        })
+        .sum()
+}
+
+// In part 1 the row/column ranges reflect when they are equal
+fn part1_reflection_criteria(
+    mirror_array: &Grid<char>,
+    range1: Range<usize>,
+    range2: Range<usize>,
+    reflection_type: ReflectionType,
+) -> bool {
+    range1.rev().zip(range2).all(|(lower, higher)| {
+        get_array_view(mirror_array, lower, reflection_type).to_vec()
+            == get_array_view(mirror_array, higher, reflection_type).to_vec()
+    })
+}
+
+// In part 2 the row/column ranges reflect when they differ by exactly
+// one character
+fn part2_reflection_criteria(
+    mirror_array: &Grid<char>,
+    range1: Range<usize>,
+    range2: Range<usize>,
+    reflection_type: ReflectionType,
+) -> bool {
+    range1
+        .rev()
+        .zip(range2)
+        .map(|(lower, higher)| {
+            let lower_set: String = get_array_view(mirror_array, lower, reflection_type)
+                .iter()
+                .collect();
+            let higher_set: String = get_array_view(mirror_array, higher, reflection_type)
+                .iter()
+                .collect();
+
+            lower_set
+                .chars()
+                .zip(higher_set.chars())
+                .filter(|(c1, c2)| c1 != c2)
+                .count()
+        })
+        .sum::<usize>()
+        == 1
+}
+
+fn part1(mirror_arrays: &[Grid<char>]) -> Result<Answer, ParseError> {
+    debug!("{:?}", mirror_arrays);
+
+    get_summary(mirror_arrays, part1_reflection_criteria).map(Answer::from)
+}
+
+fn part2(mirror_arrays: &[Grid<char>]) -> Result<Answer, ParseError> {
+    debug!("{:?}", mirror_arrays);
+
+    get_summary(mirror_arrays, part2_reflection_criteria).map(Answer::from)
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 13;
+
+    type Input = Vec<Grid<char>>;
+
+    fn parse(input: &mut impl BufRead) -> Result<Vec<Grid<char>>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Vec<Grid<char>>) -> Result<Answer, ParseError> {
+        part1(input)
+    }
+
+    fn part2(input: &Vec<Grid<char>>) -> Option<Result<Answer, ParseError>> {
+        Some(part2(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day13>();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day13>();
+    }
+}
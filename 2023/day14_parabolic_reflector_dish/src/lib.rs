@@ -0,0 +1,210 @@
+use aoc_common::{
+    answers::Answer, cycle, geometry::Direction, grid::Grid, parse::ParseError, Solution,
+};
+use std::io::BufRead;
+
+// Round rocks, cube-shaped rocks and empty spaces
+fn parse_input(input: &mut impl BufRead) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input, |c| match c {
+        'O' | '#' | '.' => Some(c),
+        _ => None,
+    })
+}
+
+fn tilt_in_direction(
+    direction: Direction,
+    platform: &Grid<char>,
+    platform_after_tilt: &mut Grid<char>,
+) {
+    // We use the usize::MAX value as a marker that the variable
+    // has not been set (this works since the input indexes are
+    // not that large).
+    let mut rock = usize::MAX;
+
+    match direction {
+        Direction::North => {
+            for col in 0..platform.ncols() {
+                for row in 0..platform.nrows() {
+                    match platform[(row, col)] {
+                        '#' => {
+                            rock = row;
+                        }
+                        'O' => {
+                            if rock == usize::MAX {
+                                platform_after_tilt[(0, col)] = 'O';
+                                rock = 0;
+                            } else {
+                                platform_after_tilt[(rock + 1, col)] = 'O';
+                                rock += 1;
+                            }
+                        }
+                        '.' => {}
+                        _ => unreachable!(),
+                    };
+                }
+
+                rock = usize::MAX;
+            }
+        }
+        Direction::South => {
+            for col in 0..platform.ncols() {
+                for row in (0..platform.nrows()).rev() {
+                    match platform[(row, col)] {
+                        '#' => {
+                            rock = row;
+                        }
+                        'O' => {
+                            if rock == usize::MAX {
+                                platform_after_tilt[(platform.nrows() - 1, col)] = 'O';
+                                rock = platform.nrows() - 1;
+                            } else {
+                                platform_after_tilt[(rock - 1, col)] = 'O';
+                                rock -= 1;
+                            }
+                        }
+                        '.' => {}
+                        _ => unreachable!(),
+                    };
+                }
+
+                rock = usize::MAX;
+            }
+        }
+        Direction::West => {
+            for row in 0..platform.nrows() {
+                for col in 0..platform.ncols() {
+                    match platform[(row, col)] {
+                        '#' => {
+                            rock = col;
+                        }
+                        'O' => {
+                            if rock == usize::MAX {
+                                platform_after_tilt[(row, 0)] = 'O';
+                                rock = 0;
+                            } else {
+                                platform_after_tilt[(row, rock + 1)] = 'O';
+                                rock += 1;
+                            }
+                        }
+                        '.' => {}
+                        _ => unreachable!(),
+                    };
+                }
+
+                rock = usize::MAX;
+            }
+        }
+        Direction::East => {
+            for row in 0..platform.nrows() {
+                for col in (0..platform.ncols()).rev() {
+                    match platform[(row, col)] {
+                        '#' => {
+                            rock = col;
+                        }
+                        'O' => {
+                            if rock == usize::MAX {
+                                platform_after_tilt[(row, platform.ncols() - 1)] = 'O';
+                                rock = platform.ncols() - 1;
+                            } else {
+                                platform_after_tilt[(row, rock - 1)] = 'O';
+                                rock -= 1;
+                            }
+                        }
+                        '.' => {}
+                        _ => unreachable!(),
+                    };
+                }
+
+                rock = usize::MAX;
+            }
+        }
+    }
+}
+
+// A fresh copy of the platform with its round rocks rolled as far as they
+// go in `direction`
+fn tilt(direction: Direction, platform: &Grid<char>) -> Grid<char> {
+    let mut platform_after_tilt = platform.map(|&c| if c == '#' { '#' } else { '.' });
+
+    tilt_in_direction(direction, platform, &mut platform_after_tilt);
+
+    platform_after_tilt
+}
+
+// One spin cycle: north, then west, then south, then east
+fn spin(platform: &mut Grid<char>) {
+    *platform = [
+        Direction::North,
+        Direction::West,
+        Direction::South,
+        Direction::East,
+    ]
+    .into_iter()
+    .fold(platform.clone(), |platform, direction| {
+        tilt(direction, &platform)
+    });
+}
+
+fn north_load(platform: &Grid<char>) -> usize {
+    platform
+        .iter()
+        .filter(|(_, c)| **c == 'O')
+        .map(|((i, _), _)| platform.nrows() - i)
+        .sum()
+}
+
+fn part1(platform: &Grid<char>) -> Answer {
+    north_load(&tilt(Direction::North, platform)).into()
+}
+
+fn part2(platform: &Grid<char>) -> Answer {
+    // The rocks end up going around the same few layouts over and over, so
+    // only the spins up to the second time around one are done
+    let platform = cycle::nth(platform.clone(), 1_000_000_000, spin, Grid::clone);
+
+    north_load(&platform).into()
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 14;
+
+    type Input = Grid<char>;
+
+    fn parse(input: &mut impl BufRead) -> Result<Grid<char>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Grid<char>) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Grid<char>) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day14>();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day14>();
+    }
+}
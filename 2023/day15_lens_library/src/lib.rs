@@ -0,0 +1,182 @@
+use aoc_common::{
+    answers::Answer,
+    parse::{self, ParseError},
+    Solution,
+};
+use log::debug;
+use nom::{
+    branch::alt,
+    bytes::complete::tag,
+    character::complete::{alpha1, satisfy},
+    error::Error,
+    multi::separated_list1,
+    sequence::preceded,
+    IResult, Parser,
+};
+use std::{fmt::Display, io::BufRead};
+
+#[derive(Debug)]
+pub enum Operation {
+    Dash,
+    Equals(usize),
+}
+
+#[derive(Debug)]
+pub struct Step {
+    label: String,
+    operation: Operation,
+}
+
+#[derive(Clone, Debug)]
+struct Slot {
+    label: String,
+    focal_length: usize,
+}
+
+impl Display for Operation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "{}",
+            match self {
+                Operation::Dash => "-".to_string(),
+                Operation::Equals(focal_length) => format!("={}", focal_length),
+            }
+        )
+    }
+}
+
+impl Display for Step {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}{}", &self.label, &self.operation.to_string())
+    }
+}
+
+fn get_hash(input: &str) -> usize {
+    input
+        .chars()
+        .fold(0, |acc, c| ((c as usize + acc) * 17) % 256)
+}
+
+fn parse_input(input: &mut impl BufRead) -> Result<Vec<Step>, ParseError> {
+    fn step_parser(input: &str) -> IResult<&str, Step, Error<&str>> {
+        let operation = alt((
+            tag("-").map(|_| Operation::Dash),
+            preceded(tag("="), satisfy(|c| c.is_ascii_digit()))
+                .map(|digit| Operation::Equals(digit.to_digit(10).unwrap() as usize)),
+        ));
+
+        (alpha1, operation)
+            .map(|(label, operation): (&str, _)| Step {
+                label: label.to_string(),
+                operation,
+            })
+            .parse(input)
+    }
+
+    let text = aoc_common::read_input(input)?;
+
+    parse::parse(text.trim_end(), separated_list1(tag(","), step_parser))
+}
+
+fn part1(steps: &[Step]) -> Answer {
+    debug!("{:?}", steps);
+
+    steps
+        .iter()
+        .map(|step| get_hash(&step.to_string()))
+        .sum::<usize>()
+        .into()
+}
+
+fn part2(steps: &[Step]) -> Answer {
+    let mut boxes: Vec<Vec<Slot>> = vec![Vec::new(); 256];
+
+    steps.iter().for_each(|step| {
+        let box_number = get_hash(&step.label);
+        if let Some((index, _)) = boxes[box_number]
+            .iter()
+            .enumerate()
+            .find(|(_, slot)| slot.label == step.label)
+        {
+            match step.operation {
+                Operation::Dash => {
+                    boxes[box_number].remove(index);
+                }
+                Operation::Equals(focal_length) => {
+                    boxes[box_number][index].focal_length = focal_length;
+                }
+            }
+        } else {
+            match step.operation {
+                Operation::Dash => {}
+                Operation::Equals(focal_length) => boxes[box_number].push(Slot {
+                    label: step.label.clone(),
+                    focal_length,
+                }),
+            }
+        }
+    });
+
+    boxes
+        .iter()
+        .enumerate()
+        .map(|(box_index, box_contents)| {
+            box_contents
+                .iter()
+                .enumerate()
+                .map(|(slot_index, slot)| (box_index + 1) * (slot_index + 1) * slot.focal_length)
+                .sum::<usize>()
+        })
+        .sum::<usize>()
+        .into()
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 15;
+
+    type Input = Vec<Step>;
+
+    fn parse(input: &mut impl BufRead) -> Result<Vec<Step>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Vec<Step>) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Vec<Step>) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day15>();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day15>();
+    }
+
+    #[test]
+    fn hash_tests() {
+        assert_eq!(get_hash("HASH"), 52);
+    }
+}
@@ -0,0 +1,44 @@
+use rand::{rngs::StdRng, Rng, SeedableRng};
+
+// A `size` by `size` contraption, with about one mirror or splitter in ten
+// tiles like in the real inputs
+pub fn generate(size: usize, seed: u64) -> String {
+    const TILES: [char; 4] = ['/', '\\', '-', '|'];
+
+    let mut rng = StdRng::seed_from_u64(seed);
+
+    (0..size)
+        .map(|_| {
+            let row: String = (0..size)
+                .map(|_| {
+                    if rng.random_bool(0.1) {
+                        TILES[rng.random_range(0..TILES.len())]
+                    } else {
+                        '.'
+                    }
+                })
+                .collect();
+
+            row + "\n"
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{generate::*, Day16};
+    use aoc_common::Solution;
+
+    #[test]
+    fn generate_tests() {
+        let input = generate(30, 7);
+        assert_eq!(input.lines().count(), 30);
+        assert!(input.lines().all(|line| line.len() == 30));
+        assert_eq!(input, generate(30, 7));
+        assert_ne!(input, generate(30, 8));
+
+        let parsed = Day16::parse(&mut input.as_bytes()).unwrap();
+        assert!(Day16::part1(&parsed).is_ok());
+        assert!(Day16::part2(&parsed).unwrap().is_ok());
+    }
+}
@@ -0,0 +1,154 @@
+mod generate;
+
+use aoc_common::{answers::Answer, geometry::Direction, grid::Grid, parse::ParseError, Solution};
+use log::debug;
+use std::{collections::VecDeque, io::BufRead};
+
+// Empty space, mirrors and splitters
+fn parse_input(input: &mut impl BufRead) -> Result<Grid<char>, ParseError> {
+    Grid::parse(input, |c| match c {
+        '.' | '/' | '\\' | '-' | '|' => Some(c),
+        _ => None,
+    })
+}
+
+// The directions the beam goes in after going through a tile
+fn next_directions(tile: char, direction: Direction) -> Vec<Direction> {
+    let vertical = matches!(direction, Direction::North | Direction::South);
+
+    match tile {
+        '.' => vec![direction],
+        '/' if vertical => vec![direction.turn_right()],
+        '/' => vec![direction.turn_left()],
+        '\\' if vertical => vec![direction.turn_left()],
+        '\\' => vec![direction.turn_right()],
+        '-' if vertical => vec![Direction::East, Direction::West],
+        '|' if !vertical => vec![Direction::North, Direction::South],
+        '-' | '|' => vec![direction],
+        _ => unreachable!(),
+    }
+}
+
+fn bounce_beam(
+    grid: &Grid<char>,
+    beam_position: (usize, usize),
+    beam_direction: Direction,
+) -> Grid<u8> {
+    let mut visited_grid = Grid::new(grid.nrows(), grid.ncols(), 0);
+    let mut beam_path: VecDeque<((usize, usize), Direction)> = VecDeque::new();
+
+    beam_path.push_back((beam_position, beam_direction));
+
+    while let Some((current_beam_position, current_beam_direction)) = beam_path.pop_front() {
+        // If we have visited this node from this direction already then we
+        // don't want to do it again
+        if visited_grid[current_beam_position] & (1 << current_beam_direction as u8) != 0 {
+            continue;
+        } else {
+            visited_grid[current_beam_position] |= 1 << current_beam_direction as u8;
+        }
+
+        debug!("{:?} {:?}", current_beam_position, current_beam_direction);
+
+        for next_direction in next_directions(grid[current_beam_position], current_beam_direction) {
+            if let Some(next_position) = grid.step(current_beam_position, next_direction) {
+                beam_path.push_back((next_position, next_direction));
+            }
+        }
+    }
+
+    visited_grid
+}
+
+fn part1(grid: &Grid<char>) -> Answer {
+    debug!("{:?}", grid);
+
+    let energized = bounce_beam(grid, (0, 0), Direction::East)
+        .iter()
+        .filter(|(_, visited)| **visited != 0)
+        .count();
+
+    energized.into()
+}
+
+fn part2(grid: &Grid<char>) -> Answer {
+    debug!("{:?}", grid);
+
+    let energized = (0..grid.ncols())
+        .map(|y| {
+            bounce_beam(grid, (0, y), Direction::South)
+                .iter()
+                .filter(|(_, visited)| **visited != 0)
+                .count()
+        })
+        .chain((0..grid.ncols()).map(|y| {
+            bounce_beam(grid, (grid.nrows() - 1, y), Direction::North)
+                .iter()
+                .filter(|(_, visited)| **visited != 0)
+                .count()
+        }))
+        .chain((0..grid.nrows()).map(|x| {
+            bounce_beam(grid, (x, 0), Direction::East)
+                .iter()
+                .filter(|(_, visited)| **visited != 0)
+                .count()
+        }))
+        .chain((0..grid.nrows()).map(|x| {
+            bounce_beam(grid, (x, grid.ncols() - 1), Direction::West)
+                .iter()
+                .filter(|(_, visited)| **visited != 0)
+                .count()
+        }))
+        .max()
+        .unwrap();
+
+    energized.into()
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 16;
+
+    type Input = Grid<char>;
+
+    fn parse(input: &mut impl BufRead) -> Result<Grid<char>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Grid<char>) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Grid<char>) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+
+    fn generate(size: usize, seed: u64) -> Option<String> {
+        Some(generate::generate(size, seed))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day16>();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day16>();
+    }
+}
@@ -0,0 +1,131 @@
+use aoc_common::{
+    answers::Answer,
+    geometry::{self, Direction, Point2},
+    parse::{self, ParseError},
+    sparse_grid::SparseGrid,
+    Solution,
+};
+use log::debug;
+use nom::character::complete::{self, multispace1};
+use nom::sequence::delimited;
+use nom::{bytes::complete::tag, character::complete::alphanumeric1};
+use std::io::BufRead;
+
+#[derive(Debug)]
+pub struct DigInstruction {
+    direction: Direction,
+    meters: u32,
+}
+
+impl TryFrom<&str> for DigInstruction {
+    type Error = ParseError;
+
+    fn try_from(input: &str) -> Result<Self, Self::Error> {
+        let (direction, _, meters, _, _) = parse::parse(
+            input,
+            (
+                geometry::direction,
+                multispace1,
+                complete::u32,
+                multispace1,
+                delimited(tag("(#"), alphanumeric1, tag(")")),
+            ),
+        )?;
+
+        Ok(DigInstruction { direction, meters })
+    }
+}
+
+fn parse_input(input: &mut impl BufRead) -> Result<Vec<DigInstruction>, ParseError> {
+    parse::parse_lines(input, |line| DigInstruction::try_from(line))
+}
+
+// Everything in the (min, max) box that can be reached from `start` without
+// going through the trench
+fn fill(start: Point2, (min, max): (Point2, Point2), lagoon: &mut SparseGrid<char>) -> usize {
+    let mut to_visit: Vec<Point2> = vec![start];
+    let mut filled = 0;
+
+    while let Some(node) = to_visit.pop() {
+        if lagoon.contains(node) {
+            continue;
+        }
+        lagoon.insert(node, '*');
+        filled += 1;
+
+        to_visit.extend(lagoon.neighbours4(node).filter(|&neighbour| {
+            (min.x..=max.x).contains(&neighbour.x)
+                && (min.y..=max.y).contains(&neighbour.y)
+                && !lagoon.contains(neighbour)
+        }));
+    }
+
+    filled
+}
+
+fn part1(dig_instructions: &[DigInstruction]) -> Answer {
+    let mut lagoon = SparseGrid::new();
+
+    debug!("{:?}", dig_instructions);
+
+    let mut position = Point2::ORIGIN;
+    lagoon.insert(position, '#');
+
+    dig_instructions.iter().for_each(|instruction| {
+        for _ in 0..instruction.meters {
+            position = lagoon.step(position, instruction.direction);
+            lagoon.insert(position, '#');
+        }
+    });
+    debug!("\n{}", lagoon);
+
+    // One more all around the trench so that the outside is all in one piece,
+    // then whatever isn't outside is dug out
+    let (min, max) = lagoon.bounds().unwrap();
+    let area = (min - Point2::new(1, 1), max + Point2::new(1, 1));
+    let outside = fill(area.0, area, &mut lagoon);
+
+    let (width, height) = (area.1.x - area.0.x + 1, area.1.y - area.0.y + 1);
+
+    (width * height - outside as i64).into()
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 18;
+
+    type Input = Vec<DigInstruction>;
+
+    fn parse(input: &mut impl BufRead) -> Result<Vec<DigInstruction>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Vec<DigInstruction>) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day18>();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day18>();
+    }
+}
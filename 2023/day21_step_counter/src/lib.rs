@@ -0,0 +1,91 @@
+use aoc_common::{answers::Answer, examples, grid::Grid, parse::ParseError, Solution};
+use log::debug;
+use std::{collections::VecDeque, io::BufRead};
+
+fn walk_x_steps(garden: &Grid<char>, number_of_steps: usize) -> usize {
+    // parse() made sure that there is one
+    let start_position = garden.find(&'S').unwrap();
+
+    let mut reached_garden_plots = 0;
+
+    let mut plots: VecDeque<((usize, usize), usize)> = VecDeque::new();
+    plots.push_front((start_position, 0));
+
+    while let Some((plot, steps)) = plots.pop_back() {
+        if steps == number_of_steps {
+            reached_garden_plots += 1;
+        } else {
+            for neighbour in garden.neighbours4(plot) {
+                if garden[neighbour] != '#' && !plots.contains(&(neighbour, steps + 1)) {
+                    plots.push_front((neighbour, steps + 1));
+                }
+            }
+        }
+    }
+
+    reached_garden_plots
+}
+
+fn part1(garden: &Grid<char>, number_of_steps: usize) -> Answer {
+    debug!("{:?}", garden);
+
+    walk_x_steps(garden, number_of_steps).into()
+}
+
+pub struct Day21;
+
+impl Solution for Day21 {
+    const YEAR: u16 = 2023;
+    const DAY: u8 = 21;
+
+    // The garden, and how many steps the elf takes
+    type Input = (Grid<char>, usize);
+
+    fn parse(input: &mut impl BufRead) -> Result<(Grid<char>, usize), ParseError> {
+        let garden = Grid::parse(input, |c| ".#S".contains(c).then_some(c))?;
+        if garden.find(&'S').is_none() {
+            return Err(ParseError::missing(garden.nrows() + 1, "a start tile S"));
+        }
+
+        Ok((garden, 64))
+    }
+
+    fn set_parameter(
+        (_, number_of_steps): &mut (Grid<char>, usize),
+        name: &str,
+        value: &str,
+    ) -> Result<(), String> {
+        match name {
+            "steps" => *number_of_steps = examples::parameter(name, value)?,
+            _ => return Err(format!("unknown parameter {}", name)),
+        }
+        Ok(())
+    }
+
+    fn part1((garden, number_of_steps): &(Grid<char>, usize)) -> Result<Answer, ParseError> {
+        Ok(part1(garden, *number_of_steps))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day21>();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day21>();
+    }
+}
@@ -0,0 +1,49 @@
+use std::{env, fs, path::Path};
+
+// The list of puzzles is generated from the days that the runner depends on,
+// so that registering a day only takes the one line in Cargo.toml (which is
+// what `aoc register` adds).
+fn main() {
+    println!("cargo::rerun-if-changed=Cargo.toml");
+
+    let manifest: toml::Table = fs::read_to_string("Cargo.toml").unwrap().parse().unwrap();
+
+    // The days are the dependencies in a year's folder, however they're
+    // written down. They're sorted by their folder so that the runner goes
+    // through them year by year.
+    let mut days: Vec<(&str, &str)> = manifest["dependencies"]
+        .as_table()
+        .unwrap()
+        .iter()
+        .filter_map(|(name, dependency)| {
+            let path = dependency.get("path")?.as_str()?;
+            path.strip_prefix("../20")?;
+
+            Some((path, name.as_str()))
+        })
+        .collect();
+    days.sort_unstable();
+
+    let puzzles: String = days
+        .iter()
+        .map(|(path, name)| {
+            // dayXX_puzzle_name
+            let day = name
+                .strip_prefix("day")
+                .and_then(|name| name.get(..2))
+                .unwrap_or_else(|| panic!("{} isn't named after its day", path));
+
+            format!(
+                "        Puzzle::new::<{}::Day{}>(),\n",
+                name.replace('-', "_"),
+                day
+            )
+        })
+        .collect();
+
+    fs::write(
+        Path::new(&env::var("OUT_DIR").unwrap()).join("registry.rs"),
+        format!("vec![\n{}    ]\n", puzzles),
+    )
+    .unwrap();
+}
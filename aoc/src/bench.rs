@@ -0,0 +1,292 @@
+use std::{collections::BTreeMap, fmt, fs, io, path::Path, time::Duration};
+
+// Anything that takes less than this longer than it used to is put down to
+// noise, however many times longer it is. Microseconds come and go with what
+// else the machine is doing.
+pub const NOISE_FLOOR: Duration = Duration::from_micros(100);
+
+// The parts of a day that are timed separately
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Phase {
+    Parse,
+    Part(u8),
+}
+
+impl fmt::Display for Phase {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "parse"),
+            Phase::Part(part) => write!(f, "part{}", part),
+        }
+    }
+}
+
+impl TryFrom<&str> for Phase {
+    type Error = ();
+
+    fn try_from(text: &str) -> Result<Self, Self::Error> {
+        match text {
+            "parse" => Ok(Phase::Parse),
+            _ => text
+                .strip_prefix("part")
+                .and_then(|part| part.parse().ok())
+                .map(Phase::Part)
+                .ok_or(()),
+        }
+    }
+}
+
+// What a number of runs of the same thing came to
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Stats {
+    pub median: Duration,
+    pub min: Duration,
+    pub stddev: Duration,
+}
+
+impl Stats {
+    // There has to be at least one sample
+    pub fn new(samples: &[Duration]) -> Self {
+        let mut sorted = samples.to_vec();
+        sorted.sort_unstable();
+
+        let middle = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[middle - 1] + sorted[middle]) / 2
+        } else {
+            sorted[middle]
+        };
+
+        let seconds: Vec<f64> = sorted.iter().map(Duration::as_secs_f64).collect();
+        let mean = seconds.iter().sum::<f64>() / seconds.len() as f64;
+        let variance =
+            seconds.iter().map(|s| (s - mean).powi(2)).sum::<f64>() / seconds.len() as f64;
+
+        Stats {
+            median,
+            min: sorted[0],
+            stddev: Duration::from_secs_f64(variance.sqrt()),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Change {
+    // Not in the baseline yet
+    New,
+    Same,
+    // By how much, 0.5 is 50% slower or faster
+    Slower(f64),
+    Faster(f64),
+}
+
+impl Change {
+    // A change only counts when it's more than `threshold` of the baseline
+    // and more than the noise floor. Runs that were slowed down by something
+    // else going on make the median jump around, so a day is only slower if
+    // even its fastest run is.
+    pub fn new(baseline: Option<Duration>, stats: &Stats, threshold: f64) -> Self {
+        let Some(baseline) = baseline else {
+            return Change::New;
+        };
+
+        let ratio =
+            |time: Duration| time.as_secs_f64() / baseline.as_secs_f64().max(f64::MIN_POSITIVE);
+        let (median, min) = (ratio(stats.median), ratio(stats.min));
+
+        if stats.median.abs_diff(baseline) < NOISE_FLOOR {
+            Change::Same
+        } else if median > 1.0 + threshold && min > 1.0 + threshold {
+            Change::Slower(median - 1.0)
+        } else if median < 1.0 / (1.0 + threshold) {
+            Change::Faster(1.0 - median)
+        } else {
+            Change::Same
+        }
+    }
+}
+
+impl fmt::Display for Change {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Change::New => write!(f, "new"),
+            Change::Same => write!(f, "-"),
+            Change::Slower(by) => write!(f, "{:.0}% slower", by * 100.0),
+            Change::Faster(by) => write!(f, "{:.0}% faster", by * 100.0),
+        }
+    }
+}
+
+// The medians of the last benchmark that was saved, one line per phase of
+// each day:
+//
+//   2015 04 part1 186512432
+//
+// with the median in nanoseconds. Like answers.txt, empty lines and lines
+// that start with # are skipped.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Baseline {
+    medians: BTreeMap<(u16, u8, Phase), Duration>,
+}
+
+impl Baseline {
+    // No file yet is the same as an empty baseline
+    pub fn load(path: &Path) -> Result<Self, String> {
+        match fs::read_to_string(path) {
+            Ok(text) => Baseline::try_from(text.as_str())
+                .map_err(|line| format!("{}: line {} is malformed", path.display(), line)),
+            Err(e) if e.kind() == io::ErrorKind::NotFound => Ok(Baseline::default()),
+            Err(e) => Err(format!("{}: {}", path.display(), e)),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        fs::write(path, self.to_string()).map_err(|e| format!("{}: {}", path.display(), e))
+    }
+
+    pub fn get(&self, year: u16, day: u8, phase: Phase) -> Option<Duration> {
+        self.medians.get(&(year, day, phase)).copied()
+    }
+
+    pub fn insert(&mut self, year: u16, day: u8, phase: Phase, median: Duration) {
+        self.medians.insert((year, day, phase), median);
+    }
+}
+
+// The error is the number of the line that couldn't be read
+impl TryFrom<&str> for Baseline {
+    type Error = usize;
+
+    fn try_from(text: &str) -> Result<Self, Self::Error> {
+        let mut baseline = Baseline::default();
+
+        for (i, line) in text.lines().enumerate() {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let [year, day, phase, nanos] = fields[..] else {
+                return Err(i + 1);
+            };
+
+            match (
+                year.parse(),
+                day.parse(),
+                Phase::try_from(phase),
+                nanos.parse(),
+            ) {
+                (Ok(year), Ok(day), Ok(phase), Ok(nanos)) => {
+                    baseline.insert(year, day, phase, Duration::from_nanos(nanos))
+                }
+                _ => return Err(i + 1),
+            }
+        }
+
+        Ok(baseline)
+    }
+}
+
+impl fmt::Display for Baseline {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(
+            f,
+            "# Written by `aoc bench --save`: year, day, phase, median in nanoseconds"
+        )?;
+        self.medians
+            .iter()
+            .try_for_each(|((year, day, phase), median)| {
+                writeln!(f, "{} {:02} {} {}", year, day, phase, median.as_nanos())
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::bench::*;
+
+    fn ms(millis: u64) -> Duration {
+        Duration::from_millis(millis)
+    }
+
+    #[test]
+    fn stats_tests() {
+        let stats = Stats::new(&[ms(5), ms(1), ms(3)]);
+        assert_eq!(stats.median, ms(3));
+        assert_eq!(stats.min, ms(1));
+        // The mean is 3, so the deviations are 2, 2 and 0 and the stddev is
+        // sqrt(8 / 3) = 1.63299ms
+        assert_eq!(stats.stddev.as_micros(), 1632);
+
+        let stats = Stats::new(&[ms(4), ms(2), ms(8), ms(6)]);
+        assert_eq!(stats.median, ms(5));
+        assert_eq!(Stats::new(&[ms(7)]).stddev, Duration::ZERO);
+    }
+
+    #[test]
+    fn change_tests() {
+        let stats = |median, min| Stats {
+            median,
+            min,
+            stddev: Duration::ZERO,
+        };
+
+        assert_eq!(Change::new(None, &stats(ms(10), ms(10)), 0.1), Change::New);
+        assert_eq!(
+            Change::new(Some(ms(10)), &stats(ms(10), ms(9)), 0.1),
+            Change::Same
+        );
+        assert_eq!(
+            Change::new(Some(ms(10)), &stats(ms(11), ms(11)), 0.2),
+            Change::Same
+        );
+        assert_eq!(
+            Change::new(Some(ms(8)), &stats(ms(12), ms(10)), 0.2),
+            Change::Slower(0.5)
+        );
+        assert_eq!(
+            Change::new(Some(ms(8)), &stats(ms(4), ms(4)), 0.2),
+            Change::Faster(0.5)
+        );
+
+        // Most runs were slow, but the fastest one wasn't
+        assert_eq!(
+            Change::new(Some(ms(8)), &stats(ms(12), ms(8)), 0.2),
+            Change::Same
+        );
+
+        // Three times as slow, but only by 20µs
+        let micros = Duration::from_micros;
+        assert_eq!(
+            Change::new(Some(micros(10)), &stats(micros(30), micros(30)), 0.1),
+            Change::Same
+        );
+
+        assert_eq!(Change::Slower(0.456).to_string(), "46% slower");
+    }
+
+    #[test]
+    fn baseline_tests() {
+        let text = "# comment\n2015 04 part1 1500\n\n2015 04 parse 20\n2022 17 part2 7\n";
+        let baseline = Baseline::try_from(text).unwrap();
+
+        assert_eq!(
+            baseline.get(2015, 4, Phase::Part(1)),
+            Some(Duration::from_nanos(1500))
+        );
+        assert_eq!(
+            baseline.get(2022, 17, Phase::Part(2)),
+            Some(Duration::from_nanos(7))
+        );
+        assert_eq!(baseline.get(2015, 4, Phase::Part(2)), None);
+
+        // Sorted by day and phase when written back
+        let written = baseline.to_string();
+        assert!(written.ends_with("2015 04 parse 20\n2015 04 part1 1500\n2022 17 part2 7\n"));
+        assert_eq!(Baseline::try_from(written.as_str()).unwrap(), baseline);
+
+        assert_eq!(Baseline::try_from("2015 04 part1\n").unwrap_err(), 1);
+        assert_eq!(Baseline::try_from("\n2015 04 part 3\n").unwrap_err(), 2);
+    }
+}
@@ -0,0 +1,171 @@
+use std::{
+    env, fs,
+    path::{Path, PathBuf},
+};
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+// Advent of Code asks for automated requests to say where they come from
+const USER_AGENT: &str = "github.com/Daedrus/aoc";
+
+// Downloads puzzle inputs, keeping a copy of everything it downloads in the
+// cache folder so that the same input is never requested twice.
+pub struct Fetcher {
+    base_url: String,
+    session: Option<String>,
+    cache_dir: PathBuf,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Fetched {
+    Downloaded(PathBuf),
+    Cached(PathBuf),
+}
+
+impl Fetcher {
+    pub fn new(base_url: &str, session: Option<String>, cache_dir: impl Into<PathBuf>) -> Self {
+        Fetcher {
+            base_url: base_url.trim_end_matches('/').to_string(),
+            session,
+            cache_dir: cache_dir.into(),
+        }
+    }
+
+    pub fn cache_path(&self, year: u16, day: u8) -> PathBuf {
+        self.cache_dir
+            .join(year.to_string())
+            .join(format!("day{:02}", day))
+    }
+
+    pub fn fetch(&self, year: u16, day: u8) -> Result<Fetched, String> {
+        let path = self.cache_path(year, day);
+        if path.exists() {
+            return Ok(Fetched::Cached(path));
+        }
+
+        // Only needed when something actually has to be downloaded
+        let session = self.session.as_ref().ok_or(
+            "no session token, set AOC_SESSION or put the token in .session in the root folder",
+        )?;
+
+        let url = format!("{}/{}/day/{}/input", self.base_url, year, day);
+        let input = ureq::get(&url)
+            .header("Cookie", &format!("session={}", session))
+            .header("User-Agent", USER_AGENT)
+            .call()
+            .and_then(|mut response| response.body_mut().read_to_string())
+            .map_err(|e| format!("{}: {}", url, e))?;
+
+        // Write to a temporary file first so that an interrupted download
+        // doesn't leave a truncated input in the cache
+        let tmp = path.with_extension("part");
+        fs::create_dir_all(path.parent().unwrap())
+            .and_then(|_| fs::write(&tmp, input))
+            .and_then(|_| fs::rename(&tmp, &path))
+            .map_err(|e| format!("{}: {}", path.display(), e))?;
+
+        Ok(Fetched::Downloaded(path))
+    }
+}
+
+pub fn session_token(root: &Path) -> Option<String> {
+    env::var("AOC_SESSION")
+        .ok()
+        .or_else(|| fs::read_to_string(root.join(".session")).ok())
+        .map(|token| token.trim().to_string())
+        .filter(|token| !token.is_empty())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::fetch::*;
+    use std::{
+        io::{BufRead, BufReader, Write},
+        net::TcpListener,
+        sync::{
+            atomic::{AtomicUsize, Ordering},
+            Arc,
+        },
+        thread,
+    };
+
+    const FIXTURE: &str = "()())\n";
+
+    // Stand-in for adventofcode.com that only knows about 2015 day 1 and
+    // counts how many requests it got
+    fn serve() -> (String, Arc<AtomicUsize>) {
+        let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+        let base_url = format!("http://{}", listener.local_addr().unwrap());
+        let requests = Arc::new(AtomicUsize::new(0));
+        let counter = requests.clone();
+
+        thread::spawn(move || {
+            listener.incoming().for_each(|stream| {
+                let mut stream = stream.unwrap();
+                counter.fetch_add(1, Ordering::SeqCst);
+
+                let request: Vec<String> = BufReader::new(&stream)
+                    .lines()
+                    .map(|line| line.unwrap())
+                    .take_while(|line| !line.is_empty())
+                    .collect();
+                let authorized = request
+                    .iter()
+                    .any(|line| line.eq_ignore_ascii_case("cookie: session=test-token"));
+
+                let (status, body) = match (request[0].as_str(), authorized) {
+                    (_, false) => ("400 Bad Request", ""),
+                    ("GET /2015/day/1/input HTTP/1.1", true) => ("200 OK", FIXTURE),
+                    _ => ("404 Not Found", ""),
+                };
+
+                write!(
+                    stream,
+                    "HTTP/1.1 {}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n{}",
+                    status,
+                    body.len(),
+                    body
+                )
+                .unwrap();
+            });
+        });
+
+        (base_url, requests)
+    }
+
+    #[test]
+    fn fetch_tests() {
+        let (base_url, requests) = serve();
+        let cache = tempfile::tempdir().unwrap();
+        let fetcher = Fetcher::new(&base_url, Some("test-token".to_string()), cache.path());
+        let path = fetcher.cache_path(2015, 1);
+
+        assert_eq!(
+            fetcher.fetch(2015, 1),
+            Ok(Fetched::Downloaded(path.clone()))
+        );
+        assert_eq!(fs::read_to_string(&path).unwrap(), FIXTURE);
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        // Already in the cache so the server doesn't get asked again
+        assert_eq!(fetcher.fetch(2015, 1), Ok(Fetched::Cached(path)));
+        assert_eq!(requests.load(Ordering::SeqCst), 1);
+
+        assert!(fetcher.fetch(2015, 2).is_err());
+        assert!(!fetcher.cache_path(2015, 2).exists());
+    }
+
+    #[test]
+    fn fetch_without_session_tests() {
+        let (base_url, requests) = serve();
+        let cache = tempfile::tempdir().unwrap();
+
+        let fetcher = Fetcher::new(&base_url, None, cache.path());
+        assert!(fetcher.fetch(2015, 1).is_err());
+        assert_eq!(requests.load(Ordering::SeqCst), 0);
+
+        let fetcher = Fetcher::new(&base_url, Some("wrong".to_string()), cache.path());
+        assert!(fetcher.fetch(2015, 1).is_err());
+        assert!(!fetcher.cache_path(2015, 1).exists());
+    }
+}
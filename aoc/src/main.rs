@@ -0,0 +1,829 @@
+mod bench;
+mod fetch;
+mod parallel;
+mod register;
+mod registry;
+
+use aoc_common::{
+    answers::{Answer, Answers, Verdict},
+    memory::CountingAllocator,
+    panic_message,
+    profiles::{self, Profile},
+    Puzzle, Report,
+};
+use bench::{Baseline, Change, Phase, Stats};
+use clap::{Args, Parser, Subcommand};
+use fetch::{Fetched, Fetcher};
+use parallel::Outcome;
+use register::Registered;
+use std::{
+    collections::HashMap,
+    env,
+    fs::{self, File},
+    io::BufReader,
+    panic::{self, AssertUnwindSafe},
+    path::{Path, PathBuf},
+    process::{self, ExitCode},
+    thread,
+    time::Duration,
+};
+
+// So that the reports can tell how much memory each part needed
+#[global_allocator]
+static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code solutions in this repository")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+    /// The repository's folder, by default the one that the command is run
+    /// from (or the closest parent folder that is part of the repository)
+    #[arg(long, global = true)]
+    root: Option<PathBuf>,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run every puzzle, every puzzle of a year or a single day
+    Run {
+        /// `all` or a year
+        target: String,
+        /// Day of the year, leave out to run the whole year
+        day: Option<u8>,
+        /// Only run this part of the puzzle
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+        part: Option<u8>,
+        /// Print one JSON object per day instead of the answers
+        #[arg(long)]
+        json: bool,
+        /// Whose inputs to solve, the name of a folder in inputs/ or `all`
+        #[arg(long, default_value = profiles::DEFAULT)]
+        profile: String,
+        #[command(flatten)]
+        parallel: Parallel,
+    },
+    /// Check the answers against the expected ones in <year>/answers.txt, or
+    /// in inputs/<profile>/<year>/answers.txt for the other profiles
+    Verify {
+        /// `all` or a year
+        #[arg(default_value = "all")]
+        target: String,
+        /// Day of the year, leave out to verify the whole year
+        day: Option<u8>,
+        /// Whose inputs to solve, the name of a folder in inputs/ or `all`
+        #[arg(long, default_value = profiles::DEFAULT)]
+        profile: String,
+        #[command(flatten)]
+        parallel: Parallel,
+    },
+    /// Time every puzzle, every puzzle of a year or a single day over a
+    /// number of runs and compare with the last saved times in bench.txt
+    Bench {
+        /// `all` or a year
+        #[arg(default_value = "all")]
+        target: String,
+        /// Day of the year, leave out to benchmark the whole year
+        day: Option<u8>,
+        /// How many times to run each day
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u32).range(1..))]
+        runs: u32,
+        /// How much slower than the baseline, in percent, counts as slower
+        #[arg(long, default_value_t = 20.0)]
+        threshold: f64,
+        /// Save the times as the new baseline instead of comparing with it
+        #[arg(long)]
+        save: bool,
+    },
+    /// Print a random input for one of the days that have a generator, to
+    /// put a solution through a bigger input than the real one
+    Generate {
+        year: u16,
+        day: u8,
+        /// How big an input, in whatever the day counts (lines, wires, the
+        /// side of a grid...)
+        #[arg(long)]
+        size: usize,
+        /// The same seed always gives the same input
+        #[arg(long, default_value_t = 0)]
+        seed: u64,
+    },
+    /// Download a puzzle's input into the day's folder
+    Fetch {
+        year: u16,
+        day: u8,
+        /// Where to download the inputs from
+        #[arg(long, env = "AOC_BASE_URL", default_value = fetch::DEFAULT_BASE_URL)]
+        base_url: String,
+    },
+    /// Generate a new day from the template and add it to the runner
+    New {
+        year: u16,
+        day: u8,
+        /// The puzzle's title in snake_case, like not_quite_lisp
+        name: String,
+    },
+    /// Add a day generated from the template to the runner
+    Register { year: u16, day: u8 },
+}
+
+// How run and verify spread the days out. bench doesn't, the days would
+// only slow each other down.
+#[derive(Args)]
+struct Parallel {
+    /// How many days to solve at the same time, all the CPUs by default
+    #[arg(long, short, value_parser = clap::value_parser!(u32).range(1..))]
+    jobs: Option<u32>,
+    /// Give up on a day that takes longer than this many seconds
+    #[arg(long, default_value_t = 60, value_parser = clap::value_parser!(u64).range(1..))]
+    timeout: u64,
+}
+
+impl Parallel {
+    fn jobs(&self) -> usize {
+        self.jobs.map_or_else(
+            || thread::available_parallelism().map_or(1, usize::from),
+            |jobs| jobs as usize,
+        )
+    }
+
+    fn timeout(&self) -> Duration {
+        Duration::from_secs(self.timeout)
+    }
+}
+
+fn select(puzzles: Vec<Puzzle>, target: &str, day: Option<u8>) -> Result<Vec<Puzzle>, String> {
+    let selected: Vec<Puzzle> = match (target, day) {
+        ("all", None) => puzzles,
+        ("all", Some(_)) => return Err("a day can only be given together with a year".to_string()),
+        (year, day) => {
+            let year: u16 = year
+                .parse()
+                .map_err(|_| format!("expected `all` or a year, got `{}`", year))?;
+
+            puzzles
+                .into_iter()
+                .filter(|puzzle| puzzle.year == year && day.is_none_or(|day| puzzle.day == day))
+                .collect()
+        }
+    };
+
+    if selected.is_empty() {
+        Err(match day {
+            Some(day) => format!("no solution registered for {} day {}", target, day),
+            None => format!("no solutions registered for {}", target),
+        })
+    } else {
+        Ok(selected)
+    }
+}
+
+// Outside of the repository, and without --root, it's the one that the
+// runner was built from
+fn root_dir(root: Option<PathBuf>) -> PathBuf {
+    root.or_else(|| {
+        env::current_dir()
+            .ok()?
+            .ancestors()
+            .find(|dir| dir.join("aoc").join("Cargo.toml").is_file())
+            .map(Path::to_path_buf)
+    })
+    .unwrap_or_else(|| {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .parent()
+            .unwrap()
+            .to_path_buf()
+    })
+}
+
+fn day_dir(root: &Path, year: u16, day: u8) -> Option<PathBuf> {
+    profiles::day_dir(root, year, day)
+}
+
+fn select_profiles(root: &Path, name: &str) -> Result<Vec<Profile>, String> {
+    match name {
+        "all" => Ok(Profile::all(root)),
+        profiles::DEFAULT => Ok(vec![Profile::new(root, name)]),
+        _ if root.join("inputs").join(name).is_dir() => Ok(vec![Profile::new(root, name)]),
+        _ => Err(format!("no profile named {} in inputs/", name)),
+    }
+}
+
+// The days that a command is about, and whose inputs to solve them with
+fn select_inputs(
+    root: &Path,
+    target: &str,
+    day: Option<u8>,
+    profile: &str,
+) -> Result<(Vec<Puzzle>, Vec<Profile>), String> {
+    Ok((
+        select(registry::puzzles(), target, day)?,
+        select_profiles(root, profile)?,
+    ))
+}
+
+// The puzzles that the profile has inputs for, none of them if it has none
+fn with_inputs(puzzles: &[Puzzle], profile: &Profile) -> Vec<Puzzle> {
+    puzzles
+        .iter()
+        .filter(|puzzle| profile.has_input(puzzle.year, puzzle.day))
+        .copied()
+        .collect()
+}
+
+fn input_path(puzzle: &Puzzle, profile: &Profile) -> Result<PathBuf, String> {
+    profile
+        .input(puzzle.year, puzzle.day)
+        .ok_or("could not find the puzzle's directory".to_string())
+}
+
+fn solve(puzzle: &Puzzle, profile: &Profile, parts: &[u8]) -> Result<Report, String> {
+    let path = input_path(puzzle, profile)?;
+    let f = File::open(&path).map_err(|e| format!("{}: {}", path.display(), e))?;
+
+    Ok(puzzle.solve(&mut BufReader::new(f), parts))
+}
+
+// With the profile that the answers are for, as "profile" next to the rest
+fn to_json(report: &Report, profile: &Profile) -> String {
+    let mut json = serde_json::to_value(report).unwrap();
+    json["profile"] = profile.name().into();
+
+    json.to_string()
+}
+
+// For the multi-line diagnostics, so that they stand out in the output
+fn print_indented(text: impl ToString) {
+    text.to_string()
+        .lines()
+        .for_each(|line| println!("    {}", line));
+}
+
+// Each phase with how long it took and the most memory it had allocated
+fn print_report(report: &Report) {
+    println!("  parse:  ({})", report.parse);
+    report.parts.iter().for_each(|result| match &result.answer {
+        Some(Ok(answer @ Answer::Grid(_))) => {
+            println!("  part {}:  ({})", result.part, result.measurement);
+            print_indented(answer);
+        }
+        Some(Ok(answer)) => {
+            println!(
+                "  part {}: {}  ({})",
+                result.part, answer, result.measurement
+            )
+        }
+        Some(Err(e)) => {
+            println!("  part {}: malformed input", result.part);
+            print_indented(e);
+        }
+        None => println!("  part {}: -", result.part),
+    });
+}
+
+fn run(
+    puzzles: &[Puzzle],
+    profiles: &[Profile],
+    parts: &[u8],
+    json: bool,
+    parallel: &Parallel,
+) -> ExitCode {
+    let mut failed = false;
+    let headings = !json && profiles.iter().any(|profile| !profile.is_default());
+
+    profiles.iter().for_each(|profile| {
+        let puzzles = with_inputs(puzzles, profile);
+        if puzzles.is_empty() {
+            return;
+        }
+        if headings {
+            println!("profile {}", profile.name());
+        }
+
+        let parts = parts.to_vec();
+        let solving = profile.clone();
+
+        parallel::solve_all(
+            &puzzles,
+            parallel.jobs(),
+            parallel.timeout(),
+            move |puzzle| solve(puzzle, &solving, &parts),
+            |puzzle, outcome| {
+                if !json {
+                    println!("{} day {:02}", puzzle.year, puzzle.day);
+                }
+
+                let e = match outcome {
+                    Outcome::Finished(Ok(report)) => {
+                        failed |= report
+                            .parts
+                            .iter()
+                            .any(|result| matches!(result.answer, Some(Err(_))));
+
+                        if json {
+                            println!("{}", to_json(&report, profile));
+                        } else {
+                            print_report(&report);
+                        }
+                        return;
+                    }
+                    Outcome::Finished(Err(e)) => format!("error: {}", e),
+                    Outcome::Panicked(message) => format!("panicked: {}", message),
+                    Outcome::TimedOut(after) => format!("timed out after {:?}", after),
+                };
+                failed = true;
+
+                // The JSON lines are only for the days that could be solved
+                if json {
+                    eprintln!("{} day {:02}: {}", puzzle.year, puzzle.day, e);
+                } else {
+                    println!("  {}", e);
+                }
+            },
+        );
+    });
+
+    if failed {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn verify(puzzles: &[Puzzle], profiles: &[Profile], parallel: &Parallel) -> ExitCode {
+    let (mut passed, mut failed, mut missing) = (0, 0, 0);
+    let (mut timed_out, mut panicked) = (0, 0);
+
+    let headings = profiles.iter().any(|profile| !profile.is_default());
+
+    println!("year  day  part  result   answer");
+
+    profiles.iter().for_each(|profile| {
+        let puzzles = with_inputs(puzzles, profile);
+        if puzzles.is_empty() {
+            return;
+        }
+        if headings {
+            println!("profile {}", profile.name());
+        }
+
+        // Each profile has its own answers
+        let mut answers: HashMap<u16, Answers> = HashMap::new();
+        let solving = profile.clone();
+        parallel::solve_all(
+            &puzzles,
+            parallel.jobs(),
+            parallel.timeout(),
+            move |puzzle| solve(puzzle, &solving, &[1, 2]),
+            |puzzle, outcome| {
+                let answers = answers.entry(puzzle.year).or_insert_with(|| {
+                    Answers::load(profile.answers(puzzle.year)).unwrap_or_else(|e| {
+                        eprintln!("{}", e);
+                        Answers::default()
+                    })
+                });
+
+                let report = match outcome {
+                    Outcome::Finished(Ok(report)) => report,
+                    Outcome::Finished(Err(e)) => {
+                        println!("{}   {:02}     -  error    {}", puzzle.year, puzzle.day, e);
+                        failed += 1;
+                        return;
+                    }
+                    Outcome::Panicked(message) => {
+                        println!(
+                            "{}   {:02}     -  panic    {}",
+                            puzzle.year, puzzle.day, message
+                        );
+                        panicked += 1;
+                        return;
+                    }
+                    Outcome::TimedOut(after) => {
+                        println!(
+                            "{}   {:02}     -  timeout  gave up after {:?}",
+                            puzzle.year, puzzle.day, after
+                        );
+                        timed_out += 1;
+                        return;
+                    }
+                };
+
+                report.parts.iter().for_each(|result| {
+                    let (verdict, answer) =
+                        match (&result.answer, answers.get(puzzle.day, result.part)) {
+                            // Days without a second part
+                            (None, None) => return,
+                            (None, Some(expected)) => {
+                                (Verdict::Fail(expected.clone()), "-".to_string())
+                            }
+                            (Some(Ok(answer)), _) => (
+                                answers.check(puzzle.day, result.part, answer),
+                                answer.to_string(),
+                            ),
+                            (Some(Err(e)), _) => {
+                                println!(
+                                    "{}   {:02}     {}  error    malformed input",
+                                    puzzle.year, puzzle.day, result.part
+                                );
+                                print_indented(e);
+                                failed += 1;
+                                return;
+                            }
+                        };
+
+                    match &verdict {
+                        Verdict::Pass => passed += 1,
+                        Verdict::Fail(_) => failed += 1,
+                        Verdict::Missing => missing += 1,
+                    }
+
+                    match &verdict {
+                        Verdict::Fail(expected) => println!(
+                            "{}   {:02}     {}  {:<7}  {} (expected {})",
+                            puzzle.year, puzzle.day, result.part, verdict, answer, expected
+                        ),
+                        _ => println!(
+                            "{}   {:02}     {}  {:<7}  {}",
+                            puzzle.year, puzzle.day, result.part, verdict, answer
+                        ),
+                    }
+                });
+            },
+        );
+    });
+
+    println!();
+    println!(
+        "{} passed, {} failed, {} missing, {} timed out, {} panicked",
+        passed, failed, missing, timed_out, panicked
+    );
+
+    if failed > 0 || timed_out > 0 || panicked > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+// Every phase of the day's report, paired with how long it took
+fn phases(report: &Report) -> Vec<(Phase, Duration)> {
+    let parts = report
+        .parts
+        .iter()
+        .filter(|result| result.answer.is_some())
+        .map(|result| (Phase::Part(result.part), result.measurement.elapsed));
+
+    [(Phase::Parse, report.parse.elapsed)]
+        .into_iter()
+        .chain(parts)
+        .collect()
+}
+
+// The input is read into memory first so that the disk isn't timed, and each
+// day is run once before the timed runs so that the first of them doesn't
+// pay for a cold cache. A day that panics fails like in `run` and `verify`,
+// and the others still get benchmarked.
+fn bench(root: &Path, puzzles: &[Puzzle], runs: u32, threshold: f64, save: bool) -> ExitCode {
+    let path = root.join("bench.txt");
+    // The baseline is only for the inputs in the days' folders
+    let default = Profile::new(root, profiles::DEFAULT);
+    let mut baseline = match Baseline::load(&path) {
+        Ok(baseline) => baseline,
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+    let (mut slower, mut faster, mut failed) = (0, 0, 0);
+
+    println!("year  day  phase        median           min        stddev  change");
+
+    puzzles.iter().for_each(|puzzle| {
+        let input = match input_path(puzzle, &default)
+            .and_then(|path| fs::read(&path).map_err(|e| format!("{}: {}", path.display(), e)))
+        {
+            Ok(input) => input,
+            Err(e) => {
+                println!("{}   {:02}  error  {}", puzzle.year, puzzle.day, e);
+                failed += 1;
+                return;
+            }
+        };
+
+        let reports = panic::catch_unwind(AssertUnwindSafe(|| {
+            (0..=runs)
+                .map(|_| puzzle.solve(&mut input.as_slice(), &[1, 2]))
+                .skip(1)
+                .collect::<Vec<Report>>()
+        }));
+        let reports = match reports {
+            Ok(reports) => reports,
+            Err(payload) => {
+                println!(
+                    "{}   {:02}  panic  {}",
+                    puzzle.year,
+                    puzzle.day,
+                    panic_message(payload)
+                );
+                failed += 1;
+                return;
+            }
+        };
+
+        if reports[0]
+            .parts
+            .iter()
+            .any(|result| matches!(result.answer, Some(Err(_))))
+        {
+            println!(
+                "{}   {:02}  error  malformed input",
+                puzzle.year, puzzle.day
+            );
+            failed += 1;
+            return;
+        }
+
+        // Every run goes through the same phases
+        let samples: Vec<Vec<(Phase, Duration)>> = reports.iter().map(phases).collect();
+        (0..samples[0].len()).for_each(|i| {
+            let phase = samples[0][i].0;
+            let stats = Stats::new(&samples.iter().map(|run| run[i].1).collect::<Vec<_>>());
+
+            let change = if save {
+                baseline.insert(puzzle.year, puzzle.day, phase, stats.median);
+                Change::Same
+            } else {
+                Change::new(
+                    baseline.get(puzzle.year, puzzle.day, phase),
+                    &stats,
+                    threshold / 100.0,
+                )
+            };
+
+            match change {
+                Change::Slower(_) => slower += 1,
+                Change::Faster(_) => faster += 1,
+                Change::New | Change::Same => {}
+            }
+
+            println!(
+                "{}   {:02}  {:<5}  {:>12?}  {:>12?}  {:>12?}  {}",
+                puzzle.year, puzzle.day, phase, stats.median, stats.min, stats.stddev, change
+            );
+        });
+    });
+
+    println!();
+    if save {
+        if let Err(e) = baseline.save(&path) {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+        println!("saved the medians to {}", path.display());
+    } else {
+        println!(
+            "{} slower, {} faster, {} failed (threshold {}%)",
+            slower, faster, failed, threshold
+        );
+    }
+
+    if slower > 0 || failed > 0 {
+        ExitCode::FAILURE
+    } else {
+        ExitCode::SUCCESS
+    }
+}
+
+fn generate(year: u16, day: u8, size: usize, seed: u64) -> Result<String, String> {
+    let puzzle = select(registry::puzzles(), &year.to_string(), Some(day))?[0];
+
+    puzzle
+        .generate(size, seed)
+        .ok_or(format!("{} day {:02} has no input generator", year, day))
+}
+
+fn fetch(root: &Path, year: u16, day: u8, base_url: &str) -> ExitCode {
+    let fetcher = Fetcher::new(base_url, fetch::session_token(root), root.join(".cache"));
+
+    let cached = match fetcher.fetch(year, day) {
+        Ok(Fetched::Downloaded(path)) => {
+            println!("downloaded {} day {:02} to {}", year, day, path.display());
+            path
+        }
+        Ok(Fetched::Cached(path)) => {
+            println!("{} day {:02} is already in {}", year, day, path.display());
+            path
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let Some(dir) = day_dir(root, year, day) else {
+        println!(
+            "no folder for {} day {:02} yet, generate it from the template",
+            year, day
+        );
+        return ExitCode::SUCCESS;
+    };
+
+    let input = dir.join("input");
+    let existing = fs::read_to_string(&input).unwrap_or_default();
+    if !existing.is_empty() {
+        println!("{} already exists, leaving it alone", input.display());
+    } else if let Err(e) = fs::copy(&cached, &input) {
+        eprintln!("{}: {}", input.display(), e);
+        return ExitCode::FAILURE;
+    } else {
+        println!("copied to {}", input.display());
+    }
+
+    ExitCode::SUCCESS
+}
+
+fn register(root: &Path, year: u16, day: u8) -> ExitCode {
+    let Some(dir) = day_dir(root, year, day) else {
+        eprintln!(
+            "no folder for {} day {:02}, generate it from the template first",
+            year, day
+        );
+        return ExitCode::FAILURE;
+    };
+
+    match register::register(&root.join("aoc").join("Cargo.toml"), &dir) {
+        Ok(Registered::Added) => {
+            println!("registered {} day {:02} with the runner", year, day);
+            ExitCode::SUCCESS
+        }
+        Ok(Registered::AlreadyThere) => {
+            println!("{} day {:02} is already registered", year, day);
+            ExitCode::SUCCESS
+        }
+        Err(e) => {
+            eprintln!("{}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// Needs cargo-generate to be installed
+fn new_day(root: &Path, year: u16, day: u8, name: &str) -> ExitCode {
+    if let Some(dir) = day_dir(root, year, day) {
+        eprintln!("{} already exists", dir.display());
+        return ExitCode::FAILURE;
+    }
+
+    let status = process::Command::new("cargo")
+        .arg("generate")
+        .arg("--path")
+        .arg(root.join("template"))
+        .arg("--destination")
+        .arg(root.join(year.to_string()))
+        .args(["--name", &format!("day{:02}_{}", day, name)])
+        .args(["--force", "--vcs", "none"])
+        .args(["-d", &format!("year={}", year)])
+        .args(["-d", &format!("day={:02}", day)])
+        .status();
+
+    match status {
+        Ok(status) if status.success() => register(root, year, day),
+        Ok(_) => ExitCode::FAILURE,
+        Err(e) => {
+            eprintln!("cargo generate: {}", e);
+            ExitCode::FAILURE
+        }
+    }
+}
+
+// For the commands that can't get started at all
+fn or_exit(result: Result<ExitCode, String>) -> ExitCode {
+    result.unwrap_or_else(|e| {
+        eprintln!("{}", e);
+        ExitCode::FAILURE
+    })
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let root = root_dir(cli.root);
+
+    match cli.command {
+        Command::Run {
+            target,
+            day,
+            part,
+            json,
+            profile,
+            parallel,
+        } => or_exit(
+            select_inputs(&root, &target, day, &profile).map(|(puzzles, profiles)| {
+                let parts = part.map_or(vec![1, 2], |part| vec![part]);
+                run(&puzzles, &profiles, &parts, json, &parallel)
+            }),
+        ),
+        Command::Verify {
+            target,
+            day,
+            profile,
+            parallel,
+        } => or_exit(
+            select_inputs(&root, &target, day, &profile)
+                .map(|(puzzles, profiles)| verify(&puzzles, &profiles, &parallel)),
+        ),
+        Command::Bench {
+            target,
+            day,
+            runs,
+            threshold,
+            save,
+        } => or_exit(
+            select(registry::puzzles(), &target, day)
+                .map(|puzzles| bench(&root, &puzzles, runs, threshold, save)),
+        ),
+        Command::Generate {
+            year,
+            day,
+            size,
+            seed,
+        } => or_exit(generate(year, day, size, seed).map(|input| {
+            print!("{}", input);
+            ExitCode::SUCCESS
+        })),
+        Command::Fetch {
+            year,
+            day,
+            base_url,
+        } => fetch(&root, year, day, &base_url),
+        Command::New { year, day, name } => new_day(&root, year, day, &name),
+        Command::Register { year, day } => register(&root, year, day),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    #[test]
+    fn select_tests() {
+        assert_eq!(
+            select(registry::puzzles(), "all", None).unwrap().len(),
+            registry::puzzles().len()
+        );
+        assert!(select(registry::puzzles(), "2015", None)
+            .unwrap()
+            .iter()
+            .all(|puzzle| puzzle.year == 2015));
+
+        let selected = select(registry::puzzles(), "2015", Some(7)).unwrap();
+        assert_eq!(selected.len(), 1);
+        assert_eq!((selected[0].year, selected[0].day), (2015, 7));
+
+        assert!(select(registry::puzzles(), "all", Some(7)).is_err());
+        assert!(select(registry::puzzles(), "2015", Some(26)).is_err());
+        assert!(select(registry::puzzles(), "1999", None).is_err());
+        assert!(select(registry::puzzles(), "twenty", None).is_err());
+    }
+
+    #[test]
+    fn report_tests() {
+        let puzzle = select(registry::puzzles(), "2015", Some(1)).unwrap()[0];
+        let profile = Profile::new(root_dir(None), profiles::DEFAULT);
+        let report = solve(&puzzle, &profile, &[1, 2]).unwrap();
+
+        assert_eq!((report.year, report.day), (2015, 1));
+        assert_eq!(report.parts.len(), 2);
+        // The input is read into a String, which has to be counted
+        assert!(report.parse.peak_memory.unwrap() > 0);
+        assert!(report
+            .parts
+            .iter()
+            .all(|result| result.measurement.peak_memory.is_some()));
+    }
+
+    #[test]
+    fn generate_tests() {
+        let input = generate(2023, 16, 5, 1).unwrap();
+        assert_eq!(input.lines().count(), 5);
+        assert_eq!(generate(2023, 16, 5, 1).unwrap(), input);
+
+        assert!(generate(2015, 1, 5, 1).is_err());
+        assert!(generate(2015, 26, 5, 1).is_err());
+    }
+
+    #[test]
+    fn day_dir_tests() {
+        let root = root_dir(None);
+        assert!(day_dir(&root, 2015, 7)
+            .unwrap()
+            .ends_with("2015/day07_some_assembly_required"));
+        assert!(day_dir(&root, 2015, 26).is_none());
+
+        // From anywhere in the repository, or from wherever --root says
+        assert!(root.join("aoc").join("Cargo.toml").is_file());
+        assert_eq!(
+            root_dir(Some(PathBuf::from("/elsewhere"))),
+            Path::new("/elsewhere")
+        );
+    }
+}
@@ -0,0 +1,140 @@
+use aoc_common::{panic_message, Puzzle, Report};
+use std::{
+    collections::BTreeMap,
+    panic::{self, AssertUnwindSafe},
+    sync::{
+        mpsc::{self, RecvTimeoutError},
+        Arc, Mutex,
+    },
+    thread,
+    time::Duration,
+};
+
+// How a day went when solved next to the others
+#[derive(Debug)]
+pub enum Outcome {
+    Finished(Result<Report, String>),
+    Panicked(String),
+    TimedOut(Duration),
+}
+
+// Solves the puzzles `jobs` at a time and hands their outcomes to `done` in
+// the same order as the puzzles, as soon as every day before them is done
+// too. A day that panics or takes longer than `timeout` doesn't stop the
+// others.
+//
+// There's no stopping a thread from the outside, so a day that times out is
+// left to run in the background until the runner exits. Its job slot goes to
+// the next day straight away all the same.
+pub fn solve_all<F>(
+    puzzles: &[Puzzle],
+    jobs: usize,
+    timeout: Duration,
+    solve: F,
+    mut done: impl FnMut(&Puzzle, Outcome),
+) where
+    F: Fn(&Puzzle) -> Result<Report, String> + Send + Sync + 'static,
+{
+    let queue: Vec<(usize, Puzzle)> = puzzles.iter().copied().enumerate().collect();
+    let queue = Arc::new(Mutex::new(queue.into_iter()));
+    let solve = Arc::new(solve);
+    let (results, finished) = mpsc::channel();
+
+    (0..jobs.clamp(1, puzzles.len().max(1))).for_each(|_| {
+        let queue = Arc::clone(&queue);
+        let solve = Arc::clone(&solve);
+        let results = results.clone();
+
+        thread::spawn(move || loop {
+            let Some((i, puzzle)) = queue.lock().unwrap().next() else {
+                break;
+            };
+
+            let outcome = solve_one(puzzle, timeout, Arc::clone(&solve));
+            if results.send((i, outcome)).is_err() {
+                break;
+            }
+        });
+    });
+    drop(results);
+
+    // The days finish in any order, the ones that are done before their turn
+    // wait here
+    let mut waiting = BTreeMap::new();
+    let mut next = 0;
+    finished.iter().for_each(|(i, outcome)| {
+        waiting.insert(i, outcome);
+        while let Some(outcome) = waiting.remove(&next) {
+            done(&puzzles[next], outcome);
+            next += 1;
+        }
+    });
+}
+
+fn solve_one<F>(puzzle: Puzzle, timeout: Duration, solve: Arc<F>) -> Outcome
+where
+    F: Fn(&Puzzle) -> Result<Report, String> + Send + Sync + 'static,
+{
+    let (sender, receiver) = mpsc::channel();
+
+    thread::spawn(move || {
+        let result = panic::catch_unwind(AssertUnwindSafe(|| solve(&puzzle)));
+        // Nobody is listening anymore when the day has timed out
+        let _ = sender.send(result);
+    });
+
+    let result = match receiver.recv_timeout(timeout) {
+        Ok(result) => result,
+        Err(RecvTimeoutError::Timeout) => return Outcome::TimedOut(timeout),
+        // Only if the thread died without getting to send anything
+        Err(RecvTimeoutError::Disconnected) => return Outcome::Panicked(String::new()),
+    };
+
+    match result {
+        Ok(result) => Outcome::Finished(result),
+        Err(payload) => Outcome::Panicked(panic_message(payload)),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{parallel::*, registry};
+
+    #[test]
+    fn solve_all_tests() {
+        let puzzles: Vec<Puzzle> = registry::puzzles()
+            .into_iter()
+            .filter(|puzzle| puzzle.year == 2015 && puzzle.day <= 4)
+            .collect();
+
+        // Day 1 takes a while so that the days after it are done before it,
+        // day 2 panics and day 3 doesn't finish in time
+        let solve = |puzzle: &Puzzle| match puzzle.day {
+            1 => {
+                thread::sleep(Duration::from_millis(200));
+                Err("one".to_string())
+            }
+            2 => panic!("two"),
+            3 => {
+                thread::sleep(Duration::from_secs(5));
+                Err("three".to_string())
+            }
+            _ => Err("four".to_string()),
+        };
+
+        let mut outcomes = Vec::new();
+        solve_all(
+            &puzzles,
+            4,
+            Duration::from_millis(500),
+            solve,
+            |puzzle, outcome| outcomes.push((puzzle.day, outcome)),
+        );
+
+        assert_eq!(outcomes.len(), 4);
+        assert!(matches!(&outcomes[0], (1, Outcome::Finished(Err(e))) if e == "one"));
+        assert!(matches!(&outcomes[1], (2, Outcome::Panicked(message)) if message == "two"));
+        assert!(matches!(&outcomes[2], (3, Outcome::TimedOut(_))));
+        assert!(matches!(&outcomes[3], (4, Outcome::Finished(Err(e))) if e == "four"));
+    }
+}
@@ -0,0 +1,128 @@
+use std::{fs, path::Path};
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Registered {
+    Added,
+    AlreadyThere,
+}
+
+// Adds a day's crate to the runner's dependencies, next to the other days of
+// its year. That's all the runner needs to pick it up.
+pub fn register(manifest: &Path, day_dir: &Path) -> Result<Registered, String> {
+    let day_manifest = day_dir.join("Cargo.toml");
+    let name = fs::read_to_string(&day_manifest)
+        .map_err(|e| format!("{}: {}", day_manifest.display(), e))?
+        .lines()
+        .find_map(|line| line.strip_prefix("name = \""))
+        .and_then(|name| name.strip_suffix('"'))
+        .map(String::from)
+        .ok_or(format!("{}: no package name", day_manifest.display()))?;
+
+    // Days live in <year>/dayXX_puzzle_name/ next to the runner
+    let (year, dir) = match (
+        day_dir.parent().and_then(Path::file_name),
+        day_dir.file_name(),
+    ) {
+        (Some(year), Some(dir)) => (year.to_string_lossy(), dir.to_string_lossy()),
+        _ => return Err(format!("{}: not a day's folder", day_dir.display())),
+    };
+    let path = format!("../{}/{}", year, dir);
+
+    let text =
+        fs::read_to_string(manifest).map_err(|e| format!("{}: {}", manifest.display(), e))?;
+    let mut lines: Vec<&str> = text.lines().collect();
+
+    let day_path = |line: &str| {
+        line.split_once(" = { path = \"")
+            .and_then(|(_, path)| path.strip_suffix("\" }"))
+            .filter(|path| path.starts_with("../20"))
+            .map(String::from)
+    };
+
+    if lines
+        .iter()
+        .any(|line| day_path(line).as_ref() == Some(&path))
+    {
+        return Ok(Registered::AlreadyThere);
+    }
+
+    // Keep the days sorted, which is also the order the runner goes through
+    // them in
+    let days: Vec<(usize, String)> = lines
+        .iter()
+        .enumerate()
+        .filter_map(|(i, line)| day_path(line).map(|path| (i, path)))
+        .collect();
+    let index = match days.iter().find(|(_, other)| *other > path) {
+        Some((i, _)) => *i,
+        None => days
+            .last()
+            .map(|(i, _)| i + 1)
+            .ok_or(format!("{}: no days to add to", manifest.display()))?,
+    };
+
+    let line = format!("{} = {{ path = \"{}\" }}", name, path);
+    lines.insert(index, &line);
+
+    fs::write(manifest, lines.join("\n") + "\n")
+        .map_err(|e| format!("{}: {}", manifest.display(), e))?;
+
+    Ok(Registered::Added)
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::register::*;
+
+    const MANIFEST: &str = "\
+[dependencies]
+aoc_common.workspace = true
+day01_not_quite_lisp = { path = \"../2015/day01_not_quite_lisp\" }
+day01_calorie_counting = { path = \"../2022/day01_calorie_counting\" }
+
+[dev-dependencies]
+tempfile.workspace = true
+";
+
+    fn new_day(root: &Path, year: &str, dir: &str) -> std::path::PathBuf {
+        let day_dir = root.join(year).join(dir);
+        fs::create_dir_all(&day_dir).unwrap();
+        fs::write(
+            day_dir.join("Cargo.toml"),
+            format!("[package]\nname = \"{}\"\nversion = \"0.1.0\"\n", dir),
+        )
+        .unwrap();
+        day_dir
+    }
+
+    #[test]
+    fn register_tests() {
+        let root = tempfile::tempdir().unwrap();
+        let manifest = root.path().join("Cargo.toml");
+        fs::write(&manifest, MANIFEST).unwrap();
+
+        let day_dir = new_day(root.path(), "2016", "day02_bathroom_security");
+        assert_eq!(register(&manifest, &day_dir), Ok(Registered::Added));
+
+        let day_dir = new_day(root.path(), "2023", "day01_trebuchet");
+        assert_eq!(register(&manifest, &day_dir), Ok(Registered::Added));
+
+        assert_eq!(
+            fs::read_to_string(&manifest).unwrap(),
+            "\
+[dependencies]
+aoc_common.workspace = true
+day01_not_quite_lisp = { path = \"../2015/day01_not_quite_lisp\" }
+day02_bathroom_security = { path = \"../2016/day02_bathroom_security\" }
+day01_calorie_counting = { path = \"../2022/day01_calorie_counting\" }
+day01_trebuchet = { path = \"../2023/day01_trebuchet\" }
+
+[dev-dependencies]
+tempfile.workspace = true
+"
+        );
+
+        assert_eq!(register(&manifest, &day_dir), Ok(Registered::AlreadyThere));
+        assert!(register(&manifest, &root.path().join("2016/day03_missing")).is_err());
+    }
+}
@@ -0,0 +1,7 @@
+use aoc_common::Puzzle;
+
+// Every solution that the runner knows about, in the order of the runner's
+// dependencies in Cargo.toml (see build.rs)
+pub fn puzzles() -> Vec<Puzzle> {
+    include!(concat!(env!("OUT_DIR"), "/registry.rs"))
+}
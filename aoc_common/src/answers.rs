@@ -0,0 +1,404 @@
+use crate::{profiles::Profile, Solution};
+use serde::{Serialize, Serializer};
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    fmt,
+    fs::{self, File},
+    io::{self, BufReader},
+    path::Path,
+};
+
+// What the parts of a puzzle return. Most answers are numbers, a few are
+// words, and some puzzles draw their answer on a screen instead. The
+// integers are wide enough for any of the primitive integers up to u64.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Answer {
+    Integer(i128),
+    Text(String),
+    // One string per row
+    Grid(Vec<String>),
+}
+
+impl Answer {
+    pub fn grid<R: IntoIterator<Item = char>>(rows: impl IntoIterator<Item = R>) -> Self {
+        Answer::Grid(
+            rows.into_iter()
+                .map(|row| row.into_iter().collect())
+                .collect(),
+        )
+    }
+
+    // The kind is told apart by the looks of the answer: numbers are
+    // integers, and anything spanning several lines is a grid. Some text
+    // answers look like numbers too, which is why answers are compared with
+    // matches(). A number is only an integer if it's written the way the
+    // integer would be, "0124" or "+5" would lose their first character.
+    pub fn parse(text: &str) -> Self {
+        if let Some(integer) = text
+            .parse::<i128>()
+            .ok()
+            .filter(|integer| integer.to_string() == text)
+        {
+            Answer::Integer(integer)
+        } else if text.contains('\n') {
+            Answer::Grid(text.lines().map(String::from).collect())
+        } else {
+            Answer::Text(text.to_string())
+        }
+    }
+}
+
+impl Answer {
+    // Whether the two answers would be typed in the same. The kind doesn't
+    // matter, a password that's all digits is the same answer as the number.
+    pub fn matches(&self, other: &Answer) -> bool {
+        match (self, other) {
+            (Answer::Integer(a), Answer::Integer(b)) => a == b,
+            _ => self.to_string() == other.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Answer {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Answer::Integer(integer) => write!(f, "{}", integer),
+            Answer::Text(text) => write!(f, "{}", text),
+            Answer::Grid(rows) => write!(f, "{}", rows.join("\n")),
+        }
+    }
+}
+
+// Integers as numbers, text as a string and grids as an array of rows
+impl Serialize for Answer {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Answer::Integer(integer) => serializer.serialize_i128(*integer),
+            Answer::Text(text) => serializer.serialize_str(text),
+            Answer::Grid(rows) => rows.serialize(serializer),
+        }
+    }
+}
+
+// isize and usize are 64 bits at most, so none of these lose anything
+macro_rules! integer_answer {
+    ($($integer:ty),*) => {
+        $(
+            impl From<$integer> for Answer {
+                fn from(integer: $integer) -> Self {
+                    Answer::Integer(integer as i128)
+                }
+            }
+
+            impl From<&$integer> for Answer {
+                fn from(integer: &$integer) -> Self {
+                    Answer::from(*integer)
+                }
+            }
+        )*
+    };
+}
+
+integer_answer!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl From<String> for Answer {
+    fn from(text: String) -> Self {
+        Answer::Text(text)
+    }
+}
+
+impl From<&str> for Answer {
+    fn from(text: &str) -> Self {
+        Answer::Text(text.to_string())
+    }
+}
+
+// Each year folder has an answers.txt with the expected answers for the
+// committed inputs, one answer per line:
+//
+//   # comment
+//   <day> <part> <answer>
+//
+// Answers that span several lines (the puzzles that draw letters on a
+// screen) are written on one line with \n between the rows. See
+// Answer::parse() for how the kind of answer is decided.
+#[derive(Debug, Default)]
+pub struct Answers(HashMap<(u8, u8), Answer>);
+
+impl Answers {
+    // The errors say which file it was, they're usually about a profile's
+    // answers that haven't been written yet
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref();
+        let text = fs::read_to_string(path)
+            .map_err(|e| io::Error::new(e.kind(), format!("{}: {}", path.display(), e)))?;
+
+        Answers::try_from(text.as_str()).map_err(|line| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "{}:{}: expected `<day> <part> <answer>`, once for each part",
+                    path.display(),
+                    line
+                ),
+            )
+        })
+    }
+
+    pub fn get(&self, day: u8, part: u8) -> Option<&Answer> {
+        self.0.get(&(day, part))
+    }
+
+    pub fn check(&self, day: u8, part: u8, answer: &Answer) -> Verdict {
+        match self.get(day, part) {
+            None => Verdict::Missing,
+            Some(expected) if expected.matches(answer) => Verdict::Pass,
+            Some(expected) => Verdict::Fail(expected.clone()),
+        }
+    }
+}
+
+// Fails with the (1-based) number of the first malformed line, or of the
+// first line that gives an answer for a part that already has one
+impl TryFrom<&str> for Answers {
+    type Error = usize;
+
+    fn try_from(text: &str) -> Result<Self, Self::Error> {
+        let mut answers = HashMap::new();
+
+        text.lines()
+            .zip(1usize..)
+            .map(|(line, number)| (line.trim(), number))
+            .filter(|(line, _)| !line.is_empty() && !line.starts_with('#'))
+            .try_for_each(|(line, number)| {
+                let mut fields = line.splitn(3, char::is_whitespace);
+
+                let (Some(day), Some(part), Some(answer)) =
+                    (fields.next(), fields.next(), fields.next())
+                else {
+                    return Err(number);
+                };
+                let (Ok(day), Ok(part)) = (day.parse(), part.parse()) else {
+                    return Err(number);
+                };
+
+                match answers.entry((day, part)) {
+                    Entry::Occupied(_) => Err(number),
+                    Entry::Vacant(entry) => {
+                        entry.insert(Answer::parse(&answer.trim().replace("\\n", "\n")));
+                        Ok(())
+                    }
+                }
+            })?;
+
+        Ok(Answers(answers))
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Pass,
+    // Holds the expected answer
+    Fail(Answer),
+    Missing,
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        // pad() instead of write!() so that the runner can align the table
+        f.pad(match self {
+            Verdict::Pass => "pass",
+            Verdict::Fail(_) => "FAIL",
+            Verdict::Missing => "missing",
+        })
+    }
+}
+
+// Used by every day's check_answers test, which runs from the day's folder.
+// Every profile that has an input for the day gets checked. A day that was
+// just generated from the template has an empty input and no answers yet,
+// there's nothing to check until it has both.
+pub fn check_answers<S: Solution>() {
+    Profile::all("../..")
+        .iter()
+        .filter(|profile| profile.has_input(S::YEAR, S::DAY))
+        .for_each(|profile| {
+            let name = profile.name();
+            let path = profile.input(S::YEAR, S::DAY).unwrap();
+            let no_input = fs::metadata(&path).map_or(true, |metadata| metadata.len() == 0);
+
+            let answers = match Answers::load(profile.answers(S::YEAR)) {
+                Err(e) if no_input && e.kind() == io::ErrorKind::NotFound => return,
+                answers => answers.unwrap_or_else(|e| panic!("{} profile: {}", name, e)),
+            };
+            if no_input && answers.get(S::DAY, 1).is_none() && answers.get(S::DAY, 2).is_none() {
+                return;
+            }
+
+            let f = File::open(&path)
+                .unwrap_or_else(|e| panic!("{} profile: {}: {}", name, path.display(), e));
+            let input = S::parse(&mut BufReader::new(f))
+                .unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+
+            // Panic with the diagnostic rather than the Debug output of the
+            // error
+            let part1 = S::part1(&input).unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+            assert_eq!(
+                answers.check(S::DAY, 1, &part1),
+                Verdict::Pass,
+                "part 1, {} profile",
+                name
+            );
+
+            match S::part2(&input) {
+                Some(part2) => {
+                    let part2 = part2.unwrap_or_else(|e| panic!("{}: {}", path.display(), e));
+                    assert_eq!(
+                        answers.check(S::DAY, 2, &part2),
+                        Verdict::Pass,
+                        "part 2, {} profile",
+                        name
+                    );
+                }
+                // The last day only has one part, an answer for a second one
+                // is a typo for some other day
+                None => assert_eq!(
+                    answers.get(S::DAY, 2),
+                    None,
+                    "part 2, {} profile: there is no part 2",
+                    name
+                ),
+            }
+        });
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::answers::*;
+
+    #[test]
+    fn answer_tests() {
+        assert_eq!(Answer::from(138u32), Answer::Integer(138));
+        assert_eq!(Answer::from(-5i64), Answer::Integer(-5));
+        // Too big for an i64
+        assert_eq!(
+            Answer::from(u64::MAX),
+            Answer::Integer(18446744073709551615)
+        );
+        assert_eq!(Answer::from(u64::MAX).to_string(), "18446744073709551615");
+        assert_eq!(
+            Answer::from("hepxxyzz"),
+            Answer::Text("hepxxyzz".to_string())
+        );
+        assert_eq!(
+            Answer::grid([['#', '.'], ['.', '#']]),
+            Answer::Grid(vec!["#.".to_string(), ".#".to_string()])
+        );
+
+        assert_eq!(Answer::parse("138"), Answer::Integer(138));
+        assert_eq!(Answer::parse("2=-1=0"), Answer::from("2=-1=0"));
+        assert_eq!(Answer::parse("-5"), Answer::Integer(-5));
+        assert_eq!(Answer::parse("0124515891"), Answer::from("0124515891"));
+        assert_eq!(Answer::parse("+5"), Answer::from("+5"));
+        assert_eq!(
+            Answer::parse("#.\n.#"),
+            Answer::grid(["#.".chars(), ".#".chars()])
+        );
+    }
+
+    #[test]
+    fn display_tests() {
+        assert_eq!(Answer::Integer(-138).to_string(), "-138");
+        assert_eq!(Answer::from("hepxxyzz").to_string(), "hepxxyzz");
+        assert_eq!(Answer::parse("#.\n.#").to_string(), "#.\n.#");
+    }
+
+    #[test]
+    fn json_tests() {
+        assert_eq!(serde_json::to_string(&Answer::Integer(138)).unwrap(), "138");
+        assert_eq!(
+            serde_json::to_string(&Answer::from(u64::MAX)).unwrap(),
+            "18446744073709551615"
+        );
+        assert_eq!(
+            serde_json::to_string(&Answer::from("hepxxyzz")).unwrap(),
+            "\"hepxxyzz\""
+        );
+        assert_eq!(
+            serde_json::to_string(&Answer::parse("#.\n.#")).unwrap(),
+            "[\"#.\",\".#\"]"
+        );
+    }
+
+    #[test]
+    fn parse_tests() {
+        let answers = Answers::try_from("# comment\n01 1 138\n\n 1 2 hello world \n").unwrap();
+
+        assert_eq!(answers.get(1, 1), Some(&Answer::Integer(138)));
+        assert_eq!(answers.get(1, 2), Some(&Answer::from("hello world")));
+        assert_eq!(answers.get(2, 1), None);
+
+        let answers = Answers::try_from("10 2 #..#\\n####\n").unwrap();
+        assert_eq!(answers.get(10, 2), Some(&Answer::parse("#..#\n####")));
+
+        assert_eq!(Answers::try_from("01 1 138\n01 1\n").unwrap_err(), 2);
+        assert_eq!(Answers::try_from("x 1 138\n").unwrap_err(), 1);
+        // The same part twice
+        assert_eq!(
+            Answers::try_from("01 1 138\n01 2 1771\n# 01 1 139\n1 1 139\n").unwrap_err(),
+            4
+        );
+    }
+
+    #[test]
+    fn load_tests() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("answers.txt");
+
+        // Which file is missing
+        let error = Answers::load(&path).unwrap_err();
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+        assert!(error
+            .to_string()
+            .starts_with(&format!("{}: ", path.display())));
+
+        fs::write(&path, "01 1 138\n01 1 139\n").unwrap();
+        assert_eq!(
+            Answers::load(&path).unwrap_err().to_string(),
+            format!(
+                "{}:2: expected `<day> <part> <answer>`, once for each part",
+                path.display()
+            )
+        );
+    }
+
+    #[test]
+    fn check_tests() {
+        let answers = Answers::try_from("01 1 138\n").unwrap();
+
+        assert_eq!(answers.check(1, 1, &Answer::Integer(138)), Verdict::Pass);
+        assert_eq!(
+            answers.check(1, 1, &Answer::Integer(139)),
+            Verdict::Fail(Answer::Integer(138))
+        );
+        // A text answer that is all digits, like a password
+        assert_eq!(answers.check(1, 1, &Answer::from("138")), Verdict::Pass);
+        assert!(!Answer::from("0138").matches(&Answer::Integer(138)));
+        assert_eq!(answers.check(1, 2, &Answer::Integer(138)), Verdict::Missing);
+
+        // The leading zero is part of the answer
+        let answers = Answers::try_from(
+            "14 1 0124515891
+",
+        )
+        .unwrap();
+        assert_eq!(
+            answers.check(14, 1, &Answer::from("0124515891")),
+            Verdict::Pass
+        );
+        assert_eq!(
+            answers.check(14, 1, &Answer::Integer(124515891)),
+            Verdict::Fail(Answer::from("0124515891"))
+        );
+    }
+}
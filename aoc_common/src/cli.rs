@@ -0,0 +1,108 @@
+use crate::examples::Example;
+use clap::Parser;
+use std::{
+    fs::File,
+    io::{self, BufRead, BufReader, Cursor},
+    path::{Path, PathBuf},
+};
+
+// The arguments that every day's binary takes. By default a day is solved
+// for the input in the current folder, like it has always been.
+#[derive(Parser, Debug)]
+#[command(about = "Solves the Advent of Code puzzle of the day")]
+pub struct Args {
+    /// Read the puzzle input from this file, `-` reads it from stdin
+    #[arg(long, default_value = "input", conflicts_with = "example")]
+    input: PathBuf,
+    /// Use an example from the puzzle description, examples/<EXAMPLE>.txt
+    #[arg(long, num_args = 0..=1, default_missing_value = "example")]
+    example: Option<String>,
+    /// Only run this part of the puzzle
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+}
+
+// See Args::open
+pub type Opened = (Box<dyn BufRead>, Vec<(String, String)>);
+
+impl Args {
+    fn path(&self) -> PathBuf {
+        match &self.example {
+            Some(name) => PathBuf::from("examples").join(format!("{}.txt", name)),
+            None => self.input.clone(),
+        }
+    }
+
+    fn is_stdin(&self) -> bool {
+        self.example.is_none() && self.input == Path::new("-")
+    }
+
+    // What the input is called in error messages
+    pub fn input_name(&self) -> String {
+        if self.is_stdin() {
+            "stdin".to_string()
+        } else {
+            self.path().display().to_string()
+        }
+    }
+
+    // The input, and the parameters that it's solved with. Only an example
+    // has any, in its header next to the answers.
+    pub fn open(&self) -> io::Result<Opened> {
+        if self.is_stdin() {
+            Ok((Box::new(io::stdin().lock()), Vec::new()))
+        } else if self.example.is_some() {
+            let example = Example::load(self.path())?;
+            Ok((Box::new(Cursor::new(example.input)), example.parameters))
+        } else {
+            Ok((
+                Box::new(BufReader::new(File::open(self.path())?)),
+                Vec::new(),
+            ))
+        }
+    }
+
+    pub fn parts(&self) -> Vec<u8> {
+        match self.part {
+            Some(part) => vec![part],
+            None => vec![1, 2],
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cli::*;
+
+    fn args(args: &[&str]) -> Result<Args, clap::Error> {
+        Args::try_parse_from(["day"].iter().chain(args))
+    }
+
+    #[test]
+    fn input_tests() {
+        assert_eq!(args(&[]).unwrap().input_name(), "input");
+        assert_eq!(
+            args(&["--input", "../other/input"]).unwrap().input_name(),
+            "../other/input"
+        );
+        assert_eq!(args(&["--input", "-"]).unwrap().input_name(), "stdin");
+        assert_eq!(
+            args(&["--example"]).unwrap().input_name(),
+            "examples/example.txt"
+        );
+        assert_eq!(
+            args(&["--example", "larger"]).unwrap().input_name(),
+            "examples/larger.txt"
+        );
+
+        assert!(args(&["--example", "--input", "input"]).is_err());
+    }
+
+    #[test]
+    fn part_tests() {
+        assert_eq!(args(&[]).unwrap().parts(), vec![1, 2]);
+        assert_eq!(args(&["--part", "2"]).unwrap().parts(), vec![2]);
+
+        assert!(args(&["--part", "3"]).is_err());
+    }
+}
@@ -0,0 +1,195 @@
+use std::{
+    collections::{hash_map::Entry, HashMap},
+    hash::Hash,
+};
+
+// After `start` steps the state is the one that comes back every `length`
+// steps
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Cycle {
+    pub start: usize,
+    pub length: usize,
+}
+
+impl Cycle {
+    // The first step that is at the same point of the loop as step `n`
+    pub fn equivalent(&self, n: usize) -> usize {
+        if n < self.start {
+            n
+        } else {
+            self.start + (n - self.start) % self.length
+        }
+    }
+}
+
+// Steps until a fingerprint comes up again or `limit` steps have been made,
+// whichever comes first. `record` is told about the state before each step,
+// and once more about the state that ends the walk.
+fn walk<S, K: Eq + Hash, R>(
+    state: &mut S,
+    limit: usize,
+    mut step: impl FnMut(&mut S),
+    mut fingerprint: impl FnMut(&S) -> K,
+    mut record: impl FnMut(&S) -> R,
+) -> (Vec<R>, Option<Cycle>) {
+    let mut seen = HashMap::new();
+    let mut records = Vec::new();
+    let mut n = 0;
+
+    loop {
+        records.push(record(state));
+
+        match seen.entry(fingerprint(state)) {
+            Entry::Occupied(entry) => {
+                let start = *entry.get();
+                return (
+                    records,
+                    Some(Cycle {
+                        start,
+                        length: n - start,
+                    }),
+                );
+            }
+            Entry::Vacant(entry) => {
+                entry.insert(n);
+            }
+        }
+
+        if n == limit {
+            return (records, None);
+        }
+
+        step(state);
+        n += 1;
+    }
+}
+
+// For the puzzles that ask what things look like after a number of steps
+// that's far too large to simulate. The steps have to come back to a state
+// they have already been in at some point, and from there on they go around
+// the same loop forever.
+//
+// `step` moves the state forward by one step and `fingerprint` sums up the
+// parts of the state that decide where it goes next. Two states with the
+// same fingerprint are taken to be the same point of the loop, so anything
+// that only ever grows (a tower's height, a counter) should be left out of
+// the fingerprint and measured with extrapolate() instead.
+//
+// find() keeps stepping until the loop shows up. This never returns if the
+// states don't go around in a loop.
+pub fn find<S, K: Eq + Hash>(
+    mut state: S,
+    step: impl FnMut(&mut S),
+    fingerprint: impl FnMut(&S) -> K,
+) -> Cycle {
+    walk(&mut state, usize::MAX, step, fingerprint, |_| ())
+        .1
+        .unwrap()
+}
+
+// The state after `n` steps. Every state is kept until the loop is found, so
+// this is for states that are cheap enough to clone.
+pub fn nth<S: Clone, K: Eq + Hash>(
+    mut state: S,
+    n: usize,
+    step: impl FnMut(&mut S),
+    fingerprint: impl FnMut(&S) -> K,
+) -> S {
+    let (mut states, cycle) = walk(&mut state, n, step, fingerprint, S::clone);
+
+    match cycle {
+        Some(cycle) => states.swap_remove(cycle.equivalent(n)),
+        None => states.pop().unwrap(),
+    }
+}
+
+// The value of `metric` after `n` steps, for a metric that goes up (or down)
+// by the same amount every time around the loop, like the height of a pile
+// that more and more gets dropped onto
+pub fn extrapolate<S, K: Eq + Hash>(
+    mut state: S,
+    n: usize,
+    step: impl FnMut(&mut S),
+    fingerprint: impl FnMut(&S) -> K,
+    metric: impl FnMut(&S) -> i64,
+) -> i64 {
+    let (metrics, cycle) = walk(&mut state, n, step, fingerprint, metric);
+
+    match cycle {
+        Some(cycle) => {
+            let per_loop = metrics[cycle.start + cycle.length] - metrics[cycle.start];
+            let loops = ((n - cycle.start) / cycle.length) as i64;
+
+            metrics[cycle.equivalent(n)] + loops * per_loop
+        }
+        None => *metrics.last().unwrap(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::cycle::*;
+
+    // x -> x² + 1 mod 255 from 3 goes 3, 10, 101, 2, 5, 26, 167, 95, 101 and
+    // loops from there
+    fn step(x: &mut u32) {
+        *x = (*x * *x + 1) % 255;
+    }
+
+    #[test]
+    fn find_tests() {
+        let cycle = find(3, step, |&x| x);
+
+        assert_eq!(
+            cycle,
+            Cycle {
+                start: 2,
+                length: 6
+            }
+        );
+        assert_eq!(cycle.equivalent(1), 1);
+        assert_eq!(cycle.equivalent(9), 3);
+        assert_eq!(cycle.equivalent(20), 2);
+
+        assert_eq!(find(0, |x| *x = (*x + 1) % 4, |&x| x).length, 4);
+    }
+
+    #[test]
+    fn nth_tests() {
+        let mut x = 3;
+        let states: Vec<u32> = (0..=30)
+            .map(|_| {
+                let state = x;
+                step(&mut x);
+                state
+            })
+            .collect();
+
+        (0..=30).for_each(|n| assert_eq!(nth(3, n, step, |&x| x), states[n]));
+        assert_eq!(nth(3, 1_000_000_000_000, step, |&x| x), 5);
+    }
+
+    #[test]
+    fn extrapolate_tests() {
+        // A counter that goes around 0..5 and a total that adds it up, with
+        // only the counter deciding what happens next
+        let step = |(counter, total): &mut (i64, i64)| {
+            *counter = (*counter + 1) % 5;
+            *total += *counter;
+        };
+        let total = |n| {
+            extrapolate(
+                (0, 0),
+                n,
+                step,
+                |&(counter, _)| counter,
+                |&(_, total)| total,
+            )
+        };
+
+        assert_eq!(total(3), 6);
+        assert_eq!(total(5), 10);
+        assert_eq!(total(12), 23);
+        assert_eq!(total(1_000_000), 2_000_000);
+    }
+}
@@ -0,0 +1,306 @@
+use crate::{panic_message, Solution};
+use std::{
+    fmt::{self, Debug},
+    iter, mem,
+    panic::{self, AssertUnwindSafe},
+};
+
+// How many inputs of each size check_pair() tries
+pub const SEEDS: u64 = 10;
+
+// Two ways of getting the same thing out of an input, usually a
+// straightforward one and a faster one, that have to agree on every input.
+// Keeping the straightforward one around is what makes it safe to optimize
+// the other.
+pub struct Pair<T> {
+    pub name: &'static str,
+    pub reference: fn(&str) -> T,
+    pub candidate: fn(&str) -> T,
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum Disagreement<T> {
+    Answers { reference: T, candidate: T },
+    // With what the candidate panicked with
+    Panicked(String),
+}
+
+impl<T: Debug> fmt::Display for Disagreement<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Disagreement::Answers {
+                reference,
+                candidate,
+            } => write!(f, "reference {:?}, candidate {:?}", reference, candidate),
+            Disagreement::Panicked(message) => write!(f, "the candidate panicked: {}", message),
+        }
+    }
+}
+
+impl<T: PartialEq> Pair<T> {
+    // Nothing when they agree, and nothing either when the reference can't
+    // make sense of the input. Shrinking an input goes through plenty of
+    // inputs that aren't valid anymore.
+    pub fn compare(&self, input: &str) -> Option<Disagreement<T>> {
+        let reference = panic::catch_unwind(AssertUnwindSafe(|| (self.reference)(input))).ok()?;
+
+        match panic::catch_unwind(AssertUnwindSafe(|| (self.candidate)(input))) {
+            Ok(candidate) if candidate == reference => None,
+            Ok(candidate) => Some(Disagreement::Answers {
+                reference,
+                candidate,
+            }),
+            Err(payload) => Some(Disagreement::Panicked(panic_message(payload))),
+        }
+    }
+}
+
+// Used by the differential tests of the days that have a generator. The pair
+// is run on SEEDS generated inputs of each size, and the first input that
+// they disagree on is shrunk down before failing, so that what's left to
+// look at is as small as it gets.
+pub fn check_pair<S: Solution, T: PartialEq + Debug>(
+    pair: &Pair<T>,
+    sizes: impl IntoIterator<Item = usize>,
+) {
+    let failure = sizes
+        .into_iter()
+        .flat_map(|size| (0..SEEDS).map(move |seed| (size, seed)))
+        .find_map(|(size, seed)| {
+            let input = S::generate(size, seed)
+                .unwrap_or_else(|| panic!("{} day {:02} has no input generator", S::YEAR, S::DAY));
+
+            pair.compare(&input)
+                .map(|disagreement| (size, seed, input, disagreement))
+        });
+    let Some((size, seed, input, disagreement)) = failure else {
+        return;
+    };
+
+    // Only on inputs that they disagree on in the same way, otherwise the
+    // shrinking can wander off to some other problem
+    let shrunk = shrink(&input, |smaller| {
+        pair.compare(smaller)
+            .is_some_and(|other| mem::discriminant(&other) == mem::discriminant(&disagreement))
+    });
+
+    panic!(
+        "{}: disagreement on the input of size {} with seed {}, shrunk down to\n{}\n{}",
+        pair.name,
+        size,
+        seed,
+        shrunk,
+        pair.compare(&shrunk).unwrap()
+    );
+}
+
+// The smallest input that it can find that still fails, by taking the first
+// smaller input that fails until none of them does. Whole lines go first,
+// then single characters, then the numbers get smaller. Each step makes the
+// input shorter or one of its numbers smaller, so it does come to an end.
+pub fn shrink(input: &str, fails: impl Fn(&str) -> bool) -> String {
+    let mut input = input.to_string();
+
+    loop {
+        let Some(smaller) = smaller_inputs(&input).find(|smaller| fails(smaller)) else {
+            return input;
+        };
+        input = smaller;
+    }
+}
+
+fn smaller_inputs(input: &str) -> impl Iterator<Item = String> + '_ {
+    let lines = input.split_inclusive('\n').map(String::from).collect();
+    let chars = input.chars().map(String::from).collect();
+
+    without_chunks(lines)
+        .chain(without_chunks(chars))
+        .chain(smaller_numbers(input))
+}
+
+// Leaving out all the pieces, then each half of them, each quarter... down to
+// each single piece
+fn without_chunks(pieces: Vec<String>) -> impl Iterator<Item = String> {
+    let len = pieces.len();
+
+    iter::successors(Some(len), |&size| (size > 1).then_some(size / 2))
+        .filter(|&size| size > 0)
+        .flat_map(move |size| (0..len).step_by(size).map(move |start| (start, size)))
+        .map(move |(start, size)| {
+            pieces[..start].concat() + &pieces[(start + size).min(len)..].concat()
+        })
+}
+
+// Each number turned into 0, or into half of itself and then closer and
+// closer to itself, so that a number that has to stay over some limit gets
+// down to right over it
+fn smaller_numbers(input: &str) -> impl Iterator<Item = String> + '_ {
+    let mut numbers = Vec::new();
+    let mut start = None;
+    input
+        .char_indices()
+        .chain([(input.len(), ' ')])
+        .for_each(|(i, c)| match (start, c.is_ascii_digit()) {
+            (None, true) => start = Some(i),
+            (Some(from), false) => {
+                numbers.push(from..i);
+                start = None;
+            }
+            _ => {}
+        });
+
+    numbers.into_iter().flat_map(move |range| {
+        let number: u64 = input[range.clone()].parse().unwrap_or(0);
+        let closer = iter::successors(Some(number / 2), |&step| (step > 1).then_some(step / 2))
+            .filter(|&step| step > 0)
+            .map(move |step| number - step);
+
+        iter::once(0)
+            .chain(closer)
+            .filter(move |&smaller| smaller < number)
+            .map(move |smaller| {
+                format!(
+                    "{}{}{}",
+                    &input[..range.start],
+                    smaller,
+                    &input[range.end..]
+                )
+            })
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{answers::Answer, differential::*, parse::ParseError};
+    use std::io::BufRead;
+
+    fn sum(input: &str) -> u64 {
+        input.lines().map(|line| line.parse::<u64>().unwrap()).sum()
+    }
+
+    // Forgets about the numbers over 100
+    fn wrong_sum(input: &str) -> u64 {
+        input
+            .lines()
+            .map(|line| line.parse::<u64>().unwrap())
+            .filter(|&number| number <= 100)
+            .sum()
+    }
+
+    fn panicky_sum(input: &str) -> u64 {
+        assert!(input.lines().count() < 3, "too many numbers");
+        sum(input)
+    }
+
+    #[test]
+    fn compare_tests() {
+        let pair = Pair {
+            name: "sum",
+            reference: sum,
+            candidate: wrong_sum,
+        };
+
+        assert_eq!(pair.compare("5\n7\n"), None);
+        assert_eq!(
+            pair.compare("5\n700\n"),
+            Some(Disagreement::Answers {
+                reference: 705,
+                candidate: 5
+            })
+        );
+        // The reference can't read it, so there's nothing to compare
+        assert_eq!(pair.compare("5\nseven\n"), None);
+
+        let pair = Pair {
+            candidate: panicky_sum,
+            ..pair
+        };
+        assert_eq!(
+            pair.compare("1\n2\n3\n"),
+            Some(Disagreement::Panicked("too many numbers".to_string()))
+        );
+        assert_eq!(
+            pair.compare("1\n2\n3\n").unwrap().to_string(),
+            "the candidate panicked: too many numbers"
+        );
+    }
+
+    #[test]
+    fn shrink_tests() {
+        let fails = |input: &str| input.lines().any(|line| line == "bad");
+        assert_eq!(shrink("good\nbad\ngood\ngood\n", fails), "bad");
+
+        // Down to the smallest number that is still too big
+        let pair = Pair {
+            name: "sum",
+            reference: sum,
+            candidate: wrong_sum,
+        };
+        let shrunk = shrink("5\n700\n12\n4000\n", |input| pair.compare(input).is_some());
+        assert_eq!(shrunk, "101");
+
+        assert_eq!(
+            smaller_numbers("a 10 b 1 c").collect::<Vec<_>>(),
+            [
+                "a 0 b 1 c",
+                "a 5 b 1 c",
+                "a 8 b 1 c",
+                "a 9 b 1 c",
+                "a 10 b 0 c"
+            ]
+        );
+    }
+
+    // A puzzle whose input is a number per line
+    struct Numbers;
+
+    impl Solution for Numbers {
+        const YEAR: u16 = 2015;
+        const DAY: u8 = 1;
+
+        type Input = ();
+
+        fn parse(_input: &mut impl BufRead) -> Result<(), ParseError> {
+            Ok(())
+        }
+
+        fn part1(_input: &()) -> Result<Answer, ParseError> {
+            Ok(Answer::Integer(0))
+        }
+
+        fn generate(size: usize, seed: u64) -> Option<String> {
+            Some(
+                (0..size as u64)
+                    .map(|i| format!("{}\n", (i + 1) * (seed + 1) * 7))
+                    .collect(),
+            )
+        }
+    }
+
+    #[test]
+    fn check_pair_tests() {
+        check_pair::<Numbers, _>(
+            &Pair {
+                name: "sum",
+                reference: sum,
+                candidate: |input| input.lines().map(|line| line.parse::<u64>().unwrap()).sum(),
+            },
+            0..5,
+        );
+    }
+
+    #[test]
+    #[should_panic(
+        expected = "sum: disagreement on the input of size 2 with seed 7, shrunk down to\n101"
+    )]
+    fn check_pair_shrinks_tests() {
+        check_pair::<Numbers, _>(
+            &Pair {
+                name: "sum",
+                reference: sum,
+                candidate: wrong_sum,
+            },
+            0..5,
+        );
+    }
+}
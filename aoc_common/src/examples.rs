@@ -0,0 +1,238 @@
+use crate::{answers::Answer, parse::ParseError, Solution};
+use std::{
+    fmt::Display,
+    fs, io,
+    path::{Path, PathBuf},
+    str::FromStr,
+};
+
+// The examples from the puzzle descriptions, kept in each day's examples/
+// folder with one file per example. The answers that the puzzle gives for an
+// example go at the top of its file, above a line with only --- on it:
+//
+//   part1: 24000
+//   part2: 45000
+//   ---
+//   1000
+//   2000
+//
+// Answers are written like in answers.txt, and an example can leave out the
+// parts that the puzzle gives no answer for. Any other `name: value` line is
+// a parameter, for the puzzles whose examples are run with a smaller number
+// than the real input (`seconds: 1000` for the reindeer race). They're
+// handed to Solution::set_parameter before the parts run. A file without a
+// header is only an input, for the tests that need to run it some other way
+// than the parts do.
+#[derive(Debug, PartialEq, Eq)]
+pub struct Example {
+    pub path: PathBuf,
+    // By part, in the order that they're given
+    pub answers: Vec<(u8, Answer)>,
+    pub parameters: Vec<(String, String)>,
+    pub input: String,
+}
+
+impl Example {
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        let path = path.as_ref();
+
+        Example::parse(path, &fs::read_to_string(path)?).map_err(|line| {
+            io::Error::new(
+                io::ErrorKind::InvalidData,
+                format!(
+                    "{}:{}: expected `part<n>: <answer>` or `<name>: <value>`",
+                    path.display(),
+                    line
+                ),
+            )
+        })
+    }
+
+    // Every .txt in the folder, sorted by name. No folder means no examples.
+    pub fn load_all(dir: impl AsRef<Path>) -> io::Result<Vec<Self>> {
+        let mut paths: Vec<PathBuf> = match fs::read_dir(dir) {
+            Ok(entries) => entries
+                .map(|entry| entry.map(|entry| entry.path()))
+                .collect::<io::Result<_>>()?,
+            Err(e) if e.kind() == io::ErrorKind::NotFound => return Ok(Vec::new()),
+            Err(e) => return Err(e),
+        };
+        paths.retain(|path| path.extension().is_some_and(|extension| extension == "txt"));
+        paths.sort_unstable();
+
+        paths.iter().map(Example::load).collect()
+    }
+
+    pub fn answer(&self, part: u8) -> Option<&Answer> {
+        self.answers
+            .iter()
+            .find(|(p, _)| *p == part)
+            .map(|(_, answer)| answer)
+    }
+
+    // Fails with the (1-based) number of the first malformed header line
+    fn parse(path: &Path, text: &str) -> Result<Self, usize> {
+        let example = |answers, parameters, input: &str| Example {
+            path: path.to_path_buf(),
+            answers,
+            parameters,
+            input: input.to_string(),
+        };
+
+        // Everything up to the first --- has to look like a header for it to
+        // be one, an input could have a --- of its own further down
+        let Some((header, body)) = text
+            .split_once("\n---\n")
+            .or_else(|| text.strip_prefix("---\n").map(|body| ("", body)))
+        else {
+            return Ok(example(Vec::new(), Vec::new(), text));
+        };
+
+        let mut answers = Vec::new();
+        let mut parameters = Vec::new();
+        for (line, number) in header.lines().zip(1..) {
+            let line = line.trim();
+            if line.is_empty() || line.starts_with('#') {
+                continue;
+            }
+
+            let entry = line.split_once(':').filter(|(name, _)| {
+                !name.is_empty()
+                    && name
+                        .chars()
+                        .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_')
+            });
+            let Some((name, value)) = entry else {
+                // Not a header after all
+                if answers.is_empty() && parameters.is_empty() {
+                    return Ok(example(Vec::new(), Vec::new(), text));
+                }
+                return Err(number);
+            };
+
+            match name.strip_prefix("part").and_then(|part| part.parse().ok()) {
+                Some(part) => {
+                    answers.push((part, Answer::parse(&value.trim().replace("\\n", "\n"))))
+                }
+                None => parameters.push((name.to_string(), value.trim().to_string())),
+            }
+        }
+
+        Ok(example(answers, parameters, body))
+    }
+}
+
+// For Solution::set_parameter, an example's parameter as whatever it's
+// supposed to be
+pub fn parameter<T>(name: &str, value: &str) -> Result<T, String>
+where
+    T: FromStr,
+    T::Err: Display,
+{
+    value
+        .parse()
+        .map_err(|e| format!("parameter {}: {}", name, e))
+}
+
+// Used by every day's check_examples test, which runs from the day's folder.
+// All the examples are run before failing so that every wrong answer shows up
+// at once.
+pub fn check_examples<S: Solution>() {
+    let examples = Example::load_all("examples").unwrap_or_else(|e| panic!("{}", e));
+    let mut wrong = Vec::new();
+
+    examples
+        .iter()
+        .filter(|example| !example.answers.is_empty())
+        .for_each(|example| {
+            let malformed = |e: ParseError| format!("malformed input\n{}", e);
+            let input = S::parse(&mut example.input.as_bytes())
+                .map_err(malformed)
+                .and_then(|mut input| {
+                    example
+                        .parameters
+                        .iter()
+                        .try_for_each(|(name, value)| S::set_parameter(&mut input, name, value))?;
+                    Ok(input)
+                });
+
+            example.answers.iter().for_each(|(part, expected)| {
+                let answer = match (&input, part) {
+                    (Err(problem), _) => Some(Err(problem.clone())),
+                    (Ok(input), 1) => Some(S::part1(input).map_err(malformed)),
+                    (Ok(input), 2) => S::part2(input).map(|answer| answer.map_err(malformed)),
+                    _ => None,
+                };
+
+                let problem = match answer {
+                    Some(Ok(answer)) if answer.matches(expected) => return,
+                    Some(Ok(answer)) => format!("{} (expected {})", answer, expected),
+                    Some(Err(problem)) => problem,
+                    None => "no answer".to_string(),
+                };
+                wrong.push(format!(
+                    "{} part {}: {}",
+                    example.path.display(),
+                    part,
+                    problem
+                ));
+            });
+        });
+
+    assert!(wrong.is_empty(), "\n{}", wrong.join("\n"));
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::examples::*;
+
+    fn parse(text: &str) -> Result<Example, usize> {
+        Example::parse(Path::new("examples/example.txt"), text)
+    }
+
+    #[test]
+    fn parse_tests() {
+        let example =
+            parse("part1: 24000\n# from the text\npart2: CMZ\n---\n1000\n\n2000\n").unwrap();
+        assert_eq!(example.answer(1), Some(&Answer::Integer(24000)));
+        assert_eq!(example.answer(2), Some(&Answer::from("CMZ")));
+        assert_eq!(example.input, "1000\n\n2000\n");
+
+        let example = parse("part2: #.\\n.#\n---\n...\n").unwrap();
+        assert_eq!(example.answer(1), None);
+        assert_eq!(example.answer(2), Some(&Answer::parse("#.\n.#")));
+
+        let example = parse("seconds: 1000\npart1: 1120\n---\nComet\n").unwrap();
+        assert_eq!(example.answer(1), Some(&Answer::Integer(1120)));
+        assert_eq!(
+            example.parameters,
+            [("seconds".to_string(), "1000".to_string())]
+        );
+        assert_eq!(example.input, "Comet\n");
+
+        // Only an input, with a --- that isn't below a header
+        let example = parse("1 -> a\n---\n2 -> b\n").unwrap();
+        assert!(example.answers.is_empty());
+        assert_eq!(example.input, "1 -> a\n---\n2 -> b\n");
+        assert_eq!(parse("1000\n2000\n").unwrap().input, "1000\n2000\n");
+
+        assert_eq!(parse("---\n1000\n").unwrap().input, "1000\n");
+        assert_eq!(parse("part1: 5\npart 2 6\n---\n1000\n").unwrap_err(), 2);
+    }
+
+    #[test]
+    fn load_all_tests() {
+        let dir = tempfile::tempdir().unwrap();
+        fs::write(dir.path().join("b.txt"), "part1: 2\n---\nb\n").unwrap();
+        fs::write(dir.path().join("a.txt"), "part1: 1\n---\na\n").unwrap();
+        fs::write(dir.path().join("notes.md"), "not an example").unwrap();
+
+        let examples = Example::load_all(dir.path()).unwrap();
+        let inputs: Vec<&str> = examples.iter().map(|e| e.input.as_str()).collect();
+        assert_eq!(inputs, ["a\n", "b\n"]);
+
+        assert!(Example::load_all(dir.path().join("nothing"))
+            .unwrap()
+            .is_empty());
+    }
+}
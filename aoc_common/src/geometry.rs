@@ -0,0 +1,270 @@
+use nom::{character::complete::anychar, combinator::map_opt, error::Error, IResult, Parser};
+use std::ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign};
+
+// A position on a plane (or the difference between two of them). y grows
+// downwards like the rows of a map, so that going north is going up the
+// screen whichever way the puzzle draws it.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point2 {
+    pub x: i64,
+    pub y: i64,
+}
+
+// Same as Point2 but with one more dimension
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub struct Point3 {
+    pub x: i64,
+    pub y: i64,
+    pub z: i64,
+}
+
+// The four ways to go on a map, clockwise from the top
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub enum Direction {
+    North,
+    East,
+    South,
+    West,
+}
+
+impl Point2 {
+    pub const ORIGIN: Point2 = Point2::new(0, 0);
+
+    pub const CARDINALS: [Point2; 4] = [
+        Point2::new(0, -1),
+        Point2::new(1, 0),
+        Point2::new(0, 1),
+        Point2::new(-1, 0),
+    ];
+
+    // Clockwise from the top right
+    pub const DIAGONALS: [Point2; 4] = [
+        Point2::new(1, -1),
+        Point2::new(1, 1),
+        Point2::new(-1, 1),
+        Point2::new(-1, -1),
+    ];
+
+    pub const fn new(x: i64, y: i64) -> Self {
+        Point2 { x, y }
+    }
+
+    pub fn manhattan(self, other: Point2) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs()
+    }
+
+    // One step in the same direction, which is how far a knot or a king
+    // moves at a time
+    pub fn signum(self) -> Self {
+        Point2::new(self.x.signum(), self.y.signum())
+    }
+
+    pub fn neighbours4(self) -> impl Iterator<Item = Point2> {
+        Point2::CARDINALS
+            .into_iter()
+            .map(move |offset| self + offset)
+    }
+
+    pub fn neighbours8(self) -> impl Iterator<Item = Point2> {
+        Point2::CARDINALS
+            .into_iter()
+            .chain(Point2::DIAGONALS)
+            .map(move |offset| self + offset)
+    }
+}
+
+impl Point3 {
+    pub const ORIGIN: Point3 = Point3::new(0, 0, 0);
+
+    pub const FACES: [Point3; 6] = [
+        Point3::new(-1, 0, 0),
+        Point3::new(1, 0, 0),
+        Point3::new(0, -1, 0),
+        Point3::new(0, 1, 0),
+        Point3::new(0, 0, -1),
+        Point3::new(0, 0, 1),
+    ];
+
+    pub const fn new(x: i64, y: i64, z: i64) -> Self {
+        Point3 { x, y, z }
+    }
+
+    pub fn manhattan(self, other: Point3) -> i64 {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs()
+    }
+
+    pub fn dot(self, other: Point3) -> i64 {
+        self.x * other.x + self.y * other.y + self.z * other.z
+    }
+
+    // The cubes that share a face with this one
+    pub fn neighbours6(self) -> impl Iterator<Item = Point3> {
+        Point3::FACES.into_iter().map(move |offset| self + offset)
+    }
+}
+
+impl Direction {
+    pub const ALL: [Direction; 4] = [
+        Direction::North,
+        Direction::East,
+        Direction::South,
+        Direction::West,
+    ];
+
+    // Puzzles spell directions as U/D/L/R, N/S/E/W or draw them as arrows
+    pub fn parse(c: char) -> Option<Self> {
+        match c {
+            'U' | 'N' | '^' => Some(Direction::North),
+            'R' | 'E' | '>' => Some(Direction::East),
+            'D' | 'S' | 'v' => Some(Direction::South),
+            'L' | 'W' | '<' => Some(Direction::West),
+            _ => None,
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        Direction::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Direction::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn reverse(self) -> Self {
+        Direction::ALL[(self as usize + 2) % 4]
+    }
+
+    pub fn offset(self) -> Point2 {
+        Point2::CARDINALS[self as usize]
+    }
+}
+
+// For the nom parsers of the lines that start with a direction
+pub fn direction(input: &str) -> IResult<&str, Direction, Error<&str>> {
+    map_opt(anychar, Direction::parse).parse(input)
+}
+
+impl From<Direction> for Point2 {
+    fn from(direction: Direction) -> Self {
+        direction.offset()
+    }
+}
+
+macro_rules! point_ops {
+    ($point:ident { $($field:ident),+ }) => {
+        impl Add for $point {
+            type Output = $point;
+
+            fn add(self, other: $point) -> $point {
+                $point { $($field: self.$field + other.$field),+ }
+            }
+        }
+
+        impl Sub for $point {
+            type Output = $point;
+
+            fn sub(self, other: $point) -> $point {
+                $point { $($field: self.$field - other.$field),+ }
+            }
+        }
+
+        impl Mul<i64> for $point {
+            type Output = $point;
+
+            fn mul(self, k: i64) -> $point {
+                $point { $($field: self.$field * k),+ }
+            }
+        }
+
+        impl Neg for $point {
+            type Output = $point;
+
+            fn neg(self) -> $point {
+                $point { $($field: -self.$field),+ }
+            }
+        }
+
+        impl AddAssign for $point {
+            fn add_assign(&mut self, other: $point) {
+                *self = *self + other;
+            }
+        }
+
+        impl SubAssign for $point {
+            fn sub_assign(&mut self, other: $point) {
+                *self = *self - other;
+            }
+        }
+    };
+}
+
+point_ops!(Point2 { x, y });
+point_ops!(Point3 { x, y, z });
+
+#[cfg(test)]
+mod tests {
+    use crate::geometry::*;
+
+    #[test]
+    fn point_tests() {
+        let p = Point2::new(3, -4);
+
+        assert_eq!(p + Point2::new(1, 1), Point2::new(4, -3));
+        assert_eq!(p - Point2::new(1, 1), Point2::new(2, -5));
+        assert_eq!(p * 2, Point2::new(6, -8));
+        assert_eq!(-p, Point2::new(-3, 4));
+        assert_eq!(p.manhattan(Point2::ORIGIN), 7);
+        assert_eq!(p.signum(), Point2::new(1, -1));
+
+        let mut q = Point2::ORIGIN;
+        q += Direction::East.into();
+        q -= Direction::North.into();
+        assert_eq!(q, Point2::new(1, 1));
+
+        let p = Point3::new(1, 2, 3);
+
+        assert_eq!(p + p * 2 - Point3::new(3, 6, 9), Point3::ORIGIN);
+        assert_eq!(p.dot(Point3::new(1, -1, 1)), 2);
+        assert_eq!(p.manhattan(-p), 12);
+    }
+
+    #[test]
+    fn neighbours_tests() {
+        let p = Point2::new(1, 1);
+
+        assert_eq!(
+            p.neighbours4().collect::<Vec<_>>(),
+            [(1, 0), (2, 1), (1, 2), (0, 1)].map(|(x, y)| Point2::new(x, y))
+        );
+        assert_eq!(p.neighbours8().count(), 8);
+        assert!(p.neighbours8().all(|q| q.manhattan(p) <= 2 && q != p));
+
+        assert_eq!(Point3::ORIGIN.neighbours6().count(), 6);
+        assert!(Point3::ORIGIN
+            .neighbours6()
+            .all(|q| q.manhattan(Point3::ORIGIN) == 1));
+    }
+
+    #[test]
+    fn direction_tests() {
+        use Direction::*;
+
+        assert_eq!(North.turn_right(), East);
+        assert_eq!(North.turn_left(), West);
+        assert_eq!(West.turn_right(), North);
+        assert_eq!(East.reverse(), West);
+        assert!(Direction::ALL
+            .iter()
+            .all(|d| d.turn_left().turn_right() == *d && d.offset() == -d.reverse().offset()));
+
+        assert_eq!(
+            "URDL".chars().map(Direction::parse).collect::<Vec<_>>(),
+            "^>v<".chars().map(Direction::parse).collect::<Vec<_>>()
+        );
+        assert_eq!(Direction::parse('S'), Some(South));
+        assert_eq!(Direction::parse('x'), None);
+
+        assert_eq!(direction("R 4"), Ok((" 4", East)));
+        assert!(direction("4 R").is_err());
+    }
+}
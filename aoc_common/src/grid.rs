@@ -0,0 +1,382 @@
+use crate::{
+    geometry::{Direction, Point2},
+    parse::ParseError,
+};
+use ndarray::{Array2, ArrayView1, Axis};
+use nom::error::ErrorKind;
+use std::{
+    fmt,
+    io::BufRead,
+    ops::{Index, IndexMut},
+};
+
+// A rectangle of cells, the shape of most of the inputs that get drawn as a
+// map. Cells are addressed by (row, column) like in ndarray, with (0, 0) in
+// the top left corner.
+#[derive(Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    cells: Array2<T>,
+}
+
+const OFFSETS4: [(isize, isize); 4] = [(-1, 0), (0, 1), (1, 0), (0, -1)];
+
+const OFFSETS8: [(isize, isize); 8] = [
+    (-1, -1),
+    (-1, 0),
+    (-1, 1),
+    (0, 1),
+    (1, 1),
+    (1, 0),
+    (1, -1),
+    (0, -1),
+];
+
+impl<T> Grid<T> {
+    pub fn new(nrows: usize, ncols: usize, value: T) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: Array2::from_elem((nrows, ncols), value),
+        }
+    }
+
+    // One character per cell and one line per row. `cell` turns a character
+    // into a cell, characters that it doesn't know about are reported like
+    // any other malformed input, and so is a grid without any cells.
+    pub fn parse(
+        input: &mut impl BufRead,
+        mut cell: impl FnMut(char) -> Option<T>,
+    ) -> Result<Self, ParseError> {
+        let mut cells = Vec::new();
+        let mut ncols = None;
+        let mut nrows = 0;
+
+        for (line, number) in input.lines().zip(1..) {
+            let line = line?;
+            let error = |column, kind| ParseError {
+                line: number,
+                column,
+                kind,
+                text: line.clone(),
+                expected: None,
+            };
+
+            let width = line.chars().count();
+            if *ncols.get_or_insert(width) != width {
+                return Err(error(width.min(ncols.unwrap()) + 1, ErrorKind::LengthValue));
+            }
+
+            for (c, column) in line.chars().zip(1..) {
+                cells.push(cell(c).ok_or_else(|| error(column, ErrorKind::Char))?);
+            }
+            nrows += 1;
+        }
+        if cells.is_empty() {
+            return Err(ParseError::missing(1, "a grid"));
+        }
+
+        Ok(Grid {
+            cells: Array2::from_shape_vec((nrows, ncols.unwrap_or(0)), cells).unwrap(),
+        })
+    }
+
+    pub fn nrows(&self) -> usize {
+        self.cells.nrows()
+    }
+
+    pub fn ncols(&self) -> usize {
+        self.cells.ncols()
+    }
+
+    pub fn get(&self, position: (usize, usize)) -> Option<&T> {
+        self.cells.get(position)
+    }
+
+    pub fn get_mut(&mut self, position: (usize, usize)) -> Option<&mut T> {
+        self.cells.get_mut(position)
+    }
+
+    pub fn row(&self, row: usize) -> ArrayView1<'_, T> {
+        self.cells.row(row)
+    }
+
+    pub fn column(&self, column: usize) -> ArrayView1<'_, T> {
+        self.cells.column(column)
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = ArrayView1<'_, T>> {
+        self.cells.rows().into_iter()
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = ArrayView1<'_, T>> {
+        self.cells.columns().into_iter()
+    }
+
+    // For whatever ndarray can do that the grid can't
+    pub fn as_array(&self) -> &Array2<T> {
+        &self.cells
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = (usize, usize)> {
+        let ncols = self.ncols();
+
+        (0..self.nrows()).flat_map(move |row| (0..ncols).map(move |column| (row, column)))
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = ((usize, usize), &T)> {
+        self.cells.indexed_iter()
+    }
+
+    pub fn iter_mut(&mut self) -> impl Iterator<Item = ((usize, usize), &mut T)> {
+        self.cells.indexed_iter_mut()
+    }
+
+    pub fn find(&self, value: &T) -> Option<(usize, usize)>
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .find(|(_, cell)| *cell == value)
+            .map(|(position, _)| position)
+    }
+
+    pub fn find_all<'a>(&'a self, value: &'a T) -> impl Iterator<Item = (usize, usize)> + 'a
+    where
+        T: PartialEq,
+    {
+        self.iter()
+            .filter(move |(_, cell)| *cell == value)
+            .map(|(position, _)| position)
+    }
+
+    // The position that is (rows, columns) away, as long as it's still on
+    // the grid
+    pub fn offset(
+        &self,
+        (row, column): (usize, usize),
+        (rows, columns): (isize, isize),
+    ) -> Option<(usize, usize)> {
+        let row = row
+            .checked_add_signed(rows)
+            .filter(|&row| row < self.nrows())?;
+        let column = column
+            .checked_add_signed(columns)
+            .filter(|&column| column < self.ncols())?;
+
+        Some((row, column))
+    }
+
+    // Rows go down the map, so north is towards row 0
+    pub fn step(&self, position: (usize, usize), direction: Direction) -> Option<(usize, usize)> {
+        let Point2 { x, y } = direction.offset();
+
+        self.offset(position, (y as isize, x as isize))
+    }
+
+    // Up, right, down and left, leaving out the ones past the edges
+    pub fn neighbours4(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        OFFSETS4
+            .iter()
+            .filter_map(move |&offset| self.offset(position, offset))
+    }
+
+    // Same as neighbours4() but with the diagonals too, clockwise from the
+    // top left
+    pub fn neighbours8(
+        &self,
+        position: (usize, usize),
+    ) -> impl Iterator<Item = (usize, usize)> + '_ {
+        OFFSETS8
+            .iter()
+            .filter_map(move |&offset| self.offset(position, offset))
+    }
+
+    pub fn map<U>(&self, f: impl FnMut(&T) -> U) -> Grid<U> {
+        Grid {
+            cells: self.cells.map(f),
+        }
+    }
+
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        Grid {
+            cells: self.cells.t().to_owned(),
+        }
+    }
+
+    pub fn rotate_clockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let mut cells = self.cells.t().to_owned();
+        cells.invert_axis(Axis(1));
+
+        Grid { cells }
+    }
+
+    pub fn rotate_counterclockwise(&self) -> Self
+    where
+        T: Clone,
+    {
+        let mut cells = self.cells.t().to_owned();
+        cells.invert_axis(Axis(0));
+
+        Grid { cells }
+    }
+}
+
+impl<T> From<Array2<T>> for Grid<T> {
+    fn from(cells: Array2<T>) -> Self {
+        Grid { cells }
+    }
+}
+
+impl<T> Index<(usize, usize)> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, position: (usize, usize)) -> &T {
+        &self.cells[position]
+    }
+}
+
+impl<T> IndexMut<(usize, usize)> for Grid<T> {
+    fn index_mut(&mut self, position: (usize, usize)) -> &mut T {
+        &mut self.cells[position]
+    }
+}
+
+// The way the grid looked in the input, given that the cells display as
+// the characters that they were parsed from
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        self.rows().enumerate().try_for_each(|(i, row)| {
+            if i > 0 {
+                writeln!(f)?;
+            }
+            row.iter().try_for_each(|cell| write!(f, "{}", cell))
+        })
+    }
+}
+
+// One row per line as well, which reads better in the debug logs than the
+// nested arrays that ndarray prints
+impl<T: fmt::Debug> fmt::Debug for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Grid {}x{}", self.nrows(), self.ncols())?;
+        self.rows().try_for_each(|row| {
+            let cells: Vec<String> = row.iter().map(|cell| format!("{:?}", cell)).collect();
+            writeln!(f, "  {}", cells.join(" "))
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::grid::*;
+    use std::io::Cursor;
+
+    fn grid(text: &str) -> Grid<char> {
+        Grid::parse(&mut Cursor::new(text), Some).unwrap()
+    }
+
+    #[test]
+    fn parse_tests() {
+        let g = grid("#..\n.#S\n");
+
+        assert_eq!((g.nrows(), g.ncols()), (2, 3));
+        assert_eq!(g[(1, 2)], 'S');
+        assert_eq!(g.get((2, 0)), None);
+        assert_eq!(g.to_string(), "#..\n.#S");
+
+        let error = Grid::parse(&mut Cursor::new("#.\n.x\n"), |c| match c {
+            '#' | '.' => Some(c),
+            _ => None,
+        })
+        .unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.kind),
+            (2, 2, ErrorKind::Char)
+        );
+
+        let error = Grid::parse(&mut Cursor::new("#..\n.#\n"), Some).unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.kind),
+            (2, 3, ErrorKind::LengthValue)
+        );
+
+        let error = Grid::parse(&mut Cursor::new(""), Some).unwrap_err();
+        assert_eq!((error.line, error.kind), (1, ErrorKind::Eof));
+    }
+
+    #[test]
+    fn neighbours_tests() {
+        let g = grid("abc\ndef\nghi\n");
+
+        let neighbours = |position| -> String {
+            g.neighbours4(position)
+                .map(|position| g[position])
+                .collect()
+        };
+        assert_eq!(neighbours((1, 1)), "bfhd");
+        assert_eq!(neighbours((0, 0)), "bd");
+        assert_eq!(neighbours((2, 2)), "fh");
+
+        let neighbours = |position| -> String {
+            g.neighbours8(position)
+                .map(|position| g[position])
+                .collect()
+        };
+        assert_eq!(neighbours((1, 1)), "abcfihgd");
+        assert_eq!(neighbours((0, 2)), "feb");
+
+        assert_eq!(g.offset((0, 0), (2, 2)), Some((2, 2)));
+        assert_eq!(g.offset((0, 0), (3, 0)), None);
+        assert_eq!(g.offset((0, 0), (0, -1)), None);
+
+        assert_eq!(g.step((1, 1), Direction::North), Some((0, 1)));
+        assert_eq!(g.step((1, 1), Direction::East), Some((1, 2)));
+        assert_eq!(g.step((2, 1), Direction::South), None);
+    }
+
+    #[test]
+    fn views_tests() {
+        let g = grid("abc\ndef\n");
+
+        assert_eq!(g.row(1).iter().collect::<String>(), "def");
+        assert_eq!(g.column(2).iter().collect::<String>(), "cf");
+        assert_eq!(g.columns().count(), 3);
+        assert_eq!(g.positions().last(), Some((1, 2)));
+
+        assert_eq!(g.find(&'e'), Some((1, 1)));
+        assert_eq!(g.find(&'x'), None);
+        assert_eq!(
+            grid("#.\n.#\n").find_all(&'#').collect::<Vec<_>>(),
+            [(0, 0), (1, 1)]
+        );
+    }
+
+    #[test]
+    fn transform_tests() {
+        let g = grid("abc\ndef\n");
+
+        assert_eq!(g.transpose().to_string(), "ad\nbe\ncf");
+        assert_eq!(g.rotate_clockwise().to_string(), "da\neb\nfc");
+        assert_eq!(g.rotate_counterclockwise().to_string(), "cf\nbe\nad");
+        assert_eq!(g.rotate_clockwise().rotate_counterclockwise(), g);
+        assert_eq!(g.map(|c| c.to_ascii_uppercase()).to_string(), "ABC\nDEF");
+    }
+
+    #[test]
+    fn debug_tests() {
+        assert_eq!(
+            format!("{:?}", grid("ab\ncd\n")),
+            "Grid 2x2\n  'a' 'b'\n  'c' 'd'\n"
+        );
+    }
+}
@@ -0,0 +1,181 @@
+pub mod answers;
+pub mod cli;
+pub mod cycle;
+pub mod differential;
+pub mod examples;
+pub mod geometry;
+pub mod grid;
+pub mod math;
+pub mod memory;
+pub mod ocr;
+pub mod parse;
+pub mod profiles;
+pub mod ranges;
+pub mod report;
+pub mod search;
+pub mod sparse_grid;
+
+use answers::Answer;
+use clap::Parser;
+use log::info;
+use parse::ParseError;
+use std::{any::Any, io::BufRead, process::ExitCode};
+
+pub use report::{PartResult, Report};
+
+// Every day implements this so that the puzzles can all be driven the same
+// way, instead of each one carrying its own copy of main().
+//
+// The input is parsed once, into whatever suits the puzzle, and the result is
+// handed to both parts.
+pub trait Solution {
+    const YEAR: u16;
+    const DAY: u8;
+
+    type Input;
+
+    fn parse(input: &mut impl BufRead) -> Result<Self::Input, ParseError>;
+
+    // The parts can still fail on inputs that parse but make no sense for the
+    // puzzle
+    fn part1(input: &Self::Input) -> Result<Answer, ParseError>;
+
+    // Day 25 only has one part, and some days have a second part that I
+    // haven't gotten around to solving yet.
+    fn part2(_input: &Self::Input) -> Option<Result<Answer, ParseError>> {
+        None
+    }
+
+    // The examples whose puzzle runs them with a smaller number than the real
+    // input give it in their header (see examples::Example). The input keeps
+    // the real number, which this replaces.
+    fn set_parameter(_input: &mut Self::Input, name: &str, _value: &str) -> Result<(), String> {
+        Err(format!("unknown parameter {}", name))
+    }
+
+    // A random input for stress and performance testing, for the days that
+    // have a generator. What `size` counts is up to the puzzle (lines, wires,
+    // the side of a grid...), and the same seed always gives the same input.
+    fn generate(_size: usize, _seed: u64) -> Option<String> {
+        None
+    }
+}
+
+// For the days whose input is one piece of text rather than lines
+pub fn read_input(input: &mut impl BufRead) -> Result<String, ParseError> {
+    let mut text = String::new();
+    input.read_to_string(&mut text)?;
+    Ok(text)
+}
+
+// What was passed to panic!(), which is nearly always some text
+pub fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_default()
+}
+
+// The main() of every day. Which input is solved and which parts are run
+// comes from the command line, see cli::Args.
+pub fn run<S: Solution>() -> ExitCode {
+    env_logger::init();
+
+    let args = cli::Args::parse();
+
+    let (mut reader, parameters) = match args.open() {
+        Ok(opened) => opened,
+        Err(e) => {
+            eprintln!("{}: {}", args.input_name(), e);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let report = solve::<S>(&mut reader, &parameters, &args.parts());
+
+    for result in report.parts {
+        match result.answer {
+            Some(Ok(answer)) => info!("Part {} answer: {}", result.part, answer),
+            Some(Err(e)) => {
+                eprintln!("{}: {}", args.input_name(), e);
+                return ExitCode::FAILURE;
+            }
+            None => {}
+        }
+    }
+
+    ExitCode::SUCCESS
+}
+
+// The runner keeps all the days in one list, which can't be done with the
+// Solution trait directly because of its associated type. Puzzle erases it.
+#[derive(Clone, Copy)]
+pub struct Puzzle {
+    pub year: u16,
+    pub day: u8,
+    solve: fn(&mut dyn BufRead, &[u8]) -> Report,
+    generate: fn(usize, u64) -> Option<String>,
+}
+
+impl Puzzle {
+    pub fn new<S: Solution>() -> Self {
+        Puzzle {
+            year: S::YEAR,
+            day: S::DAY,
+            solve: |input, parts| solve::<S>(input, &[], parts),
+            generate: S::generate,
+        }
+    }
+
+    pub fn solve(&self, input: &mut dyn BufRead, parts: &[u8]) -> Report {
+        (self.solve)(input, parts)
+    }
+
+    pub fn generate(&self, size: usize, seed: u64) -> Option<String> {
+        (self.generate)(size, seed)
+    }
+}
+
+// An input that can't be parsed is the answer to every part that was asked
+// for, there's nothing else to tell about them. The same goes for an
+// example's parameter that the day won't take.
+fn solve<S: Solution>(
+    mut input: &mut dyn BufRead,
+    parameters: &[(String, String)],
+    parts: &[u8],
+) -> Report {
+    let (parsed, parse) = memory::measure(|| -> Result<S::Input, ParseError> {
+        let mut parsed = S::parse(&mut input)?;
+        parameters
+            .iter()
+            .try_for_each(|(name, value)| S::set_parameter(&mut parsed, name, value))
+            .map_err(ParseError::bad_parameter)?;
+        Ok(parsed)
+    });
+
+    let parts = parts
+        .iter()
+        .map(|&part| {
+            let (answer, measurement) = memory::measure(|| match (&parsed, part) {
+                (Err(e), _) => Some(Err(e.clone())),
+                (Ok(parsed), 1) => Some(S::part1(parsed)),
+                (Ok(parsed), 2) => S::part2(parsed),
+                _ => None,
+            });
+
+            PartResult {
+                part,
+                answer,
+                measurement,
+            }
+        })
+        .collect();
+
+    Report {
+        year: S::YEAR,
+        day: S::DAY,
+        parse,
+        parts,
+    }
+}
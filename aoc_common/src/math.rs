@@ -0,0 +1,162 @@
+// The bits of number theory that keep coming back: things that line up
+// every so often, things that wrap around a modulus, and things that are
+// shared out between the divisors of a number.
+
+pub fn gcd(mut a: u64, mut b: u64) -> u64 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+
+    a
+}
+
+pub fn lcm(a: u64, b: u64) -> u64 {
+    if a == 0 || b == 0 {
+        0
+    } else {
+        a / gcd(a, b) * b
+    }
+}
+
+// The gcd of no numbers at all is 0, which divides nothing but itself
+pub fn gcd_all(numbers: impl IntoIterator<Item = u64>) -> u64 {
+    numbers.into_iter().fold(0, gcd)
+}
+
+// When things that each come around every so often all come around
+// together. The lcm of no numbers at all is 1.
+pub fn lcm_all(numbers: impl IntoIterator<Item = u64>) -> u64 {
+    numbers.into_iter().fold(1, lcm)
+}
+
+// (g, x, y) such that a * x + b * y = g = gcd(a, b)
+fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+    if b == 0 {
+        (a, 1, 0)
+    } else {
+        let (g, x, y) = extended_gcd(b, a % b);
+        (g, y, x - a / b * y)
+    }
+}
+
+// base^exponent % modulus without going through the huge number in between
+pub fn modpow(base: u64, mut exponent: u64, modulus: u64) -> u64 {
+    let modulus = modulus as u128;
+    let mut base = base as u128 % modulus;
+    let mut result = 1 % modulus;
+
+    while exponent > 0 {
+        if exponent & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exponent >>= 1;
+    }
+
+    result as u64
+}
+
+// The x in 0..modulus such that a * x % modulus == 1, if there is one (there
+// is when a and the modulus have no common factor)
+pub fn mod_inverse(a: i64, modulus: i64) -> Option<i64> {
+    let (g, x, _) = extended_gcd((a as i128).rem_euclid(modulus as i128), modulus as i128);
+
+    (g == 1).then(|| x.rem_euclid(modulus as i128) as i64)
+}
+
+// The numbers x such that x % modulus == remainder for each of the
+// (remainder, modulus) pairs, given as a single (remainder, modulus) pair
+// with the remainder in 0..modulus. The moduli don't have to be coprime, but
+// then the remainders may not agree with each other and there's no such x.
+pub fn crt(congruences: impl IntoIterator<Item = (i64, i64)>) -> Option<(i64, i64)> {
+    congruences
+        .into_iter()
+        .try_fold((0, 1), |(r1, m1), (r2, m2)| {
+            let (r1, m1, r2, m2) = (r1 as i128, m1 as i128, r2 as i128, m2 as i128);
+            let (g, p, _) = extended_gcd(m1, m2);
+
+            if (r2 - r1) % g != 0 {
+                return None;
+            }
+
+            // m1 * p = g (mod m2), so going (r2 - r1) / g * p times m1 from r1
+            // lands on r2 too
+            let m = m1 / g * m2;
+            let k = ((r2 - r1) / g * p).rem_euclid(m2 / g);
+            let r = (r1 + m1 * k).rem_euclid(m);
+
+            Some((r as i64, i64::try_from(m).ok()?))
+        })
+}
+
+// sums[n] is the sum of the divisors of n, for every n up to `limit`. This
+// goes through the multiples of every number, which is a lot quicker than
+// looking for the divisors of every number.
+pub fn divisor_sums(limit: usize) -> Vec<u64> {
+    divisor_sums_by(limit, |divisor, _| divisor)
+}
+
+// Same as divisor_sums() but each divisor d of n counts for f(d, n)
+pub fn divisor_sums_by(limit: usize, f: impl Fn(u64, u64) -> u64) -> Vec<u64> {
+    let mut sums = vec![0; limit + 1];
+
+    (1..=limit).for_each(|divisor| {
+        (divisor..=limit).step_by(divisor).for_each(|n| {
+            sums[n] += f(divisor as u64, n as u64);
+        })
+    });
+
+    sums
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::math::*;
+
+    #[test]
+    fn gcd_tests() {
+        assert_eq!(gcd(12, 18), 6);
+        assert_eq!(gcd(7, 0), 7);
+        assert_eq!(lcm(4, 6), 12);
+        assert_eq!(lcm(0, 6), 0);
+
+        assert_eq!(gcd_all([12, 18, 27]), 3);
+        assert_eq!(lcm_all([2, 3, 4, 5]), 60);
+        assert_eq!(lcm_all([]), 1);
+    }
+
+    #[test]
+    fn modular_tests() {
+        assert_eq!(modpow(2, 10, 1000), 24);
+        assert_eq!(modpow(3, 0, 7), 1);
+        assert_eq!(modpow(5, 3, 1), 0);
+        assert_eq!(modpow(u64::MAX, 2, u64::MAX - 1), 1);
+
+        assert_eq!(mod_inverse(3, 7), Some(5));
+        assert_eq!(mod_inverse(-3, 7), Some(2));
+        assert_eq!(mod_inverse(4, 8), None);
+    }
+
+    #[test]
+    fn crt_tests() {
+        assert_eq!(crt([(2, 3), (3, 5), (2, 7)]), Some((23, 105)));
+        assert_eq!(crt([(-1, 4)]), Some((3, 4)));
+        assert_eq!(crt([]), Some((0, 1)));
+
+        // Moduli with a common factor
+        assert_eq!(crt([(2, 4), (4, 6)]), Some((10, 12)));
+        assert_eq!(crt([(1, 4), (2, 6)]), None);
+    }
+
+    #[test]
+    fn divisor_sums_tests() {
+        let sums = divisor_sums(12);
+
+        assert_eq!(sums[1..], [1, 3, 4, 7, 6, 12, 8, 15, 13, 18, 12, 28]);
+        assert_eq!(sums[0], 0);
+
+        // Only the divisors that are at least half the number
+        let sums = divisor_sums_by(12, |d, n| if n / d <= 2 { d } else { 0 });
+        assert_eq!(sums[12], 12 + 6);
+    }
+}
@@ -0,0 +1,185 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    cell::Cell,
+    fmt,
+    sync::atomic::{AtomicBool, Ordering},
+    time::{Duration, Instant},
+};
+
+// The system allocator, keeping count of how much is allocated on the way.
+// A binary that wants to know how much memory the solutions use installs it
+// with
+//
+//   #[global_allocator]
+//   static ALLOCATOR: CountingAllocator = CountingAllocator;
+//
+// The counts are kept per thread so that solutions running side by side
+// don't get each other's allocations.
+pub struct CountingAllocator;
+
+static INSTALLED: AtomicBool = AtomicBool::new(false);
+
+thread_local! {
+    // Memory allocated by one thread and freed by another can take the count
+    // below 0
+    static ALLOCATED: Cell<isize> = const { Cell::new(0) };
+    static PEAK: Cell<isize> = const { Cell::new(0) };
+}
+
+fn count(change: isize) {
+    if !INSTALLED.load(Ordering::Relaxed) {
+        INSTALLED.store(true, Ordering::Relaxed);
+    }
+
+    // These have no destructor so they're there until the very end of the
+    // thread, try_with() is only in case
+    let _ = ALLOCATED.try_with(|allocated| {
+        let now = allocated.get() + change;
+        allocated.set(now);
+
+        let _ = PEAK.try_with(|peak| peak.set(peak.get().max(now)));
+    });
+}
+
+unsafe impl GlobalAlloc for CountingAllocator {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            count(layout.size() as isize);
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            count(layout.size() as isize);
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        count(-(layout.size() as isize));
+    }
+
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            count(new_size as isize - layout.size() as isize);
+        }
+        new_ptr
+    }
+}
+
+// How long something took and the most memory it had allocated at any one
+// time on top of what was allocated before it started. The memory is only
+// known when the CountingAllocator is installed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Measurement {
+    pub elapsed: Duration,
+    pub peak_memory: Option<usize>,
+}
+
+// Like "1.2ms, 3.4 MiB", or only the time when the memory wasn't counted
+impl fmt::Display for Measurement {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.peak_memory {
+            Some(bytes) => write!(f, "{:?}, {}", self.elapsed, format_bytes(bytes)),
+            None => write!(f, "{:?}", self.elapsed),
+        }
+    }
+}
+
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Measurement) {
+    let baseline = ALLOCATED.with(Cell::get);
+    PEAK.with(|peak| peak.set(baseline));
+
+    let start = Instant::now();
+    let result = f();
+    let elapsed = start.elapsed();
+
+    let peak = PEAK.with(Cell::get);
+    let peak_memory = INSTALLED
+        .load(Ordering::Relaxed)
+        .then(|| (peak - baseline).max(0) as usize);
+
+    (
+        result,
+        Measurement {
+            elapsed,
+            peak_memory,
+        },
+    )
+}
+
+// Sizes in the largest unit that keeps them at 1 or more
+pub fn format_bytes(bytes: usize) -> String {
+    const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+
+    if bytes < 1024 {
+        return format!("{} B", bytes);
+    }
+
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+
+    format!("{:.1} {}", size, UNITS[unit])
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::memory::*;
+
+    // The tests get the allocator too, which is the only way to see it count
+    #[global_allocator]
+    static ALLOCATOR: CountingAllocator = CountingAllocator;
+
+    #[test]
+    fn measure_tests() {
+        let (sum, measurement) = measure(|| {
+            let numbers: Vec<u64> = (0..1000).collect();
+            numbers.iter().sum::<u64>()
+        });
+
+        assert_eq!(sum, 499500);
+        assert!(measurement.peak_memory.unwrap() >= 8000);
+
+        // What was allocated before and is still around doesn't count, nor
+        // does anything that's freed without being allocated again
+        let kept = vec![0u8; 100_000];
+        let (_, measurement) = measure(|| drop(kept));
+        assert_eq!(measurement.peak_memory, Some(0));
+
+        // The other threads' allocations aren't this one's business
+        let (_, measurement) = measure(|| {
+            std::thread::spawn(|| vec![0u8; 1_000_000].len())
+                .join()
+                .unwrap()
+        });
+        assert!(measurement.peak_memory.unwrap() < 1_000_000);
+    }
+
+    #[test]
+    fn format_bytes_tests() {
+        assert_eq!(format_bytes(0), "0 B");
+        assert_eq!(format_bytes(1023), "1023 B");
+        assert_eq!(format_bytes(1536), "1.5 KiB");
+        assert_eq!(format_bytes(5 * 1024 * 1024), "5.0 MiB");
+
+        let measurement = Measurement {
+            elapsed: Duration::from_millis(3),
+            peak_memory: Some(2048),
+        };
+        assert_eq!(measurement.to_string(), "3ms, 2.0 KiB");
+        let measurement = Measurement {
+            peak_memory: None,
+            ..measurement
+        };
+        assert_eq!(measurement.to_string(), "3ms");
+    }
+}
@@ -0,0 +1,216 @@
+use ndarray::Array2;
+use std::{error, fmt};
+
+// The block letters that some puzzles draw on a screen, with '#' for the lit
+// pixels. Every letter takes up a cell of the same width, which includes the
+// blank columns that separate it from the next one (only the small Y spills
+// into them).
+struct Font {
+    cell_width: usize,
+    letters: &'static str,
+    // All the letters side by side, one string per row
+    glyphs: &'static [&'static str],
+}
+
+const SMALL: Font = Font {
+    cell_width: 5,
+    letters: "ABCEFGHIJKLOPRSUYZ",
+    glyphs: &[
+        ".##..###...##..####.####..##..#..#..###...##.#..#.#.....##..###..###...###.#..#.#...#####.",
+        "#..#.#..#.#..#.#....#....#..#.#..#...#.....#.#.#..#....#..#.#..#.#..#.#....#..#.#...#...#.",
+        "#..#.###..#....###..###..#....####...#.....#.##...#....#..#.#..#.#..#.#....#..#..#.#...#..",
+        "####.#..#.#....#....#....#.##.#..#...#.....#.#.#..#....#..#.###..###...##..#..#...#...#...",
+        "#..#.#..#.#..#.#....#....#..#.#..#...#..#..#.#.#..#....#..#.#....#.#.....#.#..#...#..#....",
+        "#..#.###...##..####.#.....###.#..#..###..##..#..#.####..##..#....#..#.###...##....#..####.",
+    ],
+};
+
+const LARGE: Font = Font {
+    cell_width: 8,
+    letters: "ABCEFGHJKLNPRXZ",
+    glyphs: &[
+        "..##....#####....####...######..######...####...#....#.....###..#....#..#.......#....#..#####...#####...#....#..######..",
+        ".#..#...#....#..#....#..#.......#.......#....#..#....#......#...#...#...#.......##...#..#....#..#....#..#....#.......#..",
+        "#....#..#....#..#.......#.......#.......#.......#....#......#...#..#....#.......##...#..#....#..#....#...#..#........#..",
+        "#....#..#....#..#.......#.......#.......#.......#....#......#...#.#.....#.......#.#..#..#....#..#....#...#..#.......#...",
+        "#....#..#####...#.......#####...#####...#.......######......#...##......#.......#.#..#..#####...#####.....##.......#....",
+        "######..#....#..#.......#.......#.......#..###..#....#......#...##......#.......#..#.#..#.......#..#......##......#.....",
+        "#....#..#....#..#.......#.......#.......#....#..#....#......#...#.#.....#.......#..#.#..#.......#...#....#..#....#......",
+        "#....#..#....#..#.......#.......#.......#....#..#....#..#...#...#..#....#.......#...##..#.......#...#....#..#...#.......",
+        "#....#..#....#..#....#..#.......#.......#...##..#....#..#...#...#...#...#.......#...##..#.......#....#..#....#..#.......",
+        "#....#..#####....####...######..#........###.#..#....#...###....#....#..######..#....#..#.......#....#..#....#..######..",
+    ],
+};
+
+impl Font {
+    fn letter(&self, cell: &[String]) -> Option<char> {
+        let width = self.cell_width;
+
+        self.letters
+            .chars()
+            .enumerate()
+            .find(|(i, _)| {
+                cell.iter()
+                    .zip(self.glyphs)
+                    .all(|(row, glyphs)| row == &glyphs[i * width..(i + 1) * width])
+            })
+            .map(|(_, letter)| letter)
+    }
+}
+
+#[derive(Debug, PartialEq, Eq)]
+pub enum OcrError {
+    // Only letters that are 6 or 10 pixels tall are known
+    Height(usize),
+    // Where the letter starts and what its cell looks like
+    Unknown { column: usize, cell: Vec<String> },
+}
+
+impl fmt::Display for OcrError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            OcrError::Height(height) => {
+                write!(f, "no letters are known that are {} pixels tall", height)
+            }
+            OcrError::Unknown { column, cell } => {
+                write!(f, "unknown letter at column {}:", column)?;
+                cell.iter().try_for_each(|row| write!(f, "\n{}", row))
+            }
+        }
+    }
+}
+
+impl error::Error for OcrError {}
+
+// Reads the letters on a screen, given one row of pixels at a time
+pub fn read<R: IntoIterator<Item = char>>(
+    rows: impl IntoIterator<Item = R>,
+) -> Result<String, OcrError> {
+    let rows: Vec<Vec<bool>> = rows
+        .into_iter()
+        .map(|row| row.into_iter().map(|pixel| pixel == '#').collect())
+        .collect();
+
+    let font = match rows.len() {
+        6 => &SMALL,
+        10 => &LARGE,
+        height => return Err(OcrError::Height(height)),
+    };
+
+    // Blank columns at the end would otherwise be read as one more letter
+    let width = rows
+        .iter()
+        .filter_map(|row| row.iter().rposition(|&lit| lit))
+        .max()
+        .map_or(0, |column| column + 1);
+
+    (0..width)
+        .step_by(font.cell_width)
+        .map(|column| {
+            let cell: Vec<String> = rows
+                .iter()
+                .map(|row| {
+                    (column..column + font.cell_width)
+                        .map(|x| match row.get(x) {
+                            Some(true) => '#',
+                            _ => '.',
+                        })
+                        .collect()
+                })
+                .collect();
+
+            font.letter(&cell).ok_or(OcrError::Unknown { column, cell })
+        })
+        .collect()
+}
+
+pub fn read_array(screen: &Array2<char>) -> Result<String, OcrError> {
+    read(
+        screen
+            .rows()
+            .into_iter()
+            .map(|row| row.iter().copied().collect::<Vec<_>>()),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ocr::*;
+
+    #[test]
+    fn small_tests() {
+        let screen = [
+            "####.####.####..##..#..#...##..##..###..",
+            "#.......#.#....#..#.#..#....#.#..#.#..#.",
+            "###....#..###..#....####....#.#..#.###..",
+            "#.....#...#....#....#..#....#.####.#..#.",
+            "#....#....#....#..#.#..#.#..#.#..#.#..#.",
+            "####.####.#.....##..#..#..##..#..#.###..",
+        ];
+
+        assert_eq!(read(screen.map(str::chars)).unwrap(), "EZFCHJAB");
+
+        // Y is wider than the other letters, and the screen may be cut short
+        let screen = [
+            "#...#.###",
+            "#...#..#.",
+            ".#.#...#.",
+            "..#....#.",
+            "..#....#.",
+            "..#...###",
+        ];
+
+        assert_eq!(read(screen.map(str::chars)).unwrap(), "YI");
+    }
+
+    #[test]
+    fn large_tests() {
+        let screen = [
+            "#....#..######....",
+            "#....#.......#....",
+            ".#..#........#....",
+            ".#..#.......#.....",
+            "..##.......#......",
+            "..##......#.......",
+            ".#..#....#........",
+            ".#..#...#.........",
+            "#....#..#.........",
+            "#....#..######....",
+        ];
+
+        assert_eq!(read(screen.map(str::chars)).unwrap(), "XZ");
+
+        let screen = Array2::from_shape_vec((10, 18), screen.concat().chars().collect()).unwrap();
+
+        assert_eq!(read_array(&screen).unwrap(), "XZ");
+    }
+
+    #[test]
+    fn error_tests() {
+        assert_eq!(read(["#..#".chars()]), Err(OcrError::Height(1)));
+
+        let screen = [
+            ".##..#..#.",
+            "#..#.#..#.",
+            "#..#.#.##.",
+            "####.##.#.",
+            "#..#.#..#.",
+            "#..#.#..#.",
+        ];
+        let error = read(screen.map(str::chars)).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            [
+                "unknown letter at column 5:",
+                "#..#.",
+                "#..#.",
+                "#.##.",
+                "##.#.",
+                "#..#.",
+                "#..#.",
+            ]
+            .join("\n")
+        );
+    }
+}
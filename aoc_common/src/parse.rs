@@ -0,0 +1,439 @@
+use nom::{bytes::complete::tag, combinator::all_consuming, error::ErrorKind, Parser};
+use std::{
+    fmt,
+    io::{self, BufRead},
+};
+
+// What went wrong while parsing an input and where, so that a malformed
+// input can be fixed without having to step through the parser.
+//
+// Lines and columns start at 1, like in every editor. The errors that aren't
+// about any one line (an input that couldn't be read at all, a puzzle that
+// has no answer for it) are at line 0, and their text is the whole message.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    pub kind: ErrorKind,
+    // The line that failed to parse, to point at in the diagnostic
+    pub text: String,
+    // The text that should have been there, when it's known
+    pub expected: Option<&'static str>,
+}
+
+impl ParseError {
+    // Locates a nom error in the text that was handed to the parser. The
+    // error holds whatever input was left when the parser gave up, so its
+    // position is how much of the text had been consumed by then.
+    pub fn new(text: &str, error: nom::Err<nom::error::Error<&str>>) -> Self {
+        let (offset, kind) = match error {
+            nom::Err::Error(e) | nom::Err::Failure(e) => (text.len() - e.input.len(), e.code),
+            nom::Err::Incomplete(_) => (text.len(), ErrorKind::Complete),
+        };
+
+        let start = text[..offset].rfind('\n').map_or(0, |newline| newline + 1);
+        let end = text[start..]
+            .find('\n')
+            .map_or(text.len(), |newline| start + newline);
+
+        ParseError {
+            line: text[..offset].matches('\n').count() + 1,
+            column: offset - start + 1,
+            kind,
+            text: text[start..end].to_string(),
+            expected: None,
+        }
+    }
+
+    // For errors from parsers that were only given one line of the input
+    pub fn at_line(self, line: usize) -> Self {
+        ParseError { line, ..self }
+    }
+
+    pub fn expecting(self, expected: &'static str) -> Self {
+        ParseError {
+            expected: Some(expected),
+            ..self
+        }
+    }
+
+    // For an input that ends before something it should have had, like the
+    // path under the map. The line is where that should have started.
+    pub fn missing(line: usize, what: &'static str) -> Self {
+        ParseError {
+            line,
+            column: 1,
+            kind: ErrorKind::Eof,
+            text: String::new(),
+            expected: Some(what),
+        }
+    }
+
+    // For the parts, when the input parses but the puzzle has no answer for
+    // it
+    pub fn no_answer(reason: impl fmt::Display) -> Self {
+        ParseError {
+            line: 0,
+            column: 0,
+            kind: ErrorKind::Fail,
+            text: format!("no answer: {}", reason),
+            expected: None,
+        }
+    }
+
+    // For an example whose parameter the day doesn't take, or can't make
+    // sense of (see Solution::set_parameter)
+    pub fn bad_parameter(problem: String) -> Self {
+        ParseError {
+            line: 0,
+            column: 0,
+            kind: ErrorKind::Fail,
+            text: problem,
+            expected: None,
+        }
+    }
+}
+
+impl From<io::Error> for ParseError {
+    fn from(error: io::Error) -> Self {
+        ParseError {
+            line: 0,
+            column: 0,
+            kind: ErrorKind::Fail,
+            text: format!("couldn't read the input: {}", error),
+            expected: None,
+        }
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if self.line == 0 {
+            return write!(f, "{}", self.text);
+        }
+
+        let gutter = " ".repeat(self.line.to_string().len());
+
+        write!(f, "line {}, column {}: ", self.line, self.column)?;
+        match (self.kind, self.expected) {
+            (ErrorKind::Eof, Some(what)) => writeln!(f, "missing {}", what)?,
+            (_, Some(expected)) => writeln!(f, "expected {:?}", expected)?,
+            (_, None) => writeln!(f, "{}", self.kind.description())?,
+        }
+        writeln!(f, "{} |", gutter)?;
+        writeln!(f, "{} | {}", self.line, self.text)?;
+        write!(f, "{} | {}^", gutter, " ".repeat(self.column - 1))
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+// Runs the parser on the whole text, anything left over is an error too
+pub fn parse<'a, O>(
+    text: &'a str,
+    parser: impl Parser<&'a str, Output = O, Error = nom::error::Error<&'a str>>,
+) -> Result<O, ParseError> {
+    all_consuming(parser)
+        .parse(text)
+        .map(|(_, output)| output)
+        .map_err(|e| ParseError::new(text, e))
+}
+
+// For the inputs with one item per line. The parser only sees one line at a
+// time so the line numbers are filled in here. The items are collected into
+// whatever the caller asks for, like with Iterator::collect.
+pub fn parse_lines<T, C: FromIterator<T>>(
+    input: &mut impl BufRead,
+    mut parse_line: impl FnMut(&str) -> Result<T, ParseError>,
+) -> Result<C, ParseError> {
+    input
+        .lines()
+        .zip(1..)
+        .map(|(line, number)| parse_line(&line?).map_err(|e| e.at_line(number)))
+        .collect()
+}
+
+// Like parse_lines, for the puzzles that need at least one line of whatever
+// the input is a list of. `what` is that one line, for the error.
+pub fn parse_lines1<T, C: FromIterator<T>>(
+    input: &mut impl BufRead,
+    what: &'static str,
+    mut parse_line: impl FnMut(&str) -> Result<T, ParseError>,
+) -> Result<C, ParseError> {
+    let mut lines = 0;
+    let items = parse_lines(input, |line| {
+        lines += 1;
+        parse_line(line)
+    })?;
+
+    match lines {
+        0 => Err(ParseError::missing(1, what)),
+        _ => Ok(items),
+    }
+}
+
+// Pulls the values out of a line that is mostly the same sentence over and
+// over, like "Comet can fly 14 km/s for 10 seconds". The line is described
+// by its pieces: the literal text and, in braces, the values in between.
+//
+//   scan!(line, {alpha1} " can fly " {speed: u32} " km/s for " {u32} " seconds")
+//
+// A bare name in braces is one of nom's character parsers (u32, i64, alpha1,
+// ...), anything else is any nom parser, like
+// `{separated_list1(space1, complete::u64)}`. A value can be given a name
+// with `{name: parser}` and left out with `{_: parser}`. The values come out
+// as a tuple (or on their own if there's only one), or as whatever comes
+// after a `=>`, which can use the named values:
+//
+//   scan!(line, "x=" {x: i64} ", y=" {y: i64} => Point2::new(x, y))
+//
+// The result is a Result with a ParseError that says which literal text was
+// expected when that's where the line didn't match, and the whole text has
+// to match.
+#[macro_export]
+macro_rules! scan {
+    // The pieces are matched in a closure so that the ? in there don't
+    // return from whatever function scan!() is used in
+    ($text:expr, $($pieces:tt)+) => {{
+        let text: &str = $text;
+        (|| {
+            let input = text;
+            $crate::scan!(@pieces text, input; (); $($pieces)+)
+        })()
+    }};
+
+    (@pieces $text:ident, $input:ident; ($($value:ident)*); => $output:expr) => {{
+        $crate::parse::scan_end($text, $input)?;
+        Ok::<_, $crate::parse::ParseError>($output)
+    }};
+    (@pieces $text:ident, $input:ident; (); ) => {{
+        $crate::parse::scan_end($text, $input)?;
+        Ok::<_, $crate::parse::ParseError>(())
+    }};
+    (@pieces $text:ident, $input:ident; ($value:ident); ) => {{
+        $crate::parse::scan_end($text, $input)?;
+        Ok::<_, $crate::parse::ParseError>($value)
+    }};
+    (@pieces $text:ident, $input:ident; ($($value:ident)*); ) => {{
+        $crate::parse::scan_end($text, $input)?;
+        Ok::<_, $crate::parse::ParseError>(($($value),*))
+    }};
+
+    (@pieces $text:ident, $input:ident; ($($value:ident)*); $literal:literal $($rest:tt)*) => {{
+        let $input = $crate::parse::scan_literal($text, $input, $literal)?;
+        $crate::scan!(@pieces $text, $input; ($($value)*); $($rest)*)
+    }};
+
+    (@pieces $text:ident, $input:ident; ($($value:ident)*); {_: $parser:ident} $($rest:tt)*) => {{
+        let ($input, _) = $crate::parse::scan_value($text, $input, $crate::parse::character::$parser)?;
+        $crate::scan!(@pieces $text, $input; ($($value)*); $($rest)*)
+    }};
+    (@pieces $text:ident, $input:ident; ($($value:ident)*); {_: $parser:expr} $($rest:tt)*) => {{
+        let ($input, _) = $crate::parse::scan_value($text, $input, $parser)?;
+        $crate::scan!(@pieces $text, $input; ($($value)*); $($rest)*)
+    }};
+    (@pieces $text:ident, $input:ident; ($($value:ident)*); {$name:ident: $parser:ident} $($rest:tt)*) => {{
+        let ($input, $name) = $crate::parse::scan_value($text, $input, $crate::parse::character::$parser)?;
+        $crate::scan!(@pieces $text, $input; ($($value)* $name); $($rest)*)
+    }};
+    (@pieces $text:ident, $input:ident; ($($value:ident)*); {$name:ident: $parser:expr} $($rest:tt)*) => {{
+        let ($input, $name) = $crate::parse::scan_value($text, $input, $parser)?;
+        $crate::scan!(@pieces $text, $input; ($($value)* $name); $($rest)*)
+    }};
+    // Each expansion of the macro has its own `value`, so these don't clash
+    (@pieces $text:ident, $input:ident; ($($value:ident)*); {$parser:ident} $($rest:tt)*) => {{
+        let ($input, value) = $crate::parse::scan_value($text, $input, $crate::parse::character::$parser)?;
+        $crate::scan!(@pieces $text, $input; ($($value)* value); $($rest)*)
+    }};
+    (@pieces $text:ident, $input:ident; ($($value:ident)*); {$parser:expr} $($rest:tt)*) => {{
+        let ($input, value) = $crate::parse::scan_value($text, $input, $parser)?;
+        $crate::scan!(@pieces $text, $input; ($($value)* value); $($rest)*)
+    }};
+}
+
+// What scan!() expands to, it's not meant to be used on its own
+#[doc(hidden)]
+pub use nom::character::complete as character;
+
+#[doc(hidden)]
+pub fn scan_literal<'a>(
+    text: &'a str,
+    input: &'a str,
+    literal: &'static str,
+) -> Result<&'a str, ParseError> {
+    tag::<_, _, nom::error::Error<&str>>(literal)
+        .parse(input)
+        .map(|(input, _)| input)
+        .map_err(|e| ParseError::new(text, e).expecting(literal))
+}
+
+#[doc(hidden)]
+pub fn scan_value<'a, O>(
+    text: &'a str,
+    input: &'a str,
+    mut parser: impl Parser<&'a str, Output = O, Error = nom::error::Error<&'a str>>,
+) -> Result<(&'a str, O), ParseError> {
+    parser.parse(input).map_err(|e| ParseError::new(text, e))
+}
+
+#[doc(hidden)]
+pub fn scan_end(text: &str, input: &str) -> Result<(), ParseError> {
+    if input.is_empty() {
+        Ok(())
+    } else {
+        Err(ParseError::new(
+            text,
+            nom::Err::Error(nom::error::Error::new(input, ErrorKind::Eof)),
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::parse::*;
+    use nom::{bytes::complete::tag, character::complete, sequence::separated_pair};
+    use std::io::Cursor;
+
+    fn pair(input: &str) -> Result<(u32, u32), ParseError> {
+        parse(
+            input,
+            separated_pair(complete::u32, tag(","), complete::u32),
+        )
+    }
+
+    #[test]
+    fn parse_tests() {
+        assert_eq!(pair("12,34"), Ok((12, 34)));
+
+        let error = pair("12;34").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.kind),
+            (1, 3, ErrorKind::Tag)
+        );
+
+        // Leftovers at the end of the line
+        let error = pair("12,34 ").unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.kind),
+            (1, 6, ErrorKind::Eof)
+        );
+    }
+
+    #[test]
+    fn parse_lines_tests() {
+        assert_eq!(
+            parse_lines(&mut Cursor::new("1,2\n3,4\n"), pair),
+            Ok(vec![(1, 2), (3, 4)])
+        );
+
+        let error =
+            parse_lines::<_, Vec<_>>(&mut Cursor::new("1,2\n3,4\n5,x\n"), pair).unwrap_err();
+        assert_eq!(
+            error,
+            ParseError {
+                line: 3,
+                column: 3,
+                kind: ErrorKind::Digit,
+                text: "5,x".to_string(),
+                expected: None,
+            }
+        );
+        assert_eq!(
+            error.to_string(),
+            "line 3, column 3: Digit\n  |\n3 | 5,x\n  |   ^"
+        );
+
+        // Not UTF-8, which can't even be read as lines
+        let error = parse_lines::<_, Vec<_>>(&mut Cursor::new(b"1,2\n\xff\n"), pair).unwrap_err();
+        assert_eq!(error.line, 0);
+        assert!(error.to_string().starts_with("couldn't read the input: "));
+    }
+
+    #[test]
+    fn parse_lines1_tests() {
+        assert_eq!(
+            parse_lines1(&mut Cursor::new("1,2\n"), "a pair", pair),
+            Ok(vec![(1, 2)])
+        );
+        assert_eq!(
+            parse_lines1::<_, Vec<_>>(&mut Cursor::new(""), "a pair", pair),
+            Err(ParseError::missing(1, "a pair"))
+        );
+    }
+
+    #[test]
+    fn missing_tests() {
+        assert_eq!(
+            ParseError::missing(3, "the path").to_string(),
+            "line 3, column 1: missing the path\n  |\n3 | \n  | ^"
+        );
+        assert_eq!(
+            ParseError::no_answer("there is no wire a").to_string(),
+            "no answer: there is no wire a"
+        );
+    }
+
+    #[test]
+    fn multiline_tests() {
+        let error = ParseError::new(
+            "ab\ncd\nef",
+            nom::Err::Error(nom::error::Error::new("d\nef", ErrorKind::Char)),
+        );
+
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(error.text, "cd");
+    }
+
+    #[test]
+    fn scan_tests() {
+        let reindeer =
+            |line| scan!(line, {alpha1} " can fly " {u32} " km/s for " {u32} " seconds.");
+
+        assert_eq!(
+            reindeer("Comet can fly 14 km/s for 10 seconds."),
+            Ok(("Comet", 14, 10))
+        );
+
+        let error = reindeer("Comet can run 14 km/s for 10 seconds.").unwrap_err();
+        assert_eq!((error.column, error.expected), (6, Some(" can fly ")));
+        assert_eq!(
+            error.to_string().lines().next(),
+            Some("line 1, column 6: expected \" can fly \"")
+        );
+
+        let error = reindeer("Comet can fly 14 km/s for ten seconds.").unwrap_err();
+        assert_eq!(
+            (error.column, error.kind, error.expected),
+            (27, ErrorKind::Digit, None)
+        );
+
+        let error = reindeer("Comet can fly 14 km/s for 10 seconds. Then").unwrap_err();
+        assert_eq!((error.column, error.kind), (38, ErrorKind::Eof));
+    }
+
+    #[test]
+    fn scan_outputs_tests() {
+        use nom::{character::complete::space1, multi::separated_list1};
+
+        // One value on its own, named values and values that are left out
+        let single: Result<i64, _> = scan!("x=-3", "x=" {i64});
+        assert_eq!(single, Ok(-3));
+
+        let sum = scan!("3 + 4", {a: u32} " + " {b: u32} => a + b);
+        assert_eq!(sum, Ok(7));
+
+        let list = scan!(
+            "Time:   7  15\nDistance:  9",
+            "Time:" {_: space1} {separated_list1(space1, nom::character::complete::u32)}
+            "\nDistance:" {_: space1} {u32}
+        );
+        assert_eq!(list, Ok((vec![7, 15], 9)));
+
+        // A literal that doesn't match is pointed at where it starts
+        let error = scan!("Time: 7\nDist: 9", "Time: " {u32} "\nDistance: " {u32}).unwrap_err();
+        assert_eq!(
+            (error.line, error.column, error.expected),
+            (1, 8, Some("\nDistance: "))
+        );
+    }
+}
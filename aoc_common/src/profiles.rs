@@ -0,0 +1,146 @@
+use std::{
+    fs,
+    path::{Path, PathBuf},
+};
+
+// Whose puzzle inputs to solve. Everyone gets a different input, and a
+// solution that gets one of them right can still get another one wrong.
+//
+// The default profile is the input in each day's folder, with the answers in
+// <year>/answers.txt. Any other profile keeps its inputs together:
+//
+//   inputs/<profile>/<year>/<day>          the input, with the day as 2 digits
+//   inputs/<profile>/<year>/answers.txt    in the same format as the year's
+//
+// and doesn't need to have an input for every day.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Profile {
+    root: PathBuf,
+    // None for the default profile
+    name: Option<String>,
+}
+
+pub const DEFAULT: &str = "default";
+
+impl Profile {
+    // `root` is the repository's root folder
+    pub fn new(root: impl Into<PathBuf>, name: &str) -> Self {
+        Profile {
+            root: root.into(),
+            name: (name != DEFAULT).then(|| name.to_string()),
+        }
+    }
+
+    // The default profile first, then the ones in inputs/ by name
+    pub fn all(root: impl Into<PathBuf>) -> Vec<Profile> {
+        let root = root.into();
+
+        let mut names: Vec<String> = fs::read_dir(root.join("inputs"))
+            .into_iter()
+            .flatten()
+            .filter_map(|entry| entry.ok())
+            .filter(|entry| entry.path().is_dir())
+            .map(|entry| entry.file_name().to_string_lossy().into_owned())
+            .filter(|name| name != DEFAULT)
+            .collect();
+        names.sort_unstable();
+
+        [Profile::new(&root, DEFAULT)]
+            .into_iter()
+            .chain(names.iter().map(|name| Profile::new(&root, name)))
+            .collect()
+    }
+
+    pub fn name(&self) -> &str {
+        self.name.as_deref().unwrap_or(DEFAULT)
+    }
+
+    pub fn is_default(&self) -> bool {
+        self.name.is_none()
+    }
+
+    // Where the day's input is, or would be. The default profile can only
+    // tell when the day has a folder.
+    pub fn input(&self, year: u16, day: u8) -> Option<PathBuf> {
+        match &self.name {
+            None => day_dir(&self.root, year, day).map(|dir| dir.join("input")),
+            Some(name) => Some(self.year_dir(name, year).join(format!("{:02}", day))),
+        }
+    }
+
+    // Every day has an input in the default profile, a missing one is an
+    // error. The other profiles only have the inputs that they have.
+    pub fn has_input(&self, year: u16, day: u8) -> bool {
+        self.is_default() || self.input(year, day).is_some_and(|path| path.is_file())
+    }
+
+    pub fn answers(&self, year: u16) -> PathBuf {
+        match &self.name {
+            None => self.root.join(year.to_string()),
+            Some(name) => self.year_dir(name, year),
+        }
+        .join("answers.txt")
+    }
+
+    fn year_dir(&self, name: &str, year: u16) -> PathBuf {
+        self.root.join("inputs").join(name).join(year.to_string())
+    }
+}
+
+// The days live in <year>/dayXX_puzzle_name/ under the root folder
+pub fn day_dir(root: &Path, year: u16, day: u8) -> Option<PathBuf> {
+    let prefix = format!("day{:02}_", day);
+
+    fs::read_dir(root.join(year.to_string()))
+        .ok()?
+        .filter_map(|entry| entry.ok())
+        .find(|entry| entry.file_name().to_string_lossy().starts_with(&prefix))
+        .map(|entry| entry.path())
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::profiles::*;
+
+    #[test]
+    fn profiles_tests() {
+        let root = tempfile::tempdir().unwrap();
+        let root = root.path();
+
+        fs::create_dir_all(root.join("2015/day07_some_assembly_required")).unwrap();
+        fs::create_dir_all(root.join("inputs/zoe/2015")).unwrap();
+        fs::create_dir_all(root.join("inputs/alice/2015")).unwrap();
+        fs::write(root.join("inputs/alice/2015/07"), "123 -> a\n").unwrap();
+
+        let profiles = Profile::all(root);
+        let names: Vec<&str> = profiles.iter().map(Profile::name).collect();
+        assert_eq!(names, ["default", "alice", "zoe"]);
+
+        let (default, alice) = (&profiles[0], &profiles[1]);
+        assert!(default.is_default());
+        assert_eq!(default, &Profile::new(root, "default"));
+
+        assert_eq!(
+            default.input(2015, 7),
+            Some(root.join("2015/day07_some_assembly_required/input"))
+        );
+        assert_eq!(default.input(2015, 8), None);
+        assert_eq!(default.answers(2015), root.join("2015/answers.txt"));
+
+        assert_eq!(
+            alice.input(2015, 7),
+            Some(root.join("inputs/alice/2015/07"))
+        );
+        assert_eq!(
+            alice.answers(2015),
+            root.join("inputs/alice/2015/answers.txt")
+        );
+        assert!(alice.has_input(2015, 7));
+        assert!(!alice.has_input(2015, 8));
+        assert!(!profiles[2].has_input(2015, 7));
+
+        // Nothing in inputs/ at all
+        let root = tempfile::tempdir().unwrap();
+        assert_eq!(Profile::all(root.path()).len(), 1);
+    }
+}
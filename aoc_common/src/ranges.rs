@@ -0,0 +1,261 @@
+use std::{
+    cmp::{max, min},
+    ops::{Range, RangeInclusive},
+};
+
+// A set of integers kept as the ranges that it's made of, for the puzzles
+// that deal with far more numbers than could be looked at one by one. The
+// ranges are half-open, sorted and never overlap or touch, so two sets with
+// the same numbers are always made of the same ranges.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct RangeSet {
+    ranges: Vec<Range<i64>>,
+}
+
+// Moves the numbers of each of its pieces by that piece's offset and leaves
+// the numbers that are in none of them where they are. Where pieces overlap
+// the one that was inserted first wins.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct RangeMap {
+    pieces: Vec<(Range<i64>, i64)>,
+}
+
+impl RangeSet {
+    pub fn new() -> Self {
+        RangeSet::default()
+    }
+
+    pub fn insert(&mut self, range: Range<i64>) {
+        *self = self.union(&RangeSet::from(range));
+    }
+
+    // How many numbers are in the set
+    pub fn len(&self) -> i64 {
+        self.ranges
+            .iter()
+            .map(|range| range.end - range.start)
+            .sum()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ranges.is_empty()
+    }
+
+    pub fn contains(&self, value: i64) -> bool {
+        let i = self.ranges.partition_point(|range| range.end <= value);
+
+        self.ranges
+            .get(i)
+            .is_some_and(|range| range.contains(&value))
+    }
+
+    pub fn min(&self) -> Option<i64> {
+        self.ranges.first().map(|range| range.start)
+    }
+
+    pub fn max(&self) -> Option<i64> {
+        self.ranges.last().map(|range| range.end - 1)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = Range<i64>> + '_ {
+        self.ranges.iter().cloned()
+    }
+
+    pub fn union(&self, other: &RangeSet) -> RangeSet {
+        self.iter().chain(other.iter()).collect()
+    }
+
+    pub fn intersection(&self, other: &RangeSet) -> RangeSet {
+        let mut ranges = Vec::new();
+        let (mut i, mut j) = (0, 0);
+
+        while let (Some(a), Some(b)) = (self.ranges.get(i), other.ranges.get(j)) {
+            let (start, end) = (max(a.start, b.start), min(a.end, b.end));
+            if start < end {
+                ranges.push(start..end);
+            }
+
+            // Whichever range ends first can't overlap anything else
+            if a.end < b.end {
+                i += 1;
+            } else {
+                j += 1;
+            }
+        }
+
+        RangeSet { ranges }
+    }
+
+    pub fn difference(&self, other: &RangeSet) -> RangeSet {
+        let mut ranges = Vec::new();
+        let mut j = 0;
+
+        for range in &self.ranges {
+            // The ranges that end before this one starts are behind all the
+            // ones that are left too
+            while other.ranges.get(j).is_some_and(|o| o.end <= range.start) {
+                j += 1;
+            }
+
+            let mut start = range.start;
+            for o in other.ranges[j..].iter().take_while(|o| o.start < range.end) {
+                if o.start > start {
+                    ranges.push(start..o.start);
+                }
+                start = max(start, o.end);
+            }
+
+            if start < range.end {
+                ranges.push(start..range.end);
+            }
+        }
+
+        RangeSet { ranges }
+    }
+
+    pub fn is_subset(&self, other: &RangeSet) -> bool {
+        self.difference(other).is_empty()
+    }
+
+    pub fn overlaps(&self, other: &RangeSet) -> bool {
+        !self.intersection(other).is_empty()
+    }
+
+    // Every number moved by `offset`
+    pub fn shift(&self, offset: i64) -> RangeSet {
+        RangeSet {
+            ranges: self
+                .iter()
+                .map(|range| range.start + offset..range.end + offset)
+                .collect(),
+        }
+    }
+}
+
+impl From<Range<i64>> for RangeSet {
+    fn from(range: Range<i64>) -> Self {
+        [range].into_iter().collect()
+    }
+}
+
+// Most puzzles give both ends of their ranges
+impl From<RangeInclusive<i64>> for RangeSet {
+    fn from(range: RangeInclusive<i64>) -> Self {
+        RangeSet::from(*range.start()..*range.end() + 1)
+    }
+}
+
+// The ranges can come in any order and overlap each other
+impl FromIterator<Range<i64>> for RangeSet {
+    fn from_iter<I: IntoIterator<Item = Range<i64>>>(iter: I) -> Self {
+        let mut sorted: Vec<Range<i64>> =
+            iter.into_iter().filter(|range| !range.is_empty()).collect();
+        sorted.sort_unstable_by_key(|range| range.start);
+
+        let mut ranges: Vec<Range<i64>> = Vec::new();
+        sorted
+            .into_iter()
+            .for_each(|range| match ranges.last_mut() {
+                Some(last) if range.start <= last.end => last.end = max(last.end, range.end),
+                _ => ranges.push(range),
+            });
+
+        RangeSet { ranges }
+    }
+}
+
+impl RangeMap {
+    pub fn new() -> Self {
+        RangeMap::default()
+    }
+
+    pub fn insert(&mut self, source: Range<i64>, offset: i64) {
+        self.pieces.push((source, offset));
+    }
+
+    pub fn get(&self, value: i64) -> i64 {
+        self.pieces
+            .iter()
+            .find(|(source, _)| source.contains(&value))
+            .map_or(value, |(_, offset)| value + offset)
+    }
+
+    // Where all the numbers of `set` end up, which takes splitting its ranges
+    // wherever a piece starts or ends
+    pub fn apply(&self, set: &RangeSet) -> RangeSet {
+        let mut left = set.clone();
+        let mut moved = Vec::new();
+
+        for (source, offset) in &self.pieces {
+            let source = RangeSet::from(source.clone());
+
+            moved.extend(left.intersection(&source).shift(*offset).iter());
+            left = left.difference(&source);
+        }
+
+        left.iter().chain(moved).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ranges::*;
+
+    fn set(ranges: &[Range<i64>]) -> RangeSet {
+        ranges.iter().cloned().collect()
+    }
+
+    #[test]
+    fn set_tests() {
+        let s = set(&[5..8, 0..2, 1..3, 8..10, 12..12]);
+
+        assert_eq!(s.iter().collect::<Vec<_>>(), [0..3, 5..10]);
+        assert_eq!(s.len(), 8);
+        assert_eq!((s.min(), s.max()), (Some(0), Some(9)));
+        assert!(s.contains(2) && s.contains(5) && s.contains(9));
+        assert!(!s.contains(3) && !s.contains(10) && !s.contains(-1));
+
+        let mut s = RangeSet::new();
+        assert!(s.is_empty());
+        s.insert(4..6);
+        s.insert(0..2);
+        s.insert(2..4);
+        assert_eq!(s, RangeSet::from(0..=5));
+    }
+
+    #[test]
+    fn operations_tests() {
+        let a = set(&[0..10, 20..30]);
+        let b = set(&[5..25, 28..40]);
+
+        assert_eq!(a.union(&b), RangeSet::from(0..40));
+        assert_eq!(a.intersection(&b), set(&[5..10, 20..25, 28..30]));
+        assert_eq!(a.difference(&b), set(&[0..5, 25..28]));
+        assert_eq!(b.difference(&a), set(&[10..20, 30..40]));
+        assert_eq!(a.difference(&a), RangeSet::new());
+
+        assert!(set(&[2..4, 21..22]).is_subset(&a));
+        assert!(!b.is_subset(&a));
+        assert!(a.overlaps(&b));
+        assert!(!a.overlaps(&RangeSet::from(10..20)));
+
+        assert_eq!(a.shift(-5), set(&[-5..5, 15..25]));
+    }
+
+    #[test]
+    fn map_tests() {
+        // The seed-to-soil map of 2023 day 5
+        let mut map = RangeMap::new();
+        map.insert(98..100, 50 - 98);
+        map.insert(50..98, 52 - 50);
+
+        assert_eq!(map.get(79), 81);
+        assert_eq!(map.get(99), 51);
+        assert_eq!(map.get(10), 10);
+
+        assert_eq!(map.apply(&set(&[79..93, 55..68])), set(&[57..70, 81..95]));
+        // 50..100 only gets shuffled around
+        assert_eq!(map.apply(&RangeSet::from(40..100)), RangeSet::from(40..100));
+        assert_eq!(map.apply(&RangeSet::from(95..105)).len(), 10);
+    }
+}
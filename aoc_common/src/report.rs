@@ -0,0 +1,112 @@
+use crate::{answers::Answer, memory::Measurement, parse::ParseError};
+use serde::{ser::SerializeStruct, Serialize, Serializer};
+
+// What solving a day came to: the answers, and how long each phase took and
+// how much memory it needed
+#[derive(Debug)]
+pub struct Report {
+    pub year: u16,
+    pub day: u8,
+    pub parse: Measurement,
+    pub parts: Vec<PartResult>,
+}
+
+#[derive(Debug)]
+pub struct PartResult {
+    pub part: u8,
+    // None for the parts that aren't solved
+    pub answer: Option<Result<Answer, ParseError>>,
+    pub measurement: Measurement,
+}
+
+// Durations go out in nanoseconds and memory in bytes, with null for the
+// memory when it wasn't counted. A malformed input gives an "error" with the
+// whole diagnostic in place of the "answer".
+//
+//   {"year":2015,"day":1,"parse":{"elapsed_ns":1200,"peak_bytes":7000},
+//    "parts":[{"part":1,"answer":138,"elapsed_ns":3100,"peak_bytes":0}, ...]}
+impl Serialize for Report {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut report = serializer.serialize_struct("Report", 4)?;
+        report.serialize_field("year", &self.year)?;
+        report.serialize_field("day", &self.day)?;
+        report.serialize_field("parse", &Phase(&self.parse))?;
+        report.serialize_field("parts", &self.parts)?;
+        report.end()
+    }
+}
+
+impl Serialize for PartResult {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut result = serializer.serialize_struct("PartResult", 4)?;
+        result.serialize_field("part", &self.part)?;
+        match &self.answer {
+            Some(Ok(answer)) => result.serialize_field("answer", answer)?,
+            Some(Err(e)) => result.serialize_field("error", &e.to_string())?,
+            None => result.serialize_field("answer", &None::<Answer>)?,
+        }
+        result.serialize_field("elapsed_ns", &nanoseconds(&self.measurement))?;
+        result.serialize_field("peak_bytes", &self.measurement.peak_memory)?;
+        result.end()
+    }
+}
+
+struct Phase<'a>(&'a Measurement);
+
+impl Serialize for Phase<'_> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut phase = serializer.serialize_struct("Phase", 2)?;
+        phase.serialize_field("elapsed_ns", &nanoseconds(self.0))?;
+        phase.serialize_field("peak_bytes", &self.0.peak_memory)?;
+        phase.end()
+    }
+}
+
+// A u64 of nanoseconds lasts for 584 years, which is plenty even for 2015
+// day 4 in debug
+fn nanoseconds(measurement: &Measurement) -> u64 {
+    measurement.elapsed.as_nanos() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::report::*;
+    use std::time::Duration;
+
+    fn measurement(micros: u64, peak_memory: Option<usize>) -> Measurement {
+        Measurement {
+            elapsed: Duration::from_micros(micros),
+            peak_memory,
+        }
+    }
+
+    #[test]
+    fn json_tests() {
+        let report = Report {
+            year: 2015,
+            day: 1,
+            parse: measurement(2, Some(4096)),
+            parts: vec![
+                PartResult {
+                    part: 1,
+                    answer: Some(Ok(Answer::Integer(138))),
+                    measurement: measurement(5, Some(0)),
+                },
+                PartResult {
+                    part: 2,
+                    answer: None,
+                    measurement: measurement(0, None),
+                },
+            ],
+        };
+
+        assert_eq!(
+            serde_json::to_string(&report).unwrap(),
+            concat!(
+                r#"{"year":2015,"day":1,"parse":{"elapsed_ns":2000,"peak_bytes":4096},"#,
+                r#""parts":[{"part":1,"answer":138,"elapsed_ns":5000,"peak_bytes":0},"#,
+                r#"{"part":2,"answer":null,"elapsed_ns":0,"peak_bytes":null}]}"#
+            )
+        );
+    }
+}
@@ -0,0 +1,258 @@
+use std::{
+    cmp::Reverse,
+    collections::{hash_map::Entry, BinaryHeap, HashMap, HashSet, VecDeque},
+    hash::Hash,
+};
+
+// What a puzzle has to say about its state space for it to be searched:
+// where to start, where each state leads to and at what cost, and when to
+// stop. The heuristic is only used by astar() and has to never overestimate
+// the cost that is left, the default of 0 makes astar() a dijkstra().
+pub trait SearchProblem {
+    type State: Clone + Eq + Hash;
+
+    fn starts(&self) -> impl IntoIterator<Item = Self::State>;
+
+    fn successors(&self, state: &Self::State) -> impl IntoIterator<Item = (Self::State, u64)>;
+
+    fn is_goal(&self, state: &Self::State) -> bool;
+
+    fn heuristic(&self, _state: &Self::State) -> u64 {
+        0
+    }
+}
+
+// The cheapest way to a goal, from the start that it was found from to the
+// goal itself
+#[derive(Debug, PartialEq, Eq)]
+pub struct Path<S> {
+    pub cost: u64,
+    pub states: Vec<S>,
+}
+
+// The states that have been reached, each one with the index of the state
+// it was reached from
+struct Nodes<S> {
+    nodes: Vec<(S, Option<usize>)>,
+}
+
+impl<S: Clone> Nodes<S> {
+    fn push(&mut self, state: S, parent: Option<usize>) -> usize {
+        self.nodes.push((state, parent));
+        self.nodes.len() - 1
+    }
+
+    fn path(&self, mut index: usize, cost: u64) -> Path<S> {
+        let mut states = vec![self.nodes[index].0.clone()];
+
+        while let Some(parent) = self.nodes[index].1 {
+            states.push(self.nodes[parent].0.clone());
+            index = parent;
+        }
+        states.reverse();
+
+        Path { cost, states }
+    }
+}
+
+// Every step costs 1 whatever the problem says, so the path found is the one
+// with the fewest steps. All the starts are searched from at once.
+pub fn bfs<P: SearchProblem>(problem: &P) -> Option<Path<P::State>> {
+    let mut nodes = Nodes { nodes: Vec::new() };
+    let mut visited = HashSet::new();
+    let mut queue = VecDeque::new();
+
+    for start in problem.starts() {
+        if visited.insert(start.clone()) {
+            let index = nodes.push(start, None);
+            queue.push_back((index, 0));
+        }
+    }
+
+    while let Some((index, steps)) = queue.pop_front() {
+        let state = nodes.nodes[index].0.clone();
+
+        if problem.is_goal(&state) {
+            return Some(nodes.path(index, steps));
+        }
+
+        for (next, _) in problem.successors(&state) {
+            if visited.insert(next.clone()) {
+                let next_index = nodes.push(next, Some(index));
+                queue.push_back((next_index, steps + 1));
+            }
+        }
+    }
+
+    None
+}
+
+pub fn dijkstra<P: SearchProblem>(problem: &P) -> Option<Path<P::State>> {
+    search(problem, |_| 0)
+}
+
+pub fn astar<P: SearchProblem>(problem: &P) -> Option<Path<P::State>> {
+    search(problem, |state| problem.heuristic(state))
+}
+
+fn search<P: SearchProblem>(
+    problem: &P,
+    heuristic: impl Fn(&P::State) -> u64,
+) -> Option<Path<P::State>> {
+    let mut nodes = Nodes { nodes: Vec::new() };
+    let mut best_costs: HashMap<P::State, u64> = HashMap::new();
+    let mut queue = BinaryHeap::new();
+
+    for start in problem.starts() {
+        if let Entry::Vacant(entry) = best_costs.entry(start.clone()) {
+            entry.insert(0);
+            let estimate = heuristic(&start);
+            let index = nodes.push(start, None);
+            queue.push(Reverse((estimate, 0, index)));
+        }
+    }
+
+    while let Some(Reverse((_, cost, index))) = queue.pop() {
+        let state = nodes.nodes[index].0.clone();
+
+        // A cheaper way to this state was found after this one was queued
+        if best_costs[&state] < cost {
+            continue;
+        }
+
+        if problem.is_goal(&state) {
+            return Some(nodes.path(index, cost));
+        }
+
+        for (next, step_cost) in problem.successors(&state) {
+            let next_cost = cost + step_cost;
+
+            if best_costs
+                .get(&next)
+                .is_none_or(|&best_cost| next_cost < best_cost)
+            {
+                let estimate = next_cost + heuristic(&next);
+                best_costs.insert(next.clone(), next_cost);
+                let next_index = nodes.push(next, Some(index));
+                queue.push(Reverse((estimate, next_cost, next_index)));
+            }
+        }
+    }
+
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::search::*;
+
+    // Going from 1 to the target by adding 1 (which costs 1) or doubling
+    // (which costs `double`)
+    struct Numbers {
+        target: u64,
+        double: u64,
+    }
+
+    impl SearchProblem for Numbers {
+        type State = u64;
+
+        fn starts(&self) -> impl IntoIterator<Item = u64> {
+            [1]
+        }
+
+        fn successors(&self, &n: &u64) -> impl IntoIterator<Item = (u64, u64)> {
+            [(n + 1, 1), (n * 2, self.double)]
+                .into_iter()
+                .filter(|&(next, _)| next <= self.target)
+        }
+
+        fn is_goal(&self, &n: &u64) -> bool {
+            n == self.target
+        }
+
+        // Each step at most doubles the number and costs at least 1
+        fn heuristic(&self, &n: &u64) -> u64 {
+            (self.target / n).ilog2() as u64
+        }
+    }
+
+    #[test]
+    fn bfs_tests() {
+        let path = bfs(&Numbers {
+            target: 10,
+            double: 5,
+        })
+        .unwrap();
+
+        assert_eq!(path.cost, 4);
+        assert_eq!(path.states, [1, 2, 4, 5, 10]);
+
+        let path = bfs(&Numbers {
+            target: 1,
+            double: 5,
+        })
+        .unwrap();
+
+        assert_eq!(
+            path,
+            Path {
+                cost: 0,
+                states: vec![1]
+            }
+        );
+    }
+
+    #[test]
+    fn dijkstra_tests() {
+        let problem = Numbers {
+            target: 10,
+            double: 6,
+        };
+        let path = dijkstra(&problem).unwrap();
+
+        assert_eq!(path.cost, 9);
+        assert_eq!(path.states, (1..=10).collect::<Vec<_>>());
+
+        let problem = Numbers {
+            target: 10,
+            double: 2,
+        };
+
+        assert_eq!(dijkstra(&problem).unwrap().cost, 6);
+        assert_eq!(astar(&problem), dijkstra(&problem));
+    }
+
+    #[test]
+    fn astar_tests() {
+        let path = astar(&Numbers {
+            target: 1000,
+            double: 1,
+        })
+        .unwrap();
+
+        assert_eq!(path.cost, 14);
+        assert_eq!(path.states.first(), Some(&1));
+        assert_eq!(path.states.last(), Some(&1000));
+
+        struct Unreachable;
+
+        impl SearchProblem for Unreachable {
+            type State = u8;
+
+            fn starts(&self) -> impl IntoIterator<Item = u8> {
+                [0]
+            }
+
+            fn successors(&self, &n: &u8) -> impl IntoIterator<Item = (u8, u64)> {
+                n.checked_add(1).map(|next| (next, 1))
+            }
+
+            fn is_goal(&self, _: &u8) -> bool {
+                false
+            }
+        }
+
+        assert_eq!(astar(&Unreachable), None);
+        assert_eq!(bfs(&Unreachable), None);
+    }
+}
@@ -0,0 +1,241 @@
+use crate::geometry::{Direction, Point2};
+use std::{collections::HashMap, fmt, ops::Index};
+
+// For the maps that have no edges: the cells are wherever the puzzle puts
+// them, negative coordinates included, and only the ones that hold something
+// take up memory. The bounding box of those cells is kept up to date as they
+// come and go, which is what gets drawn when the grid is displayed.
+//
+// Positions are Point2s with y going down, so a grid parsed from a map reads
+// the same way as with grid::Grid.
+#[derive(Clone, PartialEq, Eq)]
+pub struct SparseGrid<T> {
+    cells: HashMap<Point2, T>,
+    // The top left and bottom right corners, both inclusive
+    bounds: Option<(Point2, Point2)>,
+}
+
+// The box grown to take in `position` too
+fn extend(bounds: Option<(Point2, Point2)>, position: Point2) -> (Point2, Point2) {
+    match bounds {
+        Some((min, max)) => (
+            Point2::new(min.x.min(position.x), min.y.min(position.y)),
+            Point2::new(max.x.max(position.x), max.y.max(position.y)),
+        ),
+        None => (position, position),
+    }
+}
+
+impl<T> Default for SparseGrid<T> {
+    fn default() -> Self {
+        SparseGrid {
+            cells: HashMap::new(),
+            bounds: None,
+        }
+    }
+}
+
+impl<T> SparseGrid<T> {
+    pub fn new() -> Self {
+        SparseGrid::default()
+    }
+
+    // How many cells hold something
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    pub fn get(&self, position: Point2) -> Option<&T> {
+        self.cells.get(&position)
+    }
+
+    pub fn get_mut(&mut self, position: Point2) -> Option<&mut T> {
+        self.cells.get_mut(&position)
+    }
+
+    pub fn contains(&self, position: Point2) -> bool {
+        self.cells.contains_key(&position)
+    }
+
+    pub fn insert(&mut self, position: Point2, value: T) -> Option<T> {
+        self.bounds = Some(extend(self.bounds, position));
+
+        self.cells.insert(position, value)
+    }
+
+    pub fn remove(&mut self, position: Point2) -> Option<T> {
+        let value = self.cells.remove(&position)?;
+
+        // The box only has to shrink when the cell was on one of its edges
+        if let Some((min, max)) = self.bounds {
+            if [min.x, max.x].contains(&position.x) || [min.y, max.y].contains(&position.y) {
+                self.bounds = self
+                    .cells
+                    .keys()
+                    .fold(None, |bounds, &p| Some(extend(bounds, p)));
+            }
+        }
+
+        Some(value)
+    }
+
+    // The top left and bottom right corners of the smallest rectangle that
+    // holds every cell, both inclusive
+    pub fn bounds(&self) -> Option<(Point2, Point2)> {
+        self.bounds
+    }
+
+    pub fn contains_in_bounds(&self, position: Point2) -> bool {
+        self.bounds.is_some_and(|(min, max)| {
+            (min.x..=max.x).contains(&position.x) && (min.y..=max.y).contains(&position.y)
+        })
+    }
+
+    // In no particular order
+    pub fn iter(&self) -> impl Iterator<Item = (Point2, &T)> {
+        self.cells
+            .iter()
+            .map(|(&position, value)| (position, value))
+    }
+
+    pub fn positions(&self) -> impl Iterator<Item = Point2> + '_ {
+        self.cells.keys().copied()
+    }
+
+    // The same as with grid::Grid, except that there are no edges to stop at
+    pub fn step(&self, position: Point2, direction: Direction) -> Point2 {
+        position + direction.offset()
+    }
+
+    pub fn neighbours4(&self, position: Point2) -> impl Iterator<Item = Point2> {
+        position.neighbours4()
+    }
+
+    pub fn neighbours8(&self, position: Point2) -> impl Iterator<Item = Point2> {
+        position.neighbours8()
+    }
+
+    // The bounding box drawn one row per line, with `cell` telling what each
+    // position looks like whether it holds something or not
+    pub fn render(&self, mut cell: impl FnMut(Option<&T>) -> char) -> String {
+        let Some((min, max)) = self.bounds else {
+            return String::new();
+        };
+
+        (min.y..=max.y)
+            .map(|y| {
+                (min.x..=max.x)
+                    .map(|x| cell(self.get(Point2::new(x, y))))
+                    .collect::<String>()
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+impl<T> FromIterator<(Point2, T)> for SparseGrid<T> {
+    fn from_iter<I: IntoIterator<Item = (Point2, T)>>(iter: I) -> Self {
+        let mut grid = SparseGrid::new();
+        iter.into_iter().for_each(|(position, value)| {
+            grid.insert(position, value);
+        });
+
+        grid
+    }
+}
+
+impl<T> Index<Point2> for SparseGrid<T> {
+    type Output = T;
+
+    fn index(&self, position: Point2) -> &T {
+        &self.cells[&position]
+    }
+}
+
+// Cells that display as a character, with '.' where there's nothing
+impl<T: fmt::Display> fmt::Display for SparseGrid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let text = self.render(|cell| {
+            cell.map_or('.', |value| value.to_string().chars().next().unwrap_or(' '))
+        });
+
+        write!(f, "{}", text)
+    }
+}
+
+// The cells that hold something, row by row
+impl<T: fmt::Debug> fmt::Debug for SparseGrid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "SparseGrid of {} cells", self.len())?;
+
+        let mut cells: Vec<_> = self.iter().collect();
+        cells.sort_unstable_by_key(|(position, _)| (position.y, position.x));
+        cells.iter().try_for_each(|(position, value)| {
+            writeln!(f, "  ({}, {}) {:?}", position.x, position.y, value)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::sparse_grid::*;
+
+    #[test]
+    fn cells_tests() {
+        let mut g = SparseGrid::new();
+        assert_eq!(g.bounds(), None);
+        assert_eq!(g.to_string(), "");
+
+        g.insert(Point2::new(-2, 3), '#');
+        g.insert(Point2::new(1, -1), 'o');
+        assert_eq!(g.insert(Point2::new(1, -1), '#'), Some('o'));
+
+        assert_eq!(g.len(), 2);
+        assert_eq!(g[Point2::new(-2, 3)], '#');
+        assert_eq!(g.get(Point2::new(0, 0)), None);
+        assert!(g.contains_in_bounds(Point2::new(0, 0)));
+        assert!(!g.contains_in_bounds(Point2::new(2, 0)));
+        assert_eq!(g.bounds(), Some((Point2::new(-2, -1), Point2::new(1, 3))));
+
+        assert_eq!(g.remove(Point2::new(-2, 3)), Some('#'));
+        assert_eq!(g.remove(Point2::new(-2, 3)), None);
+        assert_eq!(g.bounds(), Some((Point2::new(1, -1), Point2::new(1, -1))));
+
+        g.insert(Point2::new(3, 0), '.');
+        *g.get_mut(Point2::new(3, 0)).unwrap() = '~';
+        assert_eq!(g[Point2::new(3, 0)], '~');
+        assert_eq!(g.bounds(), Some((Point2::new(1, -1), Point2::new(3, 0))));
+    }
+
+    #[test]
+    fn neighbours_tests() {
+        let g: SparseGrid<char> = SparseGrid::new();
+        let origin = Point2::ORIGIN;
+
+        assert_eq!(g.step(origin, Direction::North), Point2::new(0, -1));
+        assert_eq!(g.neighbours4(origin).count(), 4);
+        assert!(g.neighbours8(origin).any(|p| p == Point2::new(-1, -1)));
+    }
+
+    #[test]
+    fn render_tests() {
+        let g: SparseGrid<char> = [(-1, 0, '#'), (1, 0, '#'), (0, 2, 'o')]
+            .into_iter()
+            .map(|(x, y, c)| (Point2::new(x, y), c))
+            .collect();
+
+        assert_eq!(g.to_string(), "#.#\n...\n.o.");
+        assert_eq!(
+            g.render(|cell| if cell.is_some() { '█' } else { ' ' }),
+            "█ █\n   \n █ "
+        );
+        assert_eq!(
+            format!("{:?}", g),
+            "SparseGrid of 3 cells\n  (-1, 0) '#'\n  (1, 0) '#'\n  (0, 2) 'o'\n"
+        );
+    }
+}
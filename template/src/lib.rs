@@ -0,0 +1,64 @@
+use aoc_common::{
+    answers::Answer,
+    parse::{self, ParseError},
+    Solution,
+};
+use std::io::BufRead;
+
+fn parse_input(input: &mut impl BufRead) -> Result<Vec<String>, ParseError> {
+    parse::parse_lines(input, |line| Ok(line.to_string()))
+}
+
+// Until the parts are written they say so, which check_examples points out
+// next to the example's answers
+fn part1(_lines: &[String]) -> Answer {
+    Answer::from("unsolved")
+}
+
+fn part2(_lines: &[String]) -> Answer {
+    Answer::from("unsolved")
+}
+
+pub struct Day{{day}};
+
+impl Solution for Day{{day}} {
+    const YEAR: u16 = {{year}};
+    const DAY: u8 = {{day}};
+
+    type Input = Vec<String>;
+
+    fn parse(input: &mut impl BufRead) -> Result<Vec<String>, ParseError> {
+        parse_input(input)
+    }
+
+    fn part1(input: &Vec<String>) -> Result<Answer, ParseError> {
+        Ok(part1(input))
+    }
+
+    fn part2(input: &Vec<String>) -> Option<Result<Answer, ParseError>> {
+        Some(Ok(part2(input)))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::*;
+
+    fn init() {
+        let _ = env_logger::builder().is_test(true).try_init();
+    }
+
+    #[test]
+    fn check_answers() {
+        init();
+
+        aoc_common::answers::check_answers::<Day{{day}}>();
+    }
+
+    #[test]
+    fn check_examples() {
+        init();
+
+        aoc_common::examples::check_examples::<Day{{day}}>();
+    }
+}
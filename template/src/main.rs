@@ -1,64 +1,6 @@
-use log::info;
-use std::{
-    fs::File,
-    io::{self, BufRead, BufReader, Seek},
-};
+use {{crate_name}}::Day{{day}};
+use std::process::ExitCode;
 
-fn part1(_input: &mut impl BufRead) -> String {
-    "".to_string()
-}
-
-fn part2(_input: &mut impl BufRead) -> String {
-    "".to_string()
-}
-
-fn main() -> io::Result<()> {
-    env_logger::init();
-
-    let f = File::open("input")?;
-    let mut reader = BufReader::new(f);
-
-    info!("Part 1 answer: {}", part1(&mut reader));
-
-    reader.rewind().unwrap();
-
-    info!("Part 2 answer: {}", part2(&mut reader));
-
-    Ok(())
-}
-
-#[cfg(test)]
-mod tests {
-    use crate::*;
-    use std::io::Cursor;
-
-    fn init() {
-        let _ = env_logger::builder().is_test(true).try_init();
-    }
-
-    #[test]
-    fn check_answers() {
-        init();
-
-        let f = File::open("input").unwrap();
-        let mut reader = BufReader::new(f);
-
-        assert_eq!(part1(&mut reader), "");
-        reader.rewind().unwrap();
-        assert_eq!(part2(&mut reader), "");
-    }
-
-    #[test]
-    fn part1_tests() {
-        init();
-
-        assert_eq!(part1(&mut Cursor::new("")), "");
-    }
-
-    #[test]
-    fn part2_tests() {
-        init();
-
-        assert_eq!(part2(&mut Cursor::new("")), "");
-    }
+fn main() -> ExitCode {
+    aoc_common::run::<Day{{day}}>()
 }